@@ -1,3 +1,15 @@
+use arrow::array::PrimitiveArray;
+
+/// The type of row indexes. This is `u64` with the `bigidx` feature, to allow for more than
+/// `u32::MAX` rows, and `u32` otherwise.
+#[cfg(not(feature = "bigidx"))]
+pub type IdxSize = u32;
+#[cfg(feature = "bigidx")]
+pub type IdxSize = u64;
+
+/// An arrow array of row indexes.
+pub type IdxArr = PrimitiveArray<IdxSize>;
+
 pub trait IndexToUsize {
     /// Translate the negative index to an offset.
     fn negative_to_usize(self, index: usize) -> Option<usize>;
@@ -0,0 +1,102 @@
+//! Cancellation of long running operations.
+//!
+//! Readers take a [`CancellationToken`] as an option. Operations that don't take options, such
+//! as joins, use the token of the enclosing [`CancellationToken::scoped`] call and pass it to
+//! the threads they spawn.
+use crate::prelude::*;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Number of rows an operation processes between two checks of its token.
+const CHECK_INTERVAL: usize = 1 << 14;
+
+thread_local! {
+    static TOKEN: RefCell<Option<CancellationToken>> = RefCell::new(None);
+}
+
+/// A token that can be used to cancel a running query from another thread.
+///
+/// Clones share the same state, so cancelling a clone cancels the query.
+#[derive(Clone, Default, Debug)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation of the query this token is attached to.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Returns [`PolarsError::Cancelled`] if the token is cancelled.
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(PolarsError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Whether a loop should stop at row `idx`. The token is only read every few thousand rows,
+    /// so this can be called for every row.
+    #[inline]
+    pub(crate) fn is_cancelled_at(&self, idx: usize) -> bool {
+        idx % CHECK_INTERVAL == 0 && self.is_cancelled()
+    }
+
+    /// Run `f` with this token as the [`current`](CancellationToken::current) token of the
+    /// current thread. The previous token is restored when `f` returns or panics.
+    pub fn scoped<F, T>(&self, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        // restores the previous token, also when `f` panics
+        struct Restore(Option<CancellationToken>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                TOKEN.with(|token| *token.borrow_mut() = previous);
+            }
+        }
+
+        let previous = TOKEN.with(|token| token.borrow_mut().replace(self.clone()));
+        let _restore = Restore(previous);
+        f()
+    }
+
+    /// The token of the enclosing [`scoped`](CancellationToken::scoped) call, or a token that
+    /// is never cancelled outside of a scope.
+    pub fn current() -> Self {
+        TOKEN
+            .with(|token| token.borrow().clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scoped_token() {
+        let token = CancellationToken::new();
+        token.scoped(|| {
+            token.cancel();
+            assert!(CancellationToken::current().check().is_err());
+            // other threads don't see the scope
+            let cancelled = std::thread::spawn(|| CancellationToken::current().is_cancelled())
+                .join()
+                .unwrap();
+            assert!(!cancelled);
+        });
+        assert!(!CancellationToken::current().is_cancelled());
+    }
+}
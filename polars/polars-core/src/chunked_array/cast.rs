@@ -1,6 +1,6 @@
 //! Implementations of the ChunkCast Trait.
 #[cfg(feature = "dtype-categorical")]
-use crate::chunked_array::categorical::CategoricalChunkedBuilder;
+use crate::chunked_array::categorical::{enum_rev_map, CategoricalChunkedBuilder};
 use crate::prelude::*;
 use polars_arrow::compute::cast;
 use std::convert::TryFrom;
@@ -57,8 +57,12 @@ impl ChunkCast for CategoricalChunked {
                 let ca = UInt32Chunked::from_chunks(self.name(), self.chunks.clone());
                 Ok(ca.into_series())
             }
-            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical if self.logical_dtype() != &DataType::Categorical => {
+                self.cast(&DataType::Utf8)?.cast(data_type)
+            }
             DataType::Categorical => Ok(self.clone().into_series()),
+            dt @ DataType::Enum(_) if self.logical_dtype() == dt => Ok(self.clone().into_series()),
+            DataType::Enum(_) => self.cast(&DataType::Utf8)?.cast(data_type),
             _ => cast_impl(self.name(), &self.chunks, data_type),
         }
     }
@@ -78,6 +82,21 @@ where
                     .set_state(self);
                 Ok(ca.into_series())
             }
+            #[cfg(feature = "dtype-categorical")]
+            (UInt32, Enum(categories)) => {
+                let ca = self.cast(&UInt32)?;
+                let ca = ca.u32()?;
+                if let Some(max) = ca.max() {
+                    if max as usize >= categories.len() {
+                        return Err(PolarsError::ComputeError(
+                            format!("code {} is out of bounds for {}", max, data_type).into(),
+                        ));
+                    }
+                }
+                let mut ca = CategoricalChunked::from_chunks(self.name(), ca.chunks.clone());
+                ca.set_categorical_map(enum_rev_map(categories));
+                Ok(ca.into_series())
+            }
             _ => cast_impl(self.name(), &self.chunks, data_type),
         }
     }
@@ -94,6 +113,8 @@ impl ChunkCast for Utf8Chunked {
                 let ca = builder.finish();
                 Ok(ca.into_series())
             }
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(categories) => Ok(self.to_enum(categories)?.into_series()),
             _ => cast_impl(self.name(), &self.chunks, data_type),
         }
     }
@@ -1,5 +1,6 @@
+use super::string_cache::{active_string_cache, StringCache};
+use crate::datatypes::PlHashMap;
 use crate::prelude::*;
-use crate::{datatypes::PlHashMap, use_string_cache};
 use arrow::array::*;
 use std::marker::PhantomData;
 
@@ -45,6 +46,9 @@ pub enum RevMapping {
     Global(PlHashMap<u32, u32>, Utf8Array<i64>, u128),
     /// Utf8Array: caches the string values
     Local(Utf8Array<i64>),
+    /// Utf8Array: the fixed categories of the enum, in the order of their index
+    /// DataType: the `DataType::Enum` of these categories
+    Enum(Utf8Array<i64>, DataType),
 }
 
 #[allow(clippy::len_without_is_empty)]
@@ -53,7 +57,7 @@ impl RevMapping {
     pub fn len(&self) -> usize {
         match self {
             Self::Global(_, a, _) => a.len(),
-            Self::Local(a) => a.len(),
+            Self::Local(a) | Self::Enum(a, _) => a.len(),
         }
     }
 
@@ -64,7 +68,7 @@ impl RevMapping {
                 let idx = *map.get(&idx).unwrap();
                 a.value(idx as usize)
             }
-            Self::Local(a) => a.value(idx as usize),
+            Self::Local(a) | Self::Enum(a, _) => a.value(idx as usize),
         }
     }
 
@@ -78,17 +82,27 @@ impl RevMapping {
                 let idx = *map.get(&idx).unwrap();
                 a.value_unchecked(idx as usize)
             }
-            Self::Local(a) => a.value_unchecked(idx as usize),
+            Self::Local(a) | Self::Enum(a, _) => a.value_unchecked(idx as usize),
         }
     }
-    /// Check if the categoricals are created under the same global string cache.
+    /// Check if the categoricals are created under the same global string cache, or are
+    /// enums with the same categories.
     pub fn same_src(&self, other: &Self) -> bool {
         match (self, other) {
             (RevMapping::Global(_, _, l), RevMapping::Global(_, _, r)) => *l == *r,
+            (RevMapping::Enum(_, l), RevMapping::Enum(_, r)) => l == r,
             _ => false,
         }
     }
 
+    /// The `DataType::Enum` if these are the categories of an enum.
+    pub fn enum_dtype(&self) -> Option<&DataType> {
+        match self {
+            Self::Enum(_, dtype) => Some(dtype),
+            _ => None,
+        }
+    }
+
     /// str to Categorical
     pub fn find(&self, value: &str) -> Option<u32> {
         match self {
@@ -99,7 +113,7 @@ impl RevMapping {
                     .find(|(_k, &v)| (unsafe { a.value_unchecked(v as usize) } == value))
                     .map(|(k, _v)| *k)
             }
-            Self::Local(a) => {
+            Self::Local(a) | Self::Enum(a, _) => {
                 // Safety: within bounds
                 unsafe { (0..a.len()).find(|idx| a.value_unchecked(*idx) == value) }
                     .map(|idx| idx as u32)
@@ -112,33 +126,46 @@ pub struct CategoricalChunkedBuilder {
     array_builder: UInt32Vec,
     field: Field,
     reverse_mapping: RevMappingBuilder,
+    /// The string cache the categories are taken from. If `None` the categories are local.
+    string_cache: Option<Arc<StringCache>>,
 }
 
 impl CategoricalChunkedBuilder {
+    /// Create a builder that uses the global string cache if that is enabled.
     pub fn new(name: &str, capacity: usize) -> Self {
+        Self::new_in_cache(name, capacity, active_string_cache())
+    }
+
+    pub(crate) fn new_in_cache(
+        name: &str,
+        capacity: usize,
+        string_cache: Option<Arc<StringCache>>,
+    ) -> Self {
         let builder = MutableUtf8Array::<i64>::with_capacity(capacity / 10);
-        let reverse_mapping = if use_string_cache() {
-            let uuid = crate::STRING_CACHE.lock_map().uuid;
-            RevMappingBuilder::Global(PlHashMap::default(), builder, uuid)
-        } else {
-            RevMappingBuilder::Local(builder)
+        let reverse_mapping = match &string_cache {
+            Some(cache) => {
+                let uuid = cache.lock_map().uuid;
+                RevMappingBuilder::Global(PlHashMap::default(), builder, uuid)
+            }
+            None => RevMappingBuilder::Local(builder),
         };
 
         Self {
             array_builder: UInt32Vec::with_capacity(capacity),
             field: Field::new(name, DataType::Categorical),
             reverse_mapping,
+            string_cache,
         }
     }
 }
 impl CategoricalChunkedBuilder {
-    /// Appends all the values in a single lock of the global string cache.
+    /// Appends all the values in a single lock of the string cache.
     pub fn drain_iter<'a, I>(&mut self, i: I)
     where
         I: IntoIterator<Item = Option<&'a str>>,
     {
-        if use_string_cache() {
-            let mut cache = crate::STRING_CACHE.lock_map();
+        if let Some(cache) = &self.string_cache {
+            let mut cache = cache.lock_map();
 
             for opt_s in i {
                 match opt_s {
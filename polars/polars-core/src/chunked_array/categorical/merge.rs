@@ -65,6 +65,14 @@ impl CategoricalChunked {
 
                 Arc::new(RevMapping::Local(arr))
             }
+            (Some(RevMapping::Enum(_, dtype_l)), Some(RevMapping::Enum(_, dtype_r)))
+                if dtype_l == dtype_r =>
+            {
+                self.categorical_map.clone().unwrap()
+            }
+            (Some(RevMapping::Enum(..)), _) | (_, Some(RevMapping::Enum(..))) => {
+                panic!("cannot combine an Enum with values of another type")
+            }
             _ => panic!("cannot combine categorical under a global string cache with a non cached categorical")
         }
     }
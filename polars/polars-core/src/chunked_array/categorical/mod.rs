@@ -3,17 +3,79 @@ use arrow::array::DictionaryArray;
 use polars_arrow::compute::cast::cast;
 mod builder;
 mod merge;
+pub(crate) mod string_cache;
 
 pub use builder::*;
 use std::ops::{Deref, DerefMut};
 
+/// How the values of a [`CategoricalChunked`] are ordered by `sort`, `argsort`, `min`/`max`
+/// and comparisons.
+///
+/// The default is [`Physical`](CategoricalOrdering::Physical). As before, `sort` and `argsort`
+/// of a categorical without an explicit ordering order by the string values; set the ordering
+/// to [`Lexical`](CategoricalOrdering::Lexical) to also compare and take the `min`/`max` by the
+/// string values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CategoricalOrdering {
+    /// Order by the physical codes, i.e. the order in which the categories were first seen.
+    Physical,
+    /// Order by the string values.
+    Lexical,
+}
+
+impl Default for CategoricalOrdering {
+    fn default() -> Self {
+        CategoricalOrdering::Physical
+    }
+}
+
+/// Build the [`RevMapping`] of an `Enum` with the given categories.
+pub(crate) fn enum_rev_map(categories: &Arc<[String]>) -> Arc<RevMapping> {
+    let values = Utf8Array::<i64>::from_slice(categories);
+    Arc::new(RevMapping::Enum(values, DataType::Enum(categories.clone())))
+}
+
+impl Utf8Chunked {
+    /// Cast to an `Enum` with the given `categories`. Values that are not in `categories` are
+    /// an error.
+    pub(crate) fn to_enum(&self, categories: &Arc<[String]>) -> Result<CategoricalChunked> {
+        let lookup: PlHashMap<&str, u32> = categories
+            .iter()
+            .enumerate()
+            .map(|(idx, cat)| (cat.as_str(), idx as u32))
+            .collect();
+        if lookup.len() != categories.len() {
+            return Err(PolarsError::ValueError(
+                "the categories of an Enum must be unique".into(),
+            ));
+        }
+        let codes: UInt32Chunked = self
+            .into_iter()
+            .map(|opt_v| {
+                opt_v
+                    .map(|v| {
+                        lookup.get(v).copied().ok_or_else(|| {
+                            PolarsError::ComputeError(
+                                format!("value '{}' is not a category of the Enum", v).into(),
+                            )
+                        })
+                    })
+                    .transpose()
+            })
+            .collect::<Result<_>>()?;
+        let mut out = CategoricalChunked::from_chunks(self.name(), codes.chunks);
+        out.set_categorical_map(enum_rev_map(categories));
+        Ok(out)
+    }
+}
+
 impl From<&CategoricalChunked> for DictionaryArray<u32> {
     fn from(ca: &CategoricalChunked) -> Self {
         let ca = ca.rechunk();
         let keys = ca.downcast_iter().next().unwrap();
         let map = &**ca.categorical_map.as_ref().unwrap();
         match map {
-            RevMapping::Local(arr) => {
+            RevMapping::Local(arr) | RevMapping::Enum(arr, _) => {
                 DictionaryArray::from_data(keys.clone(), Arc::new(arr.clone()))
             }
             RevMapping::Global(reverse_map, values, _uuid) => {
@@ -33,7 +95,7 @@ impl From<&CategoricalChunked> for DictionaryArray<i64> {
         let keys = ca.downcast_iter().next().unwrap();
         let map = &**ca.categorical_map.as_ref().unwrap();
         match map {
-            RevMapping::Local(arr) => DictionaryArray::from_data(
+            RevMapping::Local(arr) | RevMapping::Enum(arr, _) => DictionaryArray::from_data(
                 cast(keys, &ArrowDataType::Int64)
                     .unwrap()
                     .as_any()
@@ -54,21 +116,126 @@ impl From<&CategoricalChunked> for DictionaryArray<i64> {
     }
 }
 
+/// The sorted unique strings of the categories of `cats`. The [`lexical_rank`]s in these
+/// strings can be compared across the categoricals.
+pub(crate) fn lexical_order<'a>(cats: &[&'a CategoricalChunked]) -> Vec<&'a str> {
+    let mut sorted = cats
+        .iter()
+        .flat_map(|ca| ca.local_categories().0.values_iter())
+        .collect::<Vec<_>>();
+    sorted.sort_unstable();
+    sorted.dedup();
+    sorted
+}
+
+/// The rank of `value` in the `sorted` strings of [`lexical_order`]. The strings in `sorted`
+/// get an odd rank and other strings the even rank between their neighbours, so comparing the
+/// ranks compares the strings.
+pub(crate) fn lexical_rank(sorted: &[&str], value: &str) -> u32 {
+    match sorted.binary_search(&value) {
+        Ok(idx) => 2 * idx as u32 + 1,
+        Err(idx) => 2 * idx as u32,
+    }
+}
+
 impl CategoricalChunked {
+    /// The string values of the categories and, for a global mapping, the map from the codes
+    /// to the index of their string.
+    fn local_categories(&self) -> (&Utf8Array<i64>, Option<&PlHashMap<u32, u32>>) {
+        match &**self.categorical_map.as_ref().unwrap() {
+            RevMapping::Local(arr) | RevMapping::Enum(arr, _) => (arr, None),
+            RevMapping::Global(map, arr, _) => (arr, Some(map)),
+        }
+    }
+
+    /// The [`lexical_rank`] of the category of every value. Comparing the ranks compares the
+    /// strings, while only the categories are looked up in `sorted`.
+    pub(crate) fn lexical_ranks(&self, sorted: &[&str]) -> UInt32Chunked {
+        let (categories, global) = self.local_categories();
+        let ranks = categories
+            .values_iter()
+            .map(|cat| lexical_rank(sorted, cat))
+            .collect::<Vec<_>>();
+        let mut out: UInt32Chunked = self
+            .deref()
+            .into_iter()
+            .map(|opt_code| {
+                opt_code.map(|code| ranks[global.map_or(code, |map| map[&code]) as usize])
+            })
+            .collect();
+        out.rename(self.name());
+        out
+    }
+
     /// Get a reference to the mapping of categorical types to the string values.
     pub fn get_categorical_map(&self) -> Option<&Arc<RevMapping>> {
         self.categorical_map.as_ref()
     }
 
+    /// The dtype of the values: `Enum` if the categories are fixed, `Categorical` otherwise.
+    pub(crate) fn logical_dtype(&self) -> &DataType {
+        match self
+            .categorical_map
+            .as_deref()
+            .and_then(|map| map.enum_dtype())
+        {
+            Some(dtype) => dtype,
+            None => self.ref_field().data_type(),
+        }
+    }
+
     pub(crate) fn set_categorical_map(&mut self, categorical_map: Arc<RevMapping>) {
         self.categorical_map = Some(categorical_map)
     }
 
     pub(crate) fn set_state<T>(mut self, other: &ChunkedArray<T>) -> Self {
         self.categorical_map = other.categorical_map.clone();
+        let ordering_bits = 1u8 << 5 | 1u8 << 6;
+        self.bit_settings =
+            (self.bit_settings & !ordering_bits) | (other.bit_settings & ordering_bits);
         self
     }
 
+    /// The ordering used by `sort`, `argsort`, `min`/`max` and comparisons.
+    ///
+    /// An `Enum` is always ordered by its categories.
+    pub fn ordering(&self) -> CategoricalOrdering {
+        if self.logical_dtype() == &DataType::Categorical && self.bit_settings & 1 << 6 != 0 {
+            CategoricalOrdering::Lexical
+        } else {
+            CategoricalOrdering::Physical
+        }
+    }
+
+    /// Set the ordering used by `sort`, `argsort`, `min`/`max` and comparisons. This has no
+    /// effect on an `Enum`.
+    pub fn set_ordering(&mut self, ordering: CategoricalOrdering) {
+        match ordering {
+            CategoricalOrdering::Physical => {
+                self.bit_settings = (self.bit_settings | 1u8 << 5) & !(1u8 << 6)
+            }
+            CategoricalOrdering::Lexical => {
+                self.bit_settings = (self.bit_settings | 1u8 << 6) & !(1u8 << 5)
+            }
+        }
+    }
+
+    pub(crate) fn uses_lexical_ordering(&self) -> bool {
+        self.ordering() == CategoricalOrdering::Lexical
+    }
+
+    /// Whether `sort` and `argsort` order by the string values. They do unless the ordering is
+    /// set to [`Physical`](CategoricalOrdering::Physical) explicitly.
+    pub(crate) fn sorts_lexically(&self) -> bool {
+        self.logical_dtype() == &DataType::Categorical && self.bit_settings & 1 << 5 == 0
+    }
+
+    /// Copy the categories and the ordering of `other`.
+    pub(crate) fn copy_cat_state(&mut self, other: &CategoricalChunked) {
+        self.categorical_map = other.categorical_map.clone();
+        self.set_ordering(other.ordering());
+    }
+
     pub(crate) fn can_fast_unique(&self) -> bool {
         self.bit_settings & 1 << 3 != 0 && self.chunks.len() == 1
     }
@@ -81,6 +248,33 @@ impl CategoricalChunked {
         }
     }
 
+    /// The index of the smallest or largest value according to the ordering of the values.
+    pub(crate) fn arg_min_max(&self, max: bool) -> Option<usize> {
+        if self.uses_lexical_ordering() {
+            let iter = self
+                .iter_str()
+                .enumerate()
+                .filter_map(|(idx, opt_v)| opt_v.map(|v| (idx, v)));
+            if max {
+                iter.max_by_key(|(_, v)| *v)
+            } else {
+                iter.min_by_key(|(_, v)| *v)
+            }
+            .map(|(idx, _)| idx)
+        } else {
+            let iter = self
+                .into_iter()
+                .enumerate()
+                .filter_map(|(idx, opt_v)| opt_v.map(|v| (idx, v)));
+            if max {
+                iter.max_by_key(|(_, v)| *v)
+            } else {
+                iter.min_by_key(|(_, v)| *v)
+            }
+            .map(|(idx, _)| idx)
+        }
+    }
+
     /// Create an `[Iterator]` that iterates over the `&str` values of the `[CategoricalChunked]`.
     pub fn iter_str(&self) -> CatIter<'_> {
         let iter = self.deref().into_iter();
@@ -210,4 +404,36 @@ mod test {
         let out = s.slice(1, 2);
         assert_eq!(out.n_unique().unwrap(), 2);
     }
+
+    #[test]
+    fn test_lexical_comparison() -> Result<()> {
+        let _lock = SINGLE_LOCK.lock();
+        reset_string_cache();
+        let a = Series::new("a", &[Some("c"), Some("a"), None, Some("b")])
+            .cast(&DataType::Categorical)?;
+        let b = Series::new("b", &[Some("b"), Some("a"), Some("a"), Some("d")])
+            .cast(&DataType::Categorical)?;
+        // the physical codes are in order of appearance
+        assert_eq!(a.categorical()?.ordering(), CategoricalOrdering::Physical);
+        let out = a.lt("b");
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(true), None, Some(false)]
+        );
+
+        let mut a = a.categorical()?.clone();
+        a.set_ordering(CategoricalOrdering::Lexical);
+        let a = a.into_series();
+        let out = a.gt(&b);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(false), None, Some(false)]
+        );
+        let out = a.lt_eq("bb");
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(false), Some(true), None, Some(true)]
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,241 @@
+use crate::datatypes::PlHashMap;
+use crate::prelude::*;
+use ahash::AHashMap;
+use lazy_static::lazy_static;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, Weak};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Distinguishes caches that are created in the same nanosecond.
+static CACHE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+pub(crate) struct SCacheInner {
+    pub(crate) map: AHashMap<String, u32>,
+    pub(crate) uuid: u128,
+}
+
+impl Default for SCacheInner {
+    fn default() -> Self {
+        Self {
+            map: Default::default(),
+            uuid: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+                .wrapping_add((CACHE_COUNTER.fetch_add(1, Ordering::Relaxed) as u128) << 96),
+        }
+    }
+}
+
+/// Used by categorical data that need to share categories.
+pub(crate) struct StringCache(pub(crate) Mutex<SCacheInner>);
+
+impl StringCache {
+    pub(crate) fn lock_map(&self) -> MutexGuard<SCacheInner> {
+        self.0.lock().unwrap()
+    }
+
+    pub(crate) fn clear(&self) {
+        let mut lock = self.lock_map();
+        *lock = Default::default();
+    }
+}
+
+impl Default for StringCache {
+    fn default() -> Self {
+        StringCache(Mutex::new(Default::default()))
+    }
+}
+
+/// Users of the global string cache.
+#[derive(Default)]
+struct GlobalCacheUsers {
+    /// Set by [`toggle_string_cache`].
+    toggled: bool,
+    /// Number of living [`StringCacheHolder`]s of the global cache.
+    holders: usize,
+}
+
+static USE_STRING_CACHE: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    pub(crate) static ref STRING_CACHE: Arc<StringCache> = Default::default();
+    static ref GLOBAL_CACHE_USERS: Mutex<GlobalCacheUsers> = Default::default();
+    static ref NAMED_STRING_CACHES: Mutex<PlHashMap<String, Weak<StringCache>>> =
+        Default::default();
+}
+
+/// Update the users of the global string cache. The cache is cleared once it isn't used anymore.
+fn update_global_cache_users<F: FnOnce(&mut GlobalCacheUsers)>(f: F) {
+    let mut users = GLOBAL_CACHE_USERS.lock().unwrap();
+    f(&mut users);
+    let in_use = users.toggled || users.holders > 0;
+    USE_STRING_CACHE.store(in_use, Ordering::Release);
+    if !in_use {
+        STRING_CACHE.clear()
+    }
+}
+
+/// Use a global string cache for the Categorical Types.
+///
+/// This is used to cache the string categories locally.
+/// This allows join operations on categorical types.
+///
+/// Turning the cache off doesn't clear it while a [`StringCacheHolder`] holds it.
+pub fn toggle_string_cache(toggle: bool) {
+    update_global_cache_users(|users| users.toggled = toggle)
+}
+
+/// Reset the global string cache used for the Categorical Types.
+///
+/// This is a no-op while a [`StringCacheHolder`] holds the cache, as the categoricals that are
+/// created under the holder would otherwise not share their categories.
+pub fn reset_string_cache() {
+    let users = GLOBAL_CACHE_USERS.lock().unwrap();
+    if users.holders == 0 {
+        STRING_CACHE.clear()
+    }
+}
+
+/// Check if string cache is set.
+pub(crate) fn use_string_cache() -> bool {
+    USE_STRING_CACHE.load(Ordering::Acquire)
+}
+
+/// The string cache new categoricals are created in, if any.
+pub(crate) fn active_string_cache() -> Option<Arc<StringCache>> {
+    if use_string_cache() {
+        Some(STRING_CACHE.clone())
+    } else {
+        None
+    }
+}
+
+/// Keeps a string cache alive for as long as the holder lives.
+///
+/// Holders are reference counted, so they can be nested and used from different threads
+/// without clearing the cache under each other's feet.
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::prelude::*;
+/// use polars_core::StringCacheHolder;
+///
+/// # fn main() -> Result<()> {
+/// let _holder = StringCacheHolder::hold();
+/// let a = Series::new("a", &["x", "y"]).cast(&DataType::Categorical)?;
+/// let b = Series::new("b", &["y", "z"]).cast(&DataType::Categorical)?;
+/// // created under the same cache, so they can be combined
+/// let mut a = a.categorical()?.clone();
+/// a.append(b.categorical()?);
+/// # Ok(())
+/// # }
+/// ```
+pub struct StringCacheHolder {
+    /// `None` for the global cache.
+    named: Option<Arc<StringCache>>,
+}
+
+impl StringCacheHolder {
+    /// Hold the global string cache. Categoricals are created in the global cache while any
+    /// holder exists, and the cache is cleared when the last holder is dropped (unless it was
+    /// turned on with [`toggle_string_cache`]).
+    pub fn hold() -> Self {
+        update_global_cache_users(|users| users.holders += 1);
+        StringCacheHolder { named: None }
+    }
+
+    /// Hold the string cache called `name`. Holders with the same name share their cache,
+    /// which is freed when the last of them is dropped.
+    ///
+    /// Named caches are independent of the global cache and of each other, so unrelated
+    /// categories don't have to share one map. Categoricals are only created in a named cache
+    /// with [`StringCacheHolder::to_categorical`].
+    pub fn named(name: &str) -> Self {
+        let mut caches = NAMED_STRING_CACHES.lock().unwrap();
+        caches.retain(|_, cache| cache.strong_count() > 0);
+        let cache = match caches.get(name).and_then(|cache| cache.upgrade()) {
+            Some(cache) => cache,
+            None => {
+                let cache: Arc<StringCache> = Default::default();
+                caches.insert(name.to_string(), Arc::downgrade(&cache));
+                cache
+            }
+        };
+        StringCacheHolder { named: Some(cache) }
+    }
+
+    pub(crate) fn cache(&self) -> Arc<StringCache> {
+        match &self.named {
+            Some(cache) => cache.clone(),
+            None => STRING_CACHE.clone(),
+        }
+    }
+
+    /// Cast a `Utf8` `Series` to `Categorical`, with the categories of this cache.
+    pub fn to_categorical(&self, s: &Series) -> Result<Series> {
+        let ca = s.utf8()?;
+        let mut builder =
+            CategoricalChunkedBuilder::new_in_cache(ca.name(), ca.len(), Some(self.cache()));
+        builder.drain_iter(ca.into_iter());
+        Ok(builder.finish().into_series())
+    }
+}
+
+impl Drop for StringCacheHolder {
+    fn drop(&mut self) {
+        if self.named.is_none() {
+            update_global_cache_users(|users| users.holders -= 1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SINGLE_LOCK;
+
+    #[test]
+    fn test_string_cache_holder() -> Result<()> {
+        let _lock = SINGLE_LOCK.lock();
+        toggle_string_cache(false);
+        let s = Series::new("a", &["a", "b"]);
+
+        let outer = StringCacheHolder::hold();
+        let inner = StringCacheHolder::hold();
+        let a = s.cast(&DataType::Categorical)?;
+        drop(inner);
+        // the outer holder keeps the cache alive
+        assert!(use_string_cache());
+        reset_string_cache();
+        let b = s.cast(&DataType::Categorical)?;
+        assert!(a
+            .categorical()?
+            .get_categorical_map()
+            .unwrap()
+            .same_src(b.categorical()?.get_categorical_map().unwrap()));
+        drop(outer);
+        assert!(!use_string_cache());
+        assert!(STRING_CACHE.lock_map().map.is_empty());
+
+        // named caches are separate
+        let x = StringCacheHolder::named("x");
+        let x2 = StringCacheHolder::named("x");
+        let y = StringCacheHolder::named("y");
+        let a = x.to_categorical(&s)?;
+        let b = x2.to_categorical(&s)?;
+        let c = y.to_categorical(&s)?;
+        let map = |s: &Series| {
+            s.categorical()
+                .unwrap()
+                .get_categorical_map()
+                .unwrap()
+                .clone()
+        };
+        assert!(map(&a).same_src(&map(&b)));
+        assert!(!map(&a).same_src(&map(&c)));
+        assert!(!use_string_cache());
+        Ok(())
+    }
+}
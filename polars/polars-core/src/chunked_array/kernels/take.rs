@@ -37,7 +37,7 @@ pub(crate) fn take_primitive_opt_iter_n_chunks<
 /// No bounds checks
 pub(crate) unsafe fn take_list_unchecked(
     values: &ListArray<i64>,
-    indices: &UInt32Array,
+    indices: &IdxArr,
 ) -> ListArray<i64> {
     // taking the whole list or a contiguous sublist
     let (list_indices, offsets) = take_value_indices_from_list(values, indices);
@@ -45,7 +45,7 @@ pub(crate) unsafe fn take_list_unchecked(
     // tmp series so that we can take primitives from it
     let s = Series::try_from(("", values.values().clone() as ArrayRef)).unwrap();
     let taken = s
-        .take_unchecked(&UInt32Chunked::from_chunks(
+        .take_unchecked(&IdxCa::from_chunks(
             "",
             vec![Arc::new(list_indices) as ArrayRef],
         ))
@@ -15,6 +15,7 @@ use std::sync::Arc;
 trait PolarsWeekDay {
     fn p_weekday(&self) -> u32;
     fn week(&self) -> u32;
+    fn iso_year(&self) -> i32;
 }
 
 impl PolarsWeekDay for NaiveDateTime {
@@ -24,6 +25,9 @@ impl PolarsWeekDay for NaiveDateTime {
     fn week(&self) -> u32 {
         self.iso_week().week()
     }
+    fn iso_year(&self) -> i32 {
+        self.iso_week().year()
+    }
 }
 
 impl PolarsWeekDay for NaiveDate {
@@ -33,6 +37,9 @@ impl PolarsWeekDay for NaiveDate {
     fn week(&self) -> u32 {
         self.iso_week().week()
     }
+    fn iso_year(&self) -> i32 {
+        self.iso_week().year()
+    }
 }
 
 macro_rules! to_temporal_unit {
@@ -98,6 +105,14 @@ to_temporal_unit!(
     i32,
     ArrowDataType::UInt32
 );
+#[cfg(feature = "dtype-date")]
+to_temporal_unit!(
+    date_to_iso_year,
+    iso_year,
+    date32_to_datetime,
+    i32,
+    ArrowDataType::Int32
+);
 
 // Times
 #[cfg(feature = "dtype-time")]
@@ -214,6 +229,14 @@ to_temporal_unit!(
     i64,
     ArrowDataType::UInt32
 );
+#[cfg(feature = "dtype-datetime")]
+to_temporal_unit!(
+    datetime_to_iso_year_ns,
+    iso_year,
+    timestamp_ns_to_datetime,
+    i64,
+    ArrowDataType::Int32
+);
 
 // Datetimes milliseconds
 
@@ -297,6 +320,14 @@ to_temporal_unit!(
     i64,
     ArrowDataType::UInt32
 );
+#[cfg(feature = "dtype-datetime")]
+to_temporal_unit!(
+    datetime_to_iso_year_ms,
+    iso_year,
+    timestamp_ms_to_datetime,
+    i64,
+    ArrowDataType::Int32
+);
 // microseconds
 #[cfg(feature = "dtype-datetime")]
 to_temporal_unit!(
@@ -378,3 +409,11 @@ to_temporal_unit!(
     i64,
     ArrowDataType::UInt32
 );
+#[cfg(feature = "dtype-datetime")]
+to_temporal_unit!(
+    datetime_to_iso_year_us,
+    iso_year,
+    timestamp_us_to_datetime,
+    i64,
+    ArrowDataType::Int32
+);
@@ -2,6 +2,8 @@
 mod iterator;
 #[cfg(feature = "list")]
 pub mod namespace;
+#[cfg(feature = "list")]
+pub(crate) mod sets;
 
 use crate::prelude::*;
 
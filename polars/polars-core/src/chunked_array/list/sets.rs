@@ -0,0 +1,363 @@
+//! Set operations between the sublists of two list columns and item lookups in sublists.
+//!
+//! Items are hashed on their physical bit representation, strings and categoricals on their
+//! string value. A null item is a value like any other and equals other null items.
+use crate::prelude::*;
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use std::convert::TryFrom;
+use std::hash::Hash;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SetOperation {
+    /// The items that are in either sublist.
+    Union,
+    /// The items that are in both sublists.
+    Intersection,
+    /// The items of the left sublist that are not in the right sublist.
+    Difference,
+    /// The items that are in exactly one of the sublists.
+    SymmetricDifference,
+}
+
+/// The items of all sublists, the offsets of the sublists in the items and the validity.
+/// Categorical items are returned as strings.
+fn list_values(ca: &ListChunked) -> Result<(Series, Vec<i64>, Option<Bitmap>)> {
+    let ca = ca.rechunk();
+    let arr = ca
+        .downcast_iter()
+        .next()
+        .ok_or_else(|| PolarsError::NoData("cannot run set operations on empty lists".into()))?;
+    let offsets = arr.offsets().as_slice();
+    let first = offsets[0];
+    let values = arr.values().slice(
+        first as usize,
+        (offsets[offsets.len() - 1] - first) as usize,
+    );
+    let values = Series::try_from(("", Arc::from(values) as ArrayRef))?;
+    let values = match ca.inner_dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical | DataType::Enum(_) => {
+            let mut cats = values.u32()?.clone();
+            cats.categorical_map = ca.categorical_map.clone();
+            cats.cast(&DataType::Categorical)?.cast(&DataType::Utf8)?
+        }
+        dt => values.cast(&dt)?,
+    };
+    let offsets = offsets.iter().map(|o| o - first).collect();
+    Ok((values, offsets, arr.validity().cloned()))
+}
+
+/// Push the indexes of the items of `op` between sublists `a` and `b`. The indexes of `b` are
+/// offset by `b_offset`. Every distinct item is pushed once, in order of first appearance.
+fn set_op_indexes<K: Hash + Eq + Copy>(
+    a: &[Option<K>],
+    b: &[Option<K>],
+    a_offset: IdxSize,
+    b_offset: IdxSize,
+    op: SetOperation,
+    out: &mut Vec<IdxSize>,
+) {
+    let mut seen = PlHashSet::with_capacity(a.len());
+    let mut push_new = |iter: &mut dyn Iterator<Item = (usize, &Option<K>)>, offset: IdxSize| {
+        for (i, k) in iter {
+            if seen.insert(*k) {
+                out.push(offset + i as IdxSize)
+            }
+        }
+    };
+    match op {
+        SetOperation::Union => {
+            push_new(&mut a.iter().enumerate(), a_offset);
+            push_new(&mut b.iter().enumerate(), b_offset);
+        }
+        SetOperation::Intersection => {
+            let b_set = b.iter().collect::<PlHashSet<_>>();
+            push_new(
+                &mut a.iter().enumerate().filter(|(_, k)| b_set.contains(k)),
+                a_offset,
+            );
+        }
+        SetOperation::Difference => {
+            let b_set = b.iter().collect::<PlHashSet<_>>();
+            push_new(
+                &mut a.iter().enumerate().filter(|(_, k)| !b_set.contains(k)),
+                a_offset,
+            );
+        }
+        SetOperation::SymmetricDifference => {
+            let a_set = a.iter().collect::<PlHashSet<_>>();
+            let b_set = b.iter().collect::<PlHashSet<_>>();
+            push_new(
+                &mut a.iter().enumerate().filter(|(_, k)| !b_set.contains(k)),
+                a_offset,
+            );
+            push_new(
+                &mut b.iter().enumerate().filter(|(_, k)| !a_set.contains(k)),
+                b_offset,
+            );
+        }
+    }
+}
+
+/// Compute the indexes of the output items in the concatenated items of both lists, the
+/// output offsets and the output validity. If `b` has a single sublist it is broadcast.
+fn set_op_impl<K: Hash + Eq + Copy>(
+    a: &[Option<K>],
+    a_offsets: &[i64],
+    a_validity: Option<&Bitmap>,
+    b: &[Option<K>],
+    b_offsets: &[i64],
+    b_validity: Option<&Bitmap>,
+    op: SetOperation,
+) -> (Vec<IdxSize>, Vec<i64>, Option<Bitmap>) {
+    let len = a_offsets.len() - 1;
+    let broadcast = b_offsets.len() == 2;
+    let mut idx = Vec::with_capacity(a.len());
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0i64);
+    let mut validity = MutableBitmap::with_capacity(len);
+
+    for i in 0..len {
+        let j = if broadcast { 0 } else { i };
+        let valid =
+            a_validity.map_or(true, |v| v.get_bit(i)) && b_validity.map_or(true, |v| v.get_bit(j));
+        if valid {
+            let (a_start, a_end) = (a_offsets[i] as usize, a_offsets[i + 1] as usize);
+            let (b_start, b_end) = (b_offsets[j] as usize, b_offsets[j + 1] as usize);
+            set_op_indexes(
+                &a[a_start..a_end],
+                &b[b_start..b_end],
+                a_start as IdxSize,
+                (a.len() + b_start) as IdxSize,
+                op,
+                &mut idx,
+            );
+        }
+        validity.push(valid);
+        offsets.push(idx.len() as i64);
+    }
+    (idx, offsets, Some(validity.into()))
+}
+
+/// Count the items of every sublist that are equal to `value`. If `value` has a single item it
+/// is broadcast.
+fn count_match_impl<K: Eq>(
+    keys: &[Option<K>],
+    offsets: &[i64],
+    validity: Option<&Bitmap>,
+    value: &[Option<K>],
+    name: &str,
+) -> UInt32Chunked {
+    let broadcast = value.len() == 1;
+    let mut ca: UInt32Chunked = (0..offsets.len() - 1)
+        .map(|i| {
+            if !validity.map_or(true, |v| v.get_bit(i)) {
+                return None;
+            }
+            let v = if broadcast { &value[0] } else { &value[i] };
+            let sublist = &keys[offsets[i] as usize..offsets[i + 1] as usize];
+            Some(sublist.iter().filter(|k| *k == v).count() as u32)
+        })
+        .collect();
+    ca.rename(name);
+    ca
+}
+
+impl ListChunked {
+    /// Apply a set operation between the sublists of `self` and `other`, row by row.
+    ///
+    /// The output sublists contain every item once, in order of first appearance with the
+    /// items of `self` first. A null sublist on either side results in a null sublist.
+    /// `other` may have a single sublist, that is compared to every sublist of `self`.
+    ///
+    /// Supported item types are numeric, temporal, boolean, Utf8 and Categorical.
+    pub fn lst_set_operation(&self, other: &ListChunked, op: SetOperation) -> Result<ListChunked> {
+        if other.len() != self.len() && other.len() != 1 {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "list set operation between lengths {} and {} is not supported",
+                    self.len(),
+                    other.len()
+                )
+                .into(),
+            ));
+        }
+        let inner_dtype = match self.dtype() {
+            DataType::List(inner) => *inner.clone(),
+            _ => unreachable!(),
+        };
+        let other = if other.dtype() != self.dtype() {
+            other.cast(self.dtype())?.list()?.clone()
+        } else {
+            other.clone()
+        };
+
+        let (a, a_offsets, a_validity) = list_values(self)?;
+        let (b, b_offsets, b_validity) = list_values(&other)?;
+        let (idx, offsets, validity) = match a.dtype() {
+            DataType::Utf8 => {
+                let a_keys = a.utf8()?.into_iter().collect::<Vec<_>>();
+                let b_keys = b.utf8()?.into_iter().collect::<Vec<_>>();
+                set_op_impl(
+                    &a_keys,
+                    &a_offsets,
+                    a_validity.as_ref(),
+                    &b_keys,
+                    &b_offsets,
+                    b_validity.as_ref(),
+                    op,
+                )
+            }
+            _ => set_op_impl(
+                &a.bit_keys()?,
+                &a_offsets,
+                a_validity.as_ref(),
+                &b.bit_keys()?,
+                &b_offsets,
+                b_validity.as_ref(),
+                op,
+            ),
+        };
+
+        let mut values = a;
+        values.append(&b)?;
+        let mut values = values.take(&IdxCa::from_vec("", idx))?;
+        if matches!(inner_dtype, DataType::Categorical | DataType::Enum(_)) {
+            values = values.cast(&inner_dtype)?;
+        }
+        let values = values.rechunk();
+        let physical = values.to_physical_repr();
+        let arr_values = physical.chunks()[0].clone();
+        let data_type = ListArray::<i64>::default_datatype(arr_values.data_type().clone());
+        let arr = ListArray::<i64>::from_data(data_type, offsets.into(), arr_values, validity);
+
+        let mut out = ListChunked::from_chunks(self.name(), vec![Arc::new(arr)]);
+        match inner_dtype {
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical | DataType::Enum(_) => {
+                out.to_logical(inner_dtype);
+                out.categorical_map = values.categorical()?.categorical_map.clone();
+            }
+            dt if dt.is_logical() => out.to_logical(dt),
+            _ => {}
+        }
+        Ok(out)
+    }
+
+    /// Count the items of every sublist that are equal to `value`. `value` has a single item
+    /// or an item per sublist. A null `value` counts the null items. Null sublists result in
+    /// a null count.
+    pub fn lst_count_match(&self, value: &Series) -> Result<UInt32Chunked> {
+        if value.len() != self.len() && value.len() != 1 {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot match {} values against {} sublists",
+                    value.len(),
+                    self.len()
+                )
+                .into(),
+            ));
+        }
+        let (values, offsets, validity) = list_values(self)?;
+        let value = value.cast(values.dtype())?;
+        Ok(match values.dtype() {
+            DataType::Utf8 => {
+                let keys = values.utf8()?.into_iter().collect::<Vec<_>>();
+                let value = value.utf8()?.into_iter().collect::<Vec<_>>();
+                count_match_impl(&keys, &offsets, validity.as_ref(), &value, self.name())
+            }
+            _ => count_match_impl(
+                &values.bit_keys()?,
+                &offsets,
+                validity.as_ref(),
+                &value.bit_keys()?,
+                self.name(),
+            ),
+        })
+    }
+
+    /// Check if the sublists contain `value`. `value` has a single item or an item per
+    /// sublist. A null `value` looks for null items. Null sublists result in null.
+    pub fn lst_contains(&self, value: &Series) -> Result<BooleanChunked> {
+        Ok(self.lst_count_match(value)?.gt(0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn list(values: &[Option<&[i32]>]) -> ListChunked {
+        let mut builder = ListPrimitiveChunkedBuilder::<i32>::new("a", 10, 10, DataType::Int32);
+        for v in values {
+            builder.append_slice(*v);
+        }
+        builder.finish()
+    }
+
+    fn to_vecs(ca: &ListChunked) -> Vec<Option<Vec<Option<i32>>>> {
+        ca.into_iter()
+            .map(|opt_s| opt_s.map(|s| Vec::from(s.i32().unwrap())))
+            .collect()
+    }
+
+    #[test]
+    fn test_list_set_operations() -> Result<()> {
+        let a = list(&[Some(&[1, 2, 2, 3]), Some(&[]), None, Some(&[4, 5])]);
+        let b = list(&[Some(&[3, 4, 1]), Some(&[1]), Some(&[1]), Some(&[5, 6])]);
+
+        let out = a.lst_set_operation(&b, SetOperation::Union)?;
+        assert_eq!(
+            to_vecs(&out),
+            &[
+                Some(vec![Some(1), Some(2), Some(3), Some(4)]),
+                Some(vec![Some(1)]),
+                None,
+                Some(vec![Some(4), Some(5), Some(6)])
+            ]
+        );
+        let out = a.lst_set_operation(&b, SetOperation::Intersection)?;
+        assert_eq!(
+            to_vecs(&out),
+            &[
+                Some(vec![Some(1), Some(3)]),
+                Some(vec![]),
+                None,
+                Some(vec![Some(5)])
+            ]
+        );
+        let out = a.lst_set_operation(&b, SetOperation::Difference)?;
+        assert_eq!(
+            to_vecs(&out),
+            &[Some(vec![Some(2)]), Some(vec![]), None, Some(vec![Some(4)])]
+        );
+        let out = a.lst_set_operation(&b, SetOperation::SymmetricDifference)?;
+        assert_eq!(
+            to_vecs(&out),
+            &[
+                Some(vec![Some(2), Some(4)]),
+                Some(vec![Some(1)]),
+                None,
+                Some(vec![Some(4), Some(6)])
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_contains() -> Result<()> {
+        let a = list(&[Some(&[1, 2, 2]), Some(&[3]), None]);
+        let out = a.lst_count_match(&Series::new("", &[2]))?;
+        assert_eq!(Vec::from(&out), &[Some(2), Some(0), None]);
+        let out = a.lst_contains(&Series::new("", &[1, 3, 3]))?;
+        assert_eq!(Vec::from(&out), &[Some(true), Some(true), None]);
+
+        let s = Series::new("", &[Some("a"), None]);
+        let b = s.reshape(&[1, -1])?;
+        let out = b
+            .list()?
+            .lst_count_match(&Series::new("", &[Option::<&str>::None]))?;
+        assert_eq!(Vec::from(&out), &[Some(1)]);
+        Ok(())
+    }
+}
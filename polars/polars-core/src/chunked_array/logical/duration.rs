@@ -44,6 +44,16 @@ impl LogicalType for DurationChunked {
                     .into_duration(TimeUnit::Microseconds)
                     .into_series())
             }
+            (Duration(TimeUnit::Microseconds), Duration(TimeUnit::Milliseconds)) => {
+                Ok((self.0.as_ref() / 1_000i64)
+                    .into_duration(TimeUnit::Milliseconds)
+                    .into_series())
+            }
+            (Duration(TimeUnit::Microseconds), Duration(TimeUnit::Nanoseconds)) => {
+                Ok((self.0.as_ref() * 1_000i64)
+                    .into_duration(TimeUnit::Nanoseconds)
+                    .into_series())
+            }
             _ => self.0.cast(dtype),
         }
     }
@@ -61,7 +61,7 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let mut s = Series::try_from(("", v)).unwrap();
 
             match **dt {
-                DataType::Categorical => {
+                DataType::Categorical | DataType::Enum(_) => {
                     let mut s_new = s.cast(&DataType::Categorical).unwrap();
                     let ca: &mut CategoricalChunked = s_new.get_inner_mut().as_mut();
                     ca.categorical_map = categorical_map.clone();
@@ -77,7 +77,7 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             AnyValue::List(s)
         }
         #[cfg(feature = "dtype-categorical")]
-        DataType::Categorical => {
+        DataType::Categorical | DataType::Enum(_) => {
             let idx = downcast!(UInt32Array);
             let rev_map = &**categorical_map.as_ref().unwrap();
             AnyValue::Categorical(idx, rev_map)
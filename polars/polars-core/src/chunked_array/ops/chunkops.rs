@@ -127,8 +127,7 @@ impl ChunkOps for CategoricalChunked {
         Self: std::marker::Sized,
     {
         let mut out: CategoricalChunked = self.deref().rechunk().into();
-        let cat_map = self.categorical_map.clone();
-        out.categorical_map = cat_map;
+        out.copy_cat_state(self);
         out
     }
     #[inline]
@@ -10,6 +10,8 @@ use crate::chunked_array::ops::take::take_random::{
 use crate::chunked_array::ops::take::take_random::{ObjectTakeRandom, ObjectTakeRandomSingleChunk};
 use crate::prelude::*;
 use std::cmp::{Ordering, PartialEq};
+#[cfg(feature = "dtype-categorical")]
+use std::ops::Deref;
 
 pub trait PartialEqInner: Send + Sync {
     /// Safety:
@@ -112,7 +114,7 @@ where
         } else {
             let t = NumTakeRandomChunked::<'_, T::Native> {
                 chunks: chunks.collect(),
-                chunk_lens: self.chunks.iter().map(|a| a.len() as u32).collect(),
+                chunk_lens: self.chunks.iter().map(|a| a.len() as IdxSize).collect(),
             };
             Box::new(t)
         }
@@ -131,7 +133,7 @@ impl<'a> IntoPartialEqInner<'a> for &'a Utf8Chunked {
                 let chunks = self.downcast_chunks();
                 let t = Utf8TakeRandom {
                     chunks,
-                    chunk_lens: self.chunks.iter().map(|a| a.len() as u32).collect(),
+                    chunk_lens: self.chunks.iter().map(|a| a.len() as IdxSize).collect(),
                 };
                 Box::new(t)
             }
@@ -151,7 +153,7 @@ impl<'a> IntoPartialEqInner<'a> for &'a BooleanChunked {
                 let chunks = self.downcast_chunks();
                 let t = BoolTakeRandom {
                     chunks,
-                    chunk_lens: self.chunks.iter().map(|a| a.len() as u32).collect(),
+                    chunk_lens: self.chunks.iter().map(|a| a.len() as IdxSize).collect(),
                 };
                 Box::new(t)
             }
@@ -225,7 +227,7 @@ where
         } else {
             let t = NumTakeRandomChunked::<'_, T::Native> {
                 chunks: chunks.collect(),
-                chunk_lens: self.chunks.iter().map(|a| a.len() as u32).collect(),
+                chunk_lens: self.chunks.iter().map(|a| a.len() as IdxSize).collect(),
             };
             Box::new(t)
         }
@@ -244,7 +246,7 @@ impl<'a> IntoPartialOrdInner<'a> for &'a Utf8Chunked {
                 let chunks = self.downcast_chunks();
                 let t = Utf8TakeRandom {
                     chunks,
-                    chunk_lens: self.chunks.iter().map(|a| a.len() as u32).collect(),
+                    chunk_lens: self.chunks.iter().map(|a| a.len() as IdxSize).collect(),
                 };
                 Box::new(t)
             }
@@ -264,7 +266,7 @@ impl<'a> IntoPartialOrdInner<'a> for &'a BooleanChunked {
                 let chunks = self.downcast_chunks();
                 let t = BoolTakeRandom {
                     chunks,
-                    chunk_lens: self.chunks.iter().map(|a| a.len() as u32).collect(),
+                    chunk_lens: self.chunks.iter().map(|a| a.len() as IdxSize).collect(),
                 };
                 Box::new(t)
             }
@@ -278,10 +280,43 @@ impl<'a> IntoPartialOrdInner<'a> for &'a ListChunked {
     }
 }
 
+#[cfg(feature = "dtype-categorical")]
+struct CategoricalTakeRandomLexical<'a> {
+    codes: NumTakeRandomChunked<'a, u32>,
+    rev_map: &'a RevMapping,
+}
+
+#[cfg(feature = "dtype-categorical")]
+impl PartialOrdInner for CategoricalTakeRandomLexical<'_> {
+    #[inline]
+    unsafe fn cmp_element_unchecked(&self, idx_a: usize, idx_b: usize) -> Ordering {
+        let a = self
+            .codes
+            .get(idx_a)
+            .map(|idx| self.rev_map.get_unchecked(idx));
+        let b = self
+            .codes
+            .get(idx_b)
+            .map(|idx| self.rev_map.get_unchecked(idx));
+        a.cmp(&b)
+    }
+}
+
 #[cfg(feature = "dtype-categorical")]
 impl<'a> IntoPartialOrdInner<'a> for &'a CategoricalChunked {
-    fn into_partial_ord_inner(self) -> Box<dyn PartialOrdInner> {
-        unimplemented!()
+    fn into_partial_ord_inner(self) -> Box<dyn PartialOrdInner + 'a> {
+        if self.uses_lexical_ordering() {
+            let t = CategoricalTakeRandomLexical {
+                codes: NumTakeRandomChunked {
+                    chunks: self.downcast_iter().collect(),
+                    chunk_lens: self.chunks.iter().map(|a| a.len() as IdxSize).collect(),
+                },
+                rev_map: self.get_categorical_map().unwrap(),
+            };
+            Box::new(t)
+        } else {
+            self.deref().into_partial_ord_inner()
+        }
     }
 }
 
@@ -320,7 +355,7 @@ impl<'a, T: PolarsObject> IntoPartialEqInner<'a> for &'a ObjectChunked<T> {
                 let chunks = self.downcast_chunks();
                 let t = ObjectTakeRandom {
                     chunks,
-                    chunk_lens: self.chunks.iter().map(|a| a.len() as u32).collect(),
+                    chunk_lens: self.chunks.iter().map(|a| a.len() as IdxSize).collect(),
                 };
                 Box::new(t)
             }
@@ -0,0 +1,195 @@
+//! Estimate the number of bytes that are held by the buffers of arrays.
+#[cfg(feature = "dtype-categorical")]
+use crate::chunked_array::categorical::RevMapping;
+#[cfg(feature = "object")]
+use crate::chunked_array::object::ObjectArray;
+use crate::prelude::*;
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::{PhysicalType, PrimitiveType};
+
+/// Collects the memory ranges of the buffers of arrays, so that a buffer that is shared between
+/// arrays (e.g. the slices of an array) is only counted once.
+#[derive(Default)]
+pub struct SizeEstimator {
+    ranges: Vec<(usize, usize)>,
+    #[cfg(feature = "dtype-categorical")]
+    rev_maps: PlHashSet<usize>,
+    /// Bytes of which we don't know the memory range.
+    untracked: usize,
+}
+
+impl SizeEstimator {
+    fn add_slice<T>(&mut self, slice: &[T]) {
+        let len = std::mem::size_of_val(slice);
+        if len > 0 {
+            let start = slice.as_ptr() as usize;
+            self.ranges.push((start, start + len));
+        }
+    }
+
+    fn add_validity(&mut self, validity: Option<&Bitmap>) {
+        if let Some(validity) = validity {
+            self.add_slice(validity.as_slice().0)
+        }
+    }
+
+    pub(crate) fn add_array(&mut self, arr: &dyn Array) {
+        macro_rules! primitive {
+            ($T:ty) => {{
+                let arr = arr.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+                self.add_slice(arr.values().as_slice());
+                self.add_validity(arr.validity());
+            }};
+        }
+        macro_rules! binary {
+            ($T:ty) => {{
+                let arr = arr.as_any().downcast_ref::<$T>().unwrap();
+                self.add_slice(arr.offsets().as_slice());
+                self.add_slice(arr.values().as_slice());
+                self.add_validity(arr.validity());
+            }};
+        }
+
+        use PhysicalType::*;
+        match arr.data_type().to_physical_type() {
+            Null => {}
+            Boolean => {
+                let arr = arr.as_any().downcast_ref::<BooleanArray>().unwrap();
+                self.add_slice(arr.values().as_slice().0);
+                self.add_validity(arr.validity());
+            }
+            Primitive(PrimitiveType::Int8) => primitive!(i8),
+            Primitive(PrimitiveType::Int16) => primitive!(i16),
+            Primitive(PrimitiveType::Int32) => primitive!(i32),
+            Primitive(PrimitiveType::Int64) => primitive!(i64),
+            Primitive(PrimitiveType::UInt8) => primitive!(u8),
+            Primitive(PrimitiveType::UInt16) => primitive!(u16),
+            Primitive(PrimitiveType::UInt32) => primitive!(u32),
+            Primitive(PrimitiveType::UInt64) => primitive!(u64),
+            Primitive(PrimitiveType::Float32) => primitive!(f32),
+            Primitive(PrimitiveType::Float64) => primitive!(f64),
+            // polars only creates the large variants
+            LargeUtf8 => binary!(Utf8Array<i64>),
+            LargeBinary => binary!(BinaryArray<i64>),
+            LargeList => {
+                let arr = arr.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+                self.add_slice(arr.offsets().as_slice());
+                self.add_validity(arr.validity());
+                self.add_array(arr.values().as_ref());
+            }
+            _ => self.untracked += arrow::compute::aggregate::estimated_bytes_size(arr),
+        }
+    }
+
+    #[cfg(feature = "dtype-categorical")]
+    fn add_rev_map(&mut self, rev_map: &Arc<RevMapping>) {
+        // the mapping is shared by all arrays that have the same categories
+        if !self.rev_maps.insert(Arc::as_ptr(rev_map) as usize) {
+            return;
+        }
+        match &**rev_map {
+            RevMapping::Local(arr) | RevMapping::Enum(arr, _) => self.add_array(arr),
+            RevMapping::Global(map, arr, _) => {
+                // a hashbrown table has a control byte per bucket
+                self.untracked += map.capacity() * (std::mem::size_of::<(u32, u32)>() + 1);
+                self.add_array(arr)
+            }
+        }
+    }
+
+    pub(crate) fn add_chunked_array<T>(&mut self, ca: &ChunkedArray<T>) {
+        for arr in &ca.chunks {
+            self.add_array(arr.as_ref())
+        }
+        #[cfg(feature = "dtype-categorical")]
+        if let Some(rev_map) = &ca.categorical_map {
+            self.add_rev_map(rev_map)
+        }
+    }
+
+    /// Objects are counted by their stack size, memory they own on the heap is not included.
+    #[cfg(feature = "object")]
+    fn add_objects<T: PolarsObject>(&mut self, arr: &ObjectArray<T>) {
+        self.add_slice(&arr.values[arr.offset..arr.offset + arr.len]);
+        self.add_validity(arr.null_bitmap.as_ref());
+    }
+
+    /// The number of bytes of all ranges, where overlapping ranges are counted once.
+    pub(crate) fn finish(mut self) -> usize {
+        self.ranges.sort_unstable();
+        let mut size = self.untracked;
+        let mut ranges = self.ranges.into_iter();
+        if let Some((mut start, mut end)) = ranges.next() {
+            for (next_start, next_end) in ranges {
+                if next_start <= end {
+                    end = std::cmp::max(end, next_end);
+                } else {
+                    size += end - start;
+                    start = next_start;
+                    end = next_end;
+                }
+            }
+            size += end - start;
+        }
+        size
+    }
+}
+
+macro_rules! impl_estimated_size {
+    ($ca:ty) => {
+        impl ChunkEstimatedSize for $ca {
+            fn add_to_size_estimate(&self, estimator: &mut SizeEstimator) {
+                estimator.add_chunked_array(self)
+            }
+        }
+    };
+}
+
+impl<T> ChunkEstimatedSize for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    fn add_to_size_estimate(&self, estimator: &mut SizeEstimator) {
+        estimator.add_chunked_array(self)
+    }
+}
+
+impl_estimated_size!(BooleanChunked);
+impl_estimated_size!(Utf8Chunked);
+impl_estimated_size!(ListChunked);
+#[cfg(feature = "dtype-categorical")]
+impl_estimated_size!(CategoricalChunked);
+
+#[cfg(feature = "object")]
+impl<T: PolarsObject> ChunkEstimatedSize for ObjectChunked<T> {
+    fn add_to_size_estimate(&self, estimator: &mut SizeEstimator) {
+        for arr in self.downcast_iter() {
+            estimator.add_objects(arr)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_estimated_size() {
+        let ca = Int64Chunked::new("a", &[Some(1), None, Some(3), Some(4)]);
+        // values and validity
+        assert_eq!(ca.estimated_size(), 4 * 8 + 1);
+
+        // slices share the buffers of the array
+        let s = ca.into_series();
+        let mut appended = s.slice(0, 2);
+        appended.append(&s.slice(1, 3)).unwrap();
+        assert_eq!(appended.estimated_size(), s.estimated_size());
+
+        let df = DataFrame::new_no_checks(vec![s.clone(), s.clone()]);
+        assert_eq!(df.estimated_size(), s.estimated_size());
+
+        let ca = Utf8Chunked::new("a", &["foo", "bar"]);
+        // offsets and values
+        assert_eq!(ca.estimated_size(), 3 * 8 + 6);
+    }
+}
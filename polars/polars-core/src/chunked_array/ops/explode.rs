@@ -197,7 +197,7 @@ impl ExplodeByOffsets for CategoricalChunked {
 }
 
 /// Convert Arrow array offsets to indexes of the original list
-pub(crate) fn offsets_to_indexes(offsets: &[i64], capacity: usize) -> Vec<u32> {
+pub(crate) fn offsets_to_indexes(offsets: &[i64], capacity: usize) -> Vec<IdxSize> {
     let mut idx = Vec::with_capacity(capacity);
 
     let mut count = 0;
@@ -260,7 +260,7 @@ impl ChunkExplode for ListChunked {
         // make sure we restore the logical type
         match self.inner_dtype() {
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical => {
+            DataType::Categorical | DataType::Enum(_) => {
                 let ca = s.u32().unwrap();
                 let mut ca = ca.clone();
                 ca.categorical_map = self.categorical_map.clone();
@@ -3,6 +3,7 @@ use std::marker::Sized;
 
 use arrow::array::ArrayRef;
 
+use self::estimated_size::SizeEstimator;
 pub use self::take::*;
 #[cfg(feature = "object")]
 use crate::chunked_array::object::ObjectType;
@@ -26,6 +27,7 @@ mod concat_str;
 #[cfg(feature = "cum_agg")]
 mod cum_agg;
 pub(crate) mod downcast;
+pub(crate) mod estimated_size;
 pub(crate) mod explode;
 mod extend;
 mod fill_null;
@@ -465,7 +467,7 @@ pub trait ChunkUnique<T> {
 
     /// Get first index of the unique values in a `ChunkedArray`.
     /// This Vec is sorted.
-    fn arg_unique(&self) -> Result<UInt32Chunked>;
+    fn arg_unique(&self) -> Result<IdxCa>;
 
     /// Number of unique values in the `ChunkedArray`
     fn n_unique(&self) -> Result<usize> {
@@ -526,10 +528,10 @@ pub trait ChunkSort<T> {
     fn sort(&self, reverse: bool) -> ChunkedArray<T>;
 
     /// Retrieve the indexes needed to sort this array.
-    fn argsort(&self, reverse: bool) -> UInt32Chunked;
+    fn argsort(&self, reverse: bool) -> IdxCa;
 
     /// Retrieve the indexes need to sort this and the other arrays.
-    fn argsort_multiple(&self, _other: &[Series], _reverse: &[bool]) -> Result<UInt32Chunked> {
+    fn argsort_multiple(&self, _other: &[Series], _reverse: &[bool]) -> Result<IdxCa> {
         Err(PolarsError::InvalidOperation(
             "argsort_multiple not implemented for this dtype".into(),
         ))
@@ -615,6 +617,27 @@ pub trait ChunkFilter<T> {
         Self: Sized;
 }
 
+/// Estimate the memory footprint of a ChunkedArray.
+pub trait ChunkEstimatedSize {
+    #[doc(hidden)]
+    fn add_to_size_estimate(&self, estimator: &mut SizeEstimator);
+
+    /// Estimate the number of bytes that are held by the buffers of the ChunkedArray: the
+    /// values, validity bitmaps, offsets, list values and categories. A buffer that is shared
+    /// between chunks, e.g. after appending a slice of the same array, is counted once.
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let array = Int32Chunked::new("array", &[1, 2, 3]);
+    /// assert_eq!(array.estimated_size(), 12);
+    /// ```
+    fn estimated_size(&self) -> usize {
+        let mut estimator = SizeEstimator::default();
+        self.add_to_size_estimate(&mut estimator);
+        estimator.finish()
+    }
+}
+
 /// Create a new ChunkedArray filled with values at that index.
 pub trait ChunkExpandAtIndex<T> {
     /// Create a new ChunkedArray filled with values at that index.
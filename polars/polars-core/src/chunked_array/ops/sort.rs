@@ -9,6 +9,8 @@ use rayon::prelude::*;
 use std::cmp::Ordering;
 use std::hint::unreachable_unchecked;
 use std::iter::FromIterator;
+#[cfg(feature = "dtype-categorical")]
+use std::ops::Deref;
 
 /// # Safety
 /// only may produce true, for f32/f64::NaN
@@ -133,7 +135,7 @@ fn argsort_branch<T, Fd, Fr>(
 macro_rules! argsort {
     ($self:expr, $reverse:expr) => {{
         let mut vals = Vec::with_capacity($self.len());
-        let mut count: u32 = 0;
+        let mut count: IdxSize = 0;
         $self.downcast_iter().for_each(|arr| {
             let iter = arr.iter().map(|v| {
                 let i = count;
@@ -149,7 +151,7 @@ macro_rules! argsort {
             |(_, a), (_, b)| order_default_null(a, b),
             |(_, a), (_, b)| order_reverse_null(a, b),
         );
-        let ca: NoNull<UInt32Chunked> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
+        let ca: NoNull<IdxCa> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
         let mut ca = ca.into_inner();
         ca.rename($self.name());
         ca
@@ -304,10 +306,10 @@ where
         })
     }
 
-    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+    fn argsort(&self, reverse: bool) -> IdxCa {
         if !self.has_validity() {
             let mut vals = Vec::with_capacity(self.len());
-            let mut count: u32 = 0;
+            let mut count: IdxSize = 0;
             self.downcast_iter().for_each(|arr| {
                 let values = arr.values();
                 let iter = values.iter().map(|&v| {
@@ -320,7 +322,7 @@ where
 
             argsort_no_nulls(vals.as_mut_slice(), reverse);
 
-            let ca: NoNull<UInt32Chunked> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
+            let ca: NoNull<IdxCa> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
             let mut ca = ca.into_inner();
             ca.rename(self.name());
             ca
@@ -339,7 +341,7 @@ where
                 len
             };
             let mut nulls_idx = Vec::with_capacity(null_cap);
-            let mut count: u32 = 0;
+            let mut count: IdxSize = 0;
             self.downcast_iter().for_each(|arr| {
                 let iter = arr.iter().filter_map(|v| {
                     let i = count;
@@ -375,8 +377,8 @@ where
                 nulls_idx
             };
 
-            let arr = UInt32Array::from_data(ArrowDataType::UInt32, Buffer::from(idx), None);
-            UInt32Chunked::from_chunks(self.name(), vec![Arc::new(arr)])
+            let arr = IdxArr::from_data(IDX_DTYPE.to_arrow(), Buffer::from(idx), None);
+            IdxCa::from_chunks(self.name(), vec![Arc::new(arr)])
         }
     }
 
@@ -385,7 +387,7 @@ where
     ///
     /// This function is very opinionated.
     /// We assume that all numeric `Series` are of the same type, if not it will panic
-    fn argsort_multiple(&self, other: &[Series], reverse: &[bool]) -> Result<UInt32Chunked> {
+    fn argsort_multiple(&self, other: &[Series], reverse: &[bool]) -> Result<IdxCa> {
         for ca in other {
             assert_eq!(self.len(), ca.len());
         }
@@ -407,7 +409,7 @@ where
             .map(|s| s.into_partial_ord_inner())
             .collect_trusted();
 
-        let mut count: u32 = 0;
+        let mut count: IdxSize = 0;
         let mut vals: Vec<_> = self
             .into_iter()
             .map(|v| {
@@ -431,7 +433,7 @@ where
                 (_, ord) => ord,
             },
         );
-        let ca: NoNull<UInt32Chunked> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
+        let ca: NoNull<IdxCa> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
         let mut ca = ca.into_inner();
         ca.set_sorted(reverse[0]);
         Ok(ca)
@@ -553,7 +555,7 @@ impl ChunkSort<Utf8Type> for Utf8Chunked {
         })
     }
 
-    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+    fn argsort(&self, reverse: bool) -> IdxCa {
         argsort!(self, reverse)
     }
 
@@ -566,7 +568,7 @@ impl ChunkSort<Utf8Type> for Utf8Chunked {
     /// In this case we assume that all numeric `Series` are `f64` types. The caller needs to
     /// uphold this contract. If not, it will panic.
     ///
-    fn argsort_multiple(&self, other: &[Series], reverse: &[bool]) -> Result<UInt32Chunked> {
+    fn argsort_multiple(&self, other: &[Series], reverse: &[bool]) -> Result<IdxCa> {
         for ca in other {
             if self.len() != ca.len() {
                 return Err(PolarsError::ShapeMisMatch(
@@ -575,7 +577,7 @@ impl ChunkSort<Utf8Type> for Utf8Chunked {
             }
         }
         assert_eq!(other.len(), reverse.len() - 1);
-        let mut count: u32 = 0;
+        let mut count: IdxSize = 0;
         let mut vals: Vec<_> = self
             .into_iter()
             .map(|v| {
@@ -603,7 +605,7 @@ impl ChunkSort<Utf8Type> for Utf8Chunked {
                 (_, ord) => ord,
             },
         );
-        let ca: NoNull<UInt32Chunked> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
+        let ca: NoNull<IdxCa> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
         let mut ca = ca.into_inner();
         ca.set_sorted(reverse[0]);
         Ok(ca)
@@ -613,6 +615,13 @@ impl ChunkSort<Utf8Type> for Utf8Chunked {
 #[cfg(feature = "dtype-categorical")]
 impl ChunkSort<CategoricalType> for CategoricalChunked {
     fn sort_with(&self, options: SortOptions) -> ChunkedArray<CategoricalType> {
+        if !self.sorts_lexically() {
+            let sorted = self.deref().sort_with(options);
+            let mut ca = self.clone();
+            ca.chunks = sorted.chunks;
+            ca.set_sorted(options.descending);
+            return ca;
+        }
         assert!(
             !options.nulls_last,
             "null last not yet supported for categorical dtype"
@@ -628,7 +637,7 @@ impl ChunkSort<CategoricalType> for CategoricalChunked {
             |(_, a), (_, b)| order_default_null(a, b),
             |(_, a), (_, b)| order_reverse_null(a, b),
         );
-        let arr: UInt32Array = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
+        let arr: IdxArr = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
         let mut ca = self.clone();
         ca.chunks = vec![Arc::new(arr)];
 
@@ -642,8 +651,11 @@ impl ChunkSort<CategoricalType> for CategoricalChunked {
         })
     }
 
-    fn argsort(&self, reverse: bool) -> UInt32Chunked {
-        let mut count: u32 = 0;
+    fn argsort(&self, reverse: bool) -> IdxCa {
+        if !self.sorts_lexically() {
+            return self.deref().argsort(reverse);
+        }
+        let mut count: IdxSize = 0;
         // safety: we know the iterators len
         let mut vals = self
             .iter_str()
@@ -660,7 +672,7 @@ impl ChunkSort<CategoricalType> for CategoricalChunked {
             |(_, a), (_, b)| order_default_null(a, b),
             |(_, a), (_, b)| order_reverse_null(a, b),
         );
-        let ca: NoNull<UInt32Chunked> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
+        let ca: NoNull<IdxCa> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
         let mut ca = ca.into_inner();
         ca.rename(self.name());
         ca
@@ -694,7 +706,7 @@ impl ChunkSort<BooleanType> for BooleanChunked {
         })
     }
 
-    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+    fn argsort(&self, reverse: bool) -> IdxCa {
         argsort!(self, reverse)
     }
 }
@@ -934,4 +946,27 @@ mod test {
         let expected = &[None, None, Some("a"), Some("b"), Some("c")];
         assert_eq!(out, expected);
     }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_sort_categorical_physical() {
+        let ca = Utf8Chunked::new("a", &[Some("c"), None, Some("a"), Some("b")]);
+        let ca = ca.cast(&DataType::Categorical).unwrap();
+        let mut ca = ca.categorical().unwrap().clone();
+        ca.set_ordering(CategoricalOrdering::Physical);
+        let out = ca.sort(false);
+        let out = out.iter_str().collect::<Vec<_>>();
+        assert_eq!(out, &[None, Some("c"), Some("a"), Some("b")]);
+        let ca = ca.slice(2, 2);
+        assert_eq!(ca.ordering(), CategoricalOrdering::Physical);
+        assert_eq!(Vec::from(&ca.argsort(true)), &[Some(1), Some(0)]);
+
+        let categories: Arc<[String]> = Arc::from(vec!["high".into(), "low".into()]);
+        let ca = Utf8Chunked::new("a", &["low", "high", "low"]);
+        let ca = ca.cast(&DataType::Enum(categories.clone())).unwrap();
+        assert_eq!(ca.dtype(), &DataType::Enum(categories));
+        let out = ca.sort(false);
+        assert_eq!(out.str_value(0), "high");
+        assert_eq!(out.str_value(2), "low");
+    }
 }
@@ -59,7 +59,7 @@ impl ChunkTakeEvery<ListType> for ListChunked {
 impl ChunkTakeEvery<CategoricalType> for CategoricalChunked {
     fn take_every(&self, n: usize) -> CategoricalChunked {
         let mut ca: CategoricalChunked = self.deref().take_every(n).into();
-        ca.categorical_map = self.categorical_map.clone();
+        ca.copy_cat_state(self);
         ca
     }
 }
@@ -6,6 +6,7 @@ mod encoding;
 
 use crate::prelude::*;
 use arrow::compute::substring::substring;
+use polars_arrow::array::ValueSize;
 use polars_arrow::kernels::string::*;
 use regex::Regex;
 use std::borrow::Cow;
@@ -15,12 +16,36 @@ fn f_regex_extract<'a>(reg: &Regex, input: &'a str, group_index: usize) -> Optio
         .and_then(|cap| cap.get(group_index).map(|m| Cow::Borrowed(m.as_str())))
 }
 
+/// Pad `s` with `fill` to `width` characters, on the left if `left` is `true`.
+fn pad(s: &str, width: usize, fill: char, left: bool) -> Cow<str> {
+    let n_chars = s.chars().count();
+    if n_chars >= width {
+        return Cow::Borrowed(s);
+    }
+    let padding = std::iter::repeat(fill).take(width - n_chars);
+    let mut out = String::with_capacity(s.len() + (width - n_chars) * fill.len_utf8());
+    if left {
+        out.extend(padding);
+        out.push_str(s);
+    } else {
+        out.push_str(s);
+        out.extend(padding);
+    }
+    Cow::Owned(out)
+}
+
 impl Utf8Chunked {
     /// Get the length of the string values.
     pub fn str_lengths(&self) -> UInt32Chunked {
         self.apply_kernel_cast(&string_lengths)
     }
 
+    /// Get the number of characters of the string values.
+    /// This differs from [`str_lengths`](Utf8Chunked::str_lengths) for non-ASCII strings.
+    pub fn n_chars(&self) -> UInt32Chunked {
+        self.apply_cast_numeric(|s| s.chars().count() as u32)
+    }
+
     /// Check if strings contain a regex pattern
     pub fn contains(&self, pat: &str) -> Result<BooleanChunked> {
         let reg = Regex::new(pat)?;
@@ -34,6 +59,36 @@ impl Utf8Chunked {
         Ok(ca)
     }
 
+    /// Check if strings start with a prefix
+    pub fn starts_with(&self, prefix: &str) -> BooleanChunked {
+        let f = |s: &str| s.starts_with(prefix);
+        let mut ca: BooleanChunked = if !self.has_validity() {
+            self.into_no_null_iter().map(f).collect()
+        } else {
+            self.into_iter().map(|opt_s| opt_s.map(f)).collect()
+        };
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Check if strings end with a suffix
+    pub fn ends_with(&self, suffix: &str) -> BooleanChunked {
+        let f = |s: &str| s.ends_with(suffix);
+        let mut ca: BooleanChunked = if !self.has_validity() {
+            self.into_no_null_iter().map(f).collect()
+        } else {
+            self.into_iter().map(|opt_s| opt_s.map(f)).collect()
+        };
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Count the non-overlapping matches of a regex pattern
+    pub fn count_matches(&self, pat: &str) -> Result<UInt32Chunked> {
+        let reg = Regex::new(pat)?;
+        Ok(self.apply_cast_numeric(|s| reg.find_iter(s).count() as u32))
+    }
+
     /// Replace the leftmost (sub)string by a regex pattern
     pub fn replace(&self, pat: &str, val: &str) -> Result<Utf8Chunked> {
         let reg = Regex::new(pat)?;
@@ -54,6 +109,113 @@ impl Utf8Chunked {
         Ok(self.apply_on_opt(|e| e.and_then(|input| f_regex_extract(&reg, input, group_index))))
     }
 
+    /// Extract all non-overlapping matches of a regex pattern
+    pub fn extract_all(&self, pat: &str) -> Result<ListChunked> {
+        let reg = Regex::new(pat)?;
+        let mut builder =
+            ListUtf8ChunkedBuilder::new(self.name(), self.len(), self.get_values_size());
+        for opt_s in self {
+            match opt_s {
+                None => builder.append_null(),
+                Some(s) => builder.append_values_iter(reg.find_iter(s).map(|m| m.as_str())),
+            }
+        }
+        Ok(builder.finish())
+    }
+
+    /// Remove leading and trailing characters. If `chars` is `None`, whitespace is removed,
+    /// otherwise all characters in `chars` are removed.
+    #[must_use]
+    pub fn strip(&self, chars: Option<&str>) -> Utf8Chunked {
+        match chars {
+            None => self.apply(|s| Cow::Borrowed(s.trim())),
+            Some(chars) => {
+                self.apply(|s| Cow::Borrowed(s.trim_matches(|c: char| chars.contains(c))))
+            }
+        }
+    }
+
+    /// Remove leading characters. If `chars` is `None`, whitespace is removed,
+    /// otherwise all characters in `chars` are removed.
+    #[must_use]
+    pub fn lstrip(&self, chars: Option<&str>) -> Utf8Chunked {
+        match chars {
+            None => self.apply(|s| Cow::Borrowed(s.trim_start())),
+            Some(chars) => {
+                self.apply(|s| Cow::Borrowed(s.trim_start_matches(|c: char| chars.contains(c))))
+            }
+        }
+    }
+
+    /// Remove trailing characters. If `chars` is `None`, whitespace is removed,
+    /// otherwise all characters in `chars` are removed.
+    #[must_use]
+    pub fn rstrip(&self, chars: Option<&str>) -> Utf8Chunked {
+        match chars {
+            None => self.apply(|s| Cow::Borrowed(s.trim_end())),
+            Some(chars) => {
+                self.apply(|s| Cow::Borrowed(s.trim_end_matches(|c: char| chars.contains(c))))
+            }
+        }
+    }
+
+    /// Pad the strings with zeros on the left to `width` characters.
+    /// A leading sign is kept in front of the zeros.
+    #[must_use]
+    pub fn zfill(&self, width: usize) -> Utf8Chunked {
+        self.apply(|s| match s.strip_prefix(&['+', '-'][..]) {
+            Some(digits) => {
+                let sign = &s[..1];
+                let digits = pad(digits, width.saturating_sub(1), '0', true);
+                Cow::Owned(format!("{}{}", sign, digits))
+            }
+            None => pad(s, width, '0', true),
+        })
+    }
+
+    /// Pad the strings on the right with `fill` to `width` characters, i.e. left justify them.
+    #[must_use]
+    pub fn ljust(&self, width: usize, fill: char) -> Utf8Chunked {
+        self.apply(|s| pad(s, width, fill, false))
+    }
+
+    /// Pad the strings on the left with `fill` to `width` characters, i.e. right justify them.
+    #[must_use]
+    pub fn rjust(&self, width: usize, fill: char) -> Utf8Chunked {
+        self.apply(|s| pad(s, width, fill, true))
+    }
+
+    /// Split the strings by `by` in exactly `n + 1` parts. Missing parts are null and the
+    /// remainder of the string after the `n`th split is discarded.
+    pub fn split_exact(&self, by: &str, n: usize) -> ListChunked {
+        let mut builder =
+            ListUtf8ChunkedBuilder::new(self.name(), self.len(), self.get_values_size());
+        for opt_s in self {
+            match opt_s {
+                None => builder.append_null(),
+                Some(s) => {
+                    let mut parts = s.split(by);
+                    builder.append_trusted_len_iter((0..n + 1).map(|_| parts.next()))
+                }
+            }
+        }
+        builder.finish()
+    }
+
+    /// Split the strings by `by` in at most `n` parts. The last part contains the remainder
+    /// of the string.
+    pub fn splitn(&self, by: &str, n: usize) -> ListChunked {
+        let mut builder =
+            ListUtf8ChunkedBuilder::new(self.name(), self.len(), self.get_values_size());
+        for opt_s in self {
+            match opt_s {
+                None => builder.append_null(),
+                Some(s) => builder.append_values_iter(s.splitn(n, by)),
+            }
+        }
+        builder.finish()
+    }
+
     /// Modify the strings to their lowercase equivalent
     #[must_use]
     pub fn to_lowercase(&self) -> Utf8Chunked {
@@ -84,3 +246,43 @@ impl Utf8Chunked {
         Ok(Self::from_chunks(self.name(), chunks))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_str_cleanup() -> Result<()> {
+        let ca = Utf8Chunked::new("a", &[Some("  -12 "), None, Some("xxabcx")]);
+
+        let stripped = ca.strip(None);
+        assert_eq!(Vec::from(&stripped), &[Some("-12"), None, Some("xxabcx")]);
+        let stripped = ca.lstrip(Some("x "));
+        assert_eq!(Vec::from(&stripped), &[Some("-12 "), None, Some("abcx")]);
+        assert_eq!(
+            Vec::from(&stripped.strip(None).zfill(5)),
+            &[Some("-0012"), None, Some("0abcx")]
+        );
+        assert_eq!(
+            Vec::from(&ca.rstrip(Some("x")).rjust(7, '.')),
+            &[Some(".  -12 "), None, Some("..xxabc")]
+        );
+        assert_eq!(
+            Vec::from(&ca.starts_with("xx")),
+            &[Some(false), None, Some(true)]
+        );
+        assert_eq!(
+            Vec::from(&ca.count_matches("x")?),
+            &[Some(0), None, Some(3)]
+        );
+
+        let parts = Utf8Chunked::new("a", &["a_b_c", "a"])
+            .split_exact("_", 1)
+            .into_iter()
+            .map(|opt_s| opt_s.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(Vec::from(parts[0].utf8()?), &[Some("a"), Some("b")]);
+        assert_eq!(Vec::from(parts[1].utf8()?), &[Some("a"), None]);
+        Ok(())
+    }
+}
@@ -48,6 +48,17 @@ impl DateChunked {
         self.apply_kernel_cast::<UInt32Type>(&date_to_week)
     }
 
+    /// Returns the ISO year, i.e. the year of the ISO week of the date.
+    /// It can differ from the calendar year in the first and last days of a year.
+    pub fn iso_year(&self) -> Int32Chunked {
+        self.apply_kernel_cast::<Int32Type>(&date_to_iso_year)
+    }
+
+    /// Returns the quarter of the year, ranging from 1 to 4.
+    pub fn quarter(&self) -> UInt32Chunked {
+        self.month().apply(|month| (month - 1) / 3 + 1)
+    }
+
     /// Extract day from underlying NaiveDate representation.
     /// Returns the day of month starting from 1.
     ///
@@ -84,6 +84,22 @@ impl DatetimeChunked {
         self.apply_kernel_cast::<UInt32Type>(&f)
     }
 
+    /// Returns the ISO year, i.e. the year of the ISO week of the date.
+    /// It can differ from the calendar year in the first and last days of a year.
+    pub fn iso_year(&self) -> Int32Chunked {
+        let f = match self.time_unit() {
+            TimeUnit::Nanoseconds => datetime_to_iso_year_ns,
+            TimeUnit::Microseconds => datetime_to_iso_year_us,
+            TimeUnit::Milliseconds => datetime_to_iso_year_ms,
+        };
+        self.apply_kernel_cast::<Int32Type>(&f)
+    }
+
+    /// Returns the quarter of the year, ranging from 1 to 4.
+    pub fn quarter(&self) -> UInt32Chunked {
+        self.month().apply(|month| (month - 1) / 3 + 1)
+    }
+
     /// Extract day from underlying NaiveDateTime representation.
     /// Returns the day of month starting from 1.
     ///
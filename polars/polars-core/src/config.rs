@@ -0,0 +1,203 @@
+//! Settings of the engine.
+//!
+//! The defaults are read from the `POLARS_*` environment variables whenever they are needed,
+//! so changes to the environment are picked up. A [`Config`] can be activated for a scope with
+//! [`Config::scoped`], which overrides the environment for the current thread only. This allows
+//! different settings per query in the same process.
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+
+thread_local! {
+    static CONFIG: RefCell<Option<Arc<Config>>> = RefCell::new(None);
+}
+
+fn env_is_set(key: &str) -> bool {
+    std::env::var(key).is_ok()
+}
+
+fn env_parse<T: FromStr>(key: &str) -> Option<T> {
+    std::env::var(key).ok().and_then(|v| v.parse().ok())
+}
+
+/// Settings of the engine. Every field documents the environment variable it defaults to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// Number of threads in the global thread pool. This is read once, when the thread pool
+    /// is created.
+    ///
+    /// `POLARS_MAX_THREADS`, defaults to the number of cpus.
+    pub max_threads: usize,
+    /// Print information about the execution of queries.
+    ///
+    /// `POLARS_VERBOSE`
+    pub verbose: bool,
+    /// Maximum number of rows shown when a `DataFrame` is formatted.
+    ///
+    /// `POLARS_FMT_MAX_ROWS`, defaults to 8.
+    pub fmt_max_rows: usize,
+    /// Maximum number of columns shown when a `DataFrame` is formatted.
+    ///
+    /// `POLARS_FMT_MAX_COLS`, defaults to 8.
+    pub fmt_max_cols: usize,
+    /// Format tables with ASCII characters only.
+    ///
+    /// `POLARS_FMT_NO_UTF8`
+    pub fmt_no_utf8: bool,
+    /// Width of formatted tables. If `None` the width of the terminal is used.
+    ///
+    /// `POLARS_TABLE_WIDTH`
+    pub table_width: Option<u16>,
+    /// Take the columns of a `DataFrame` in parallel, instead of parallelizing within columns.
+    ///
+    /// `POLARS_VERT_PAR`
+    pub vertical_parallel: bool,
+    /// Never use the partitioned groupby.
+    ///
+    /// `POLARS_NO_PARTITION`
+    pub no_partition: bool,
+    /// Fraction of the rows that is sampled to estimate the cardinality of groupby keys.
+    ///
+    /// `POLARS_PARTITION_SAMPLE_FRAC`, defaults to 0.001.
+    pub partition_sample_frac: f32,
+    /// Maximum cardinality of groupby keys for which the partitioned groupby is used. If `None`,
+    /// 0.5% is used for known and 1% for estimated cardinalities.
+    ///
+    /// `POLARS_PARTITION_CARDINALITY_FRAC`
+    pub partition_cardinality_frac: Option<f32>,
+    /// Don't skip parquet row groups based on their statistics.
+    ///
+    /// `POLARS_NO_PARQUET_STATISTICS`
+    pub no_parquet_statistics: bool,
+    /// Directory in which spilled data is written.
+    ///
+    /// `POLARS_TEMP_DIR`, defaults to the temporary directory of the system.
+    pub temp_dir: PathBuf,
+}
+
+impl Config {
+    /// Read the settings from the environment variables. Values that cannot be parsed are
+    /// ignored and fall back to their default.
+    pub fn from_env() -> Self {
+        Config {
+            max_threads: env_parse("POLARS_MAX_THREADS")
+                .filter(|n| *n > 0)
+                .unwrap_or_else(num_cpus::get),
+            verbose: env_is_set("POLARS_VERBOSE"),
+            fmt_max_rows: env_parse("POLARS_FMT_MAX_ROWS").unwrap_or(8),
+            fmt_max_cols: env_parse("POLARS_FMT_MAX_COLS").unwrap_or(8),
+            fmt_no_utf8: env_is_set("POLARS_FMT_NO_UTF8"),
+            table_width: env_parse("POLARS_TABLE_WIDTH"),
+            vertical_parallel: env_is_set("POLARS_VERT_PAR"),
+            no_partition: env_is_set("POLARS_NO_PARTITION"),
+            partition_sample_frac: env_parse("POLARS_PARTITION_SAMPLE_FRAC").unwrap_or(0.001),
+            partition_cardinality_frac: env_parse("POLARS_PARTITION_CARDINALITY_FRAC"),
+            no_parquet_statistics: env_is_set("POLARS_NO_PARQUET_STATISTICS"),
+            temp_dir: std::env::var("POLARS_TEMP_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| std::env::temp_dir()),
+        }
+    }
+
+    /// The active settings of this thread. These are the settings of the innermost
+    /// [`Config::scoped`] call, or the environment variables outside of a scope. Outside of a
+    /// scope the environment is read on every call, so hot loops should read the settings once.
+    pub fn current() -> Arc<Config> {
+        CONFIG
+            .with(|cfg| cfg.borrow().clone())
+            .unwrap_or_else(|| Arc::new(Config::from_env()))
+    }
+
+    /// Run `f` with these settings active on the current thread. The previous settings are
+    /// restored when `f` returns or panics.
+    ///
+    /// Work that is spawned on other threads, such as the rayon workers of [`POOL`](crate::POOL),
+    /// doesn't see the settings, unless it activates them as well. Operations that run on
+    /// those threads should be given the settings they need explicitly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::config::Config;
+    ///
+    /// let cfg = Config {
+    ///     fmt_max_rows: 20,
+    ///     ..Config::from_env()
+    /// };
+    /// cfg.scoped(|cfg| {
+    ///     assert_eq!(cfg.fmt_max_rows, 20);
+    ///     assert_eq!(Config::current().fmt_max_rows, 20);
+    /// });
+    /// ```
+    pub fn scoped<F, T>(&self, f: F) -> T
+    where
+        F: FnOnce(&Config) -> T,
+    {
+        Arc::new(self.clone()).scoped_arc(f)
+    }
+
+    /// [`Config::scoped`] without cloning the settings.
+    pub fn scoped_arc<F, T>(self: Arc<Self>, f: F) -> T
+    where
+        F: FnOnce(&Config) -> T,
+    {
+        // restores the previous settings, also when `f` panics
+        struct Restore(Option<Arc<Config>>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                CONFIG.with(|cfg| *cfg.borrow_mut() = previous);
+            }
+        }
+
+        let previous = CONFIG.with(|cfg| cfg.borrow_mut().replace(self.clone()));
+        let _restore = Restore(previous);
+        f(&self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scoped_config() {
+        let outer = Config {
+            fmt_max_rows: 3,
+            ..Config::from_env()
+        };
+        let inner = Config {
+            fmt_max_rows: 5,
+            ..Config::from_env()
+        };
+        outer.scoped(|_| {
+            assert_eq!(Config::current().fmt_max_rows, 3);
+            inner.scoped(|_| assert_eq!(Config::current().fmt_max_rows, 5));
+            assert_eq!(Config::current().fmt_max_rows, 3);
+            // other threads don't see the scope
+            let scoped = std::thread::spawn(|| CONFIG.with(|cfg| cfg.borrow().is_some()))
+                .join()
+                .unwrap();
+            assert!(!scoped);
+        });
+        assert!(CONFIG.with(|cfg| cfg.borrow().is_none()));
+    }
+
+    #[test]
+    fn test_config_malformed_env() {
+        std::env::set_var("POLARS_TABLE_WIDTH", "wide");
+        assert_eq!(Config::from_env().table_width, None);
+        std::env::remove_var("POLARS_TABLE_WIDTH");
+    }
+
+    #[test]
+    fn test_config_env_change() {
+        // the environment is read again after the first use, as the bindings set it at runtime
+        assert_eq!(Config::current().fmt_max_cols, 8);
+        std::env::set_var("POLARS_FMT_MAX_COLS", "17");
+        assert_eq!(Config::current().fmt_max_cols, 17);
+        std::env::remove_var("POLARS_FMT_MAX_COLS");
+        assert_eq!(Config::current().fmt_max_cols, 8);
+    }
+}
@@ -26,6 +26,7 @@ use num::{Bounded, FromPrimitive, Num, NumCast, Zero};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign, Div, Mul, Rem, Sub};
@@ -133,6 +134,20 @@ pub type Float64Chunked = ChunkedArray<Float64Type>;
 pub type Utf8Chunked = ChunkedArray<Utf8Type>;
 pub type CategoricalChunked = ChunkedArray<CategoricalType>;
 
+pub use polars_arrow::index::{IdxArr, IdxSize};
+/// The `PolarsDataType` of row indexes, see [`IdxSize`].
+#[cfg(not(feature = "bigidx"))]
+pub type IdxType = UInt32Type;
+#[cfg(feature = "bigidx")]
+pub type IdxType = UInt64Type;
+/// A `ChunkedArray` of row indexes.
+pub type IdxCa = ChunkedArray<IdxType>;
+/// The `DataType` of row indexes.
+#[cfg(not(feature = "bigidx"))]
+pub const IDX_DTYPE: DataType = DataType::UInt32;
+#[cfg(feature = "bigidx")]
+pub const IDX_DTYPE: DataType = DataType::UInt64;
+
 pub trait NumericNative:
     PartialOrd
     + NativeType
@@ -478,6 +493,7 @@ impl Display for DataType {
             #[cfg(feature = "object")]
             DataType::Object(s) => s,
             DataType::Categorical => "cat",
+            DataType::Enum(_) => "enum",
             DataType::Unknown => unreachable!(),
         };
         f.write_str(s)
@@ -520,6 +536,9 @@ impl PartialEq for AnyValue<'_> {
                 (RevMapping::Local(arr_l), RevMapping::Local(arr_r)) => {
                     std::ptr::eq(arr_l, arr_r) && idx_l == idx_r
                 }
+                (RevMapping::Enum(_, dt_l), RevMapping::Enum(_, dt_r)) => {
+                    dt_l == dt_r && idx_l == idx_r
+                }
                 _ => false,
             },
             #[cfg(feature = "dtype-duration")]
@@ -628,6 +647,10 @@ pub enum DataType {
     Object(&'static str),
     Null,
     Categorical,
+    /// A categorical with a fixed list of categories. The physical value of a category is its
+    /// index in the list, so it is stable across files and the order of the list is the
+    /// order of the values.
+    Enum(Arc<[String]>),
     // some logical types we cannot know statically, e.g. Datetime
     Unknown,
 }
@@ -650,7 +673,7 @@ impl DataType {
             Datetime(_, _) => Int64,
             Duration(_) => Int64,
             Time => Int64,
-            Categorical => UInt32,
+            Categorical | Enum(_) => UInt32,
             _ => self.clone(),
         }
     }
@@ -688,7 +711,7 @@ impl DataType {
             Null => ArrowDataType::Null,
             #[cfg(feature = "object")]
             Object(_) => panic!("cannot convert object to arrow"),
-            Categorical => ArrowDataType::UInt32,
+            Categorical | Enum(_) => ArrowDataType::UInt32,
             Unknown => unreachable!(),
         }
     }
@@ -912,15 +935,30 @@ impl Schema {
                         ))),
                         true,
                     ),
-                    DataType::Categorical => ArrowField::new(
-                        f.name(),
-                        ArrowDataType::Dictionary(
-                            IntegerType::UInt32,
-                            Box::new(ArrowDataType::LargeUtf8),
-                            false,
-                        ),
-                        true,
-                    ),
+                    DataType::Categorical | DataType::Enum(_) => {
+                        let field = ArrowField::new(
+                            f.name(),
+                            ArrowDataType::Dictionary(
+                                IntegerType::UInt32,
+                                Box::new(ArrowDataType::LargeUtf8),
+                                false,
+                            ),
+                            true,
+                        );
+                        // the categories of an `Enum` are kept in the metadata, so that they
+                        // survive IPC and parquet files
+                        match f.data_type() {
+                            DataType::Enum(categories) => {
+                                let mut metadata = BTreeMap::new();
+                                metadata.insert(
+                                    ENUM_CATEGORIES_KEY.to_string(),
+                                    encode_categories(categories),
+                                );
+                                field.with_metadata(metadata)
+                            }
+                            _ => field,
+                        }
+                    }
                     _ => f.to_arrow(),
                 }
             })
@@ -1001,8 +1039,39 @@ impl From<&ArrowDataType> for DataType {
     }
 }
 
+/// Key of the arrow field metadata that stores the categories of an `Enum`.
+const ENUM_CATEGORIES_KEY: &str = "POLARS.enum_categories";
+
+/// Encode the categories as `<byte length>:<category>` for every category.
+fn encode_categories(categories: &[String]) -> String {
+    categories
+        .iter()
+        .map(|cat| format!("{}:{}", cat.len(), cat))
+        .collect()
+}
+
+fn decode_categories(mut encoded: &str) -> Option<Vec<String>> {
+    let mut categories = vec![];
+    while !encoded.is_empty() {
+        let (len, rest) = encoded.split_once(':')?;
+        let len = len.parse::<usize>().ok()?;
+        categories.push(rest.get(..len)?.to_string());
+        encoded = rest.get(len..)?;
+    }
+    Some(categories)
+}
+
 impl From<&ArrowField> for Field {
     fn from(f: &ArrowField) -> Self {
+        if let ArrowDataType::Dictionary(..) = f.data_type() {
+            if let Some(categories) = f
+                .metadata
+                .get(ENUM_CATEGORIES_KEY)
+                .and_then(|encoded| decode_categories(encoded))
+            {
+                return Field::new(&f.name, DataType::Enum(categories.into()));
+            }
+        }
         Field::new(&f.name, f.data_type().into())
     }
 }
@@ -1032,6 +1101,18 @@ pub type PlHashSet<V> = hashbrown::HashSet<V, RandomState>;
 mod test {
     use super::*;
 
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_enum_categories_in_arrow_schema() {
+        let categories: Arc<[String]> = Arc::from(vec!["a".into(), "".into(), "b:c".into()]);
+        let schema = Schema::new(vec![Field::new("e", DataType::Enum(categories.clone()))]);
+        let arrow_schema = schema.to_arrow();
+        let field: Field = (&arrow_schema.fields[0]).into();
+        assert_eq!(field.data_type(), &DataType::Enum(categories));
+
+        assert_eq!(decode_categories("3:ab"), None);
+    }
+
     #[test]
     fn test_arrow_dtypes_to_polars() {
         let dtypes = [
@@ -47,6 +47,8 @@ pub enum PolarsError {
     Duplicate(ErrString),
     #[error("implementation error; this should not have happened.")]
     ImplementationError,
+    #[error("Query was cancelled")]
+    Cancelled,
 }
 
 pub type Result<T> = std::result::Result<T, PolarsError>;
@@ -0,0 +1,69 @@
+//! Export and import of Series and DataFrames through the
+//! [Arrow C data interface](https://arrow.apache.org/docs/format/CDataInterface.html)
+//! and the [Arrow C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html).
+mod stream;
+
+use crate::prelude::*;
+use arrow::ffi;
+pub use arrow::ffi::{Ffi_ArrowArray as ArrowArray, Ffi_ArrowSchema as ArrowSchema};
+pub use stream::ArrowArrayStream;
+
+impl Series {
+    /// Export the `Series` as an [`ArrowArray`] and its field as an [`ArrowSchema`].
+    /// The `Series` is rechunked if it consists of multiple chunks.
+    ///
+    /// The memory of the array is released when the consumer calls the `release` callbacks,
+    /// or when the structs are dropped on the Rust side.
+    pub fn to_ffi(&self) -> Result<(ArrowArray, ArrowSchema)> {
+        #[cfg(feature = "object")]
+        if matches!(self.dtype(), DataType::Object(_)) {
+            return Err(PolarsError::InvalidOperation(
+                "cannot export an object Series to arrow".into(),
+            ));
+        }
+        let arr = self.rechunk().to_arrow(0);
+        let field = ArrowField::new(self.name(), arr.data_type().clone(), true);
+
+        let mut array = ArrowArray::empty();
+        let mut schema = ArrowSchema::empty();
+        // Safety: both pointers point to valid, initialized structs.
+        unsafe {
+            ffi::export_field_to_c(&field, &mut schema);
+            ffi::export_array_to_c(arr, &mut array);
+        }
+        Ok((array, schema))
+    }
+
+    /// Import a `Series` from an [`ArrowArray`] and the [`ArrowSchema`] that describes it.
+    ///
+    /// # Safety
+    /// The structs must be valid according to the Arrow C data interface and the array must
+    /// match the schema. The `release` callback of the array is called when the `Series`
+    /// is dropped.
+    pub unsafe fn from_ffi(array: ArrowArray, schema: &ArrowSchema) -> Result<Series> {
+        let field = ffi::import_field_from_c(schema)?;
+        let arr = ffi::import_array_from_c(Box::new(array), &field)?;
+        Series::try_from((field.name.as_str(), ArrayRef::from(arr)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_series_ffi_roundtrip() -> Result<()> {
+        let mut s = Series::new("a", &[Some(1i32), None, Some(3)]);
+        s.append(&Series::new("a", &[4i32]))?;
+        let (array, schema) = s.to_ffi()?;
+        let out = unsafe { Series::from_ffi(array, &schema)? };
+        assert!(out.series_equal_missing(&s));
+        assert_eq!(out.name(), "a");
+
+        let s = Series::new("b", &["foo", "bar"]);
+        let (array, schema) = s.to_ffi()?;
+        let out = unsafe { Series::from_ffi(array, &schema)? };
+        assert!(out.series_equal(&s));
+        Ok(())
+    }
+}
@@ -0,0 +1,290 @@
+use super::{ArrowArray, ArrowSchema};
+use crate::prelude::*;
+use arrow::array::StructArray;
+use arrow::ffi;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+// errno that is returned by the callbacks if the next batch could not be produced
+const EIO: c_int = 5;
+
+type Batches = Box<dyn Iterator<Item = Result<DataFrame>> + Send>;
+
+/// An `ArrowArrayStream` of the Arrow C stream interface.
+///
+/// Every batch of the stream is a struct array of which the fields are the columns of the
+/// `DataFrame`. The stream is released when the consumer calls `release` or when the
+/// struct is dropped on the Rust side.
+#[repr(C)]
+pub struct ArrowArrayStream {
+    get_schema: Option<unsafe extern "C" fn(*mut ArrowArrayStream, *mut ArrowSchema) -> c_int>,
+    get_next: Option<unsafe extern "C" fn(*mut ArrowArrayStream, *mut ArrowArray) -> c_int>,
+    get_last_error: Option<unsafe extern "C" fn(*mut ArrowArrayStream) -> *const c_char>,
+    release: Option<unsafe extern "C" fn(*mut ArrowArrayStream)>,
+    private_data: *mut c_void,
+}
+
+struct PrivateData {
+    batches: Batches,
+    field: ArrowField,
+    error: Option<CString>,
+}
+
+impl ArrowArrayStream {
+    /// A released stream that can be used as the destination of an import.
+    pub fn empty() -> Self {
+        ArrowArrayStream {
+            get_schema: None,
+            get_next: None,
+            get_last_error: None,
+            release: None,
+            private_data: ptr::null_mut(),
+        }
+    }
+
+    /// Create a stream that produces the `DataFrame`s of `batches`. Every batch must have the
+    /// columns of `schema`.
+    pub fn from_batches<I>(schema: &Schema, batches: I) -> Result<Self>
+    where
+        I: Iterator<Item = Result<DataFrame>> + Send + 'static,
+    {
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|f| {
+                #[cfg(feature = "object")]
+                if matches!(f.data_type(), DataType::Object(_)) {
+                    return Err(PolarsError::InvalidOperation(
+                        "cannot export an object Series to arrow".into(),
+                    ));
+                }
+                // logical types are converted when the arrays are exported, so we take the data
+                // type of an exported empty array
+                let s = Series::full_null(f.name(), 0, f.data_type());
+                Ok(ArrowField::new(
+                    f.name(),
+                    s.to_arrow(0).data_type().clone(),
+                    true,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let private_data = Box::new(PrivateData {
+            batches: Box::new(batches),
+            field: ArrowField::new("", ArrowDataType::Struct(fields), false),
+            error: None,
+        });
+
+        Ok(ArrowArrayStream {
+            get_schema: Some(get_schema),
+            get_next: Some(get_next),
+            get_last_error: Some(get_last_error),
+            release: Some(release),
+            private_data: Box::into_raw(private_data) as *mut c_void,
+        })
+    }
+}
+
+impl Drop for ArrowArrayStream {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            // Safety: the stream is not released yet.
+            unsafe { release(self) }
+        }
+    }
+}
+
+impl DataFrame {
+    /// Export the `DataFrame` as an [`ArrowArrayStream`] that produces a batch per chunk.
+    /// The chunks of the columns are aligned first if they don't have the same lengths.
+    pub fn to_ffi_stream(&self) -> Result<ArrowArrayStream> {
+        let mut df = self.clone();
+        df.rechunk();
+        let chunk_lengths = df
+            .get_columns()
+            .get(0)
+            .map(|s| s.chunk_lengths().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let schema = df.schema();
+        let mut offset = 0;
+        let batches = chunk_lengths.into_iter().map(move |len| {
+            let batch = df.slice(offset as i64, len);
+            offset += len;
+            Ok(batch)
+        });
+        ArrowArrayStream::from_batches(&schema, batches)
+    }
+}
+
+fn export_batch(mut df: DataFrame, field: &ArrowField) -> Result<ArrowArray> {
+    let fields = match field.data_type() {
+        ArrowDataType::Struct(fields) => fields,
+        _ => unreachable!(),
+    };
+    if df.width() != fields.len() {
+        return Err(PolarsError::ShapeMisMatch(
+            format!(
+                "batch has {} columns, the schema of the stream has {}",
+                df.width(),
+                fields.len()
+            )
+            .into(),
+        ));
+    }
+    df.as_single_chunk();
+    let arrays = df
+        .get_columns()
+        .iter()
+        .zip(fields)
+        .map(|(s, field)| {
+            let arr = s.to_arrow(0);
+            if arr.data_type() != field.data_type() {
+                return Err(PolarsError::SchemaMisMatch(
+                    format!(
+                        "column {} has arrow type {:?}, the schema of the stream has {:?}",
+                        s.name(),
+                        arr.data_type(),
+                        field.data_type()
+                    )
+                    .into(),
+                ));
+            }
+            Ok(arr)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let arr = Arc::new(StructArray::from_data(
+        field.data_type().clone(),
+        arrays,
+        None,
+    ));
+    let mut array = ArrowArray::empty();
+    // Safety: the pointer points to a valid, initialized struct.
+    unsafe { ffi::export_array_to_c(arr, &mut array) };
+    Ok(array)
+}
+
+unsafe extern "C" fn get_schema(stream: *mut ArrowArrayStream, out: *mut ArrowSchema) -> c_int {
+    let private = &*((*stream).private_data as *const PrivateData);
+    let mut schema = ArrowSchema::empty();
+    ffi::export_field_to_c(&private.field, &mut schema);
+    // `out` may be uninitialized, so we must not drop its content
+    ptr::write(out, schema);
+    0
+}
+
+unsafe extern "C" fn get_next(stream: *mut ArrowArrayStream, out: *mut ArrowArray) -> c_int {
+    let private = &mut *((*stream).private_data as *mut PrivateData);
+
+    // a panic must not unwind into the caller
+    let result = catch_unwind(AssertUnwindSafe(|| match private.batches.next() {
+        Some(batch) => batch.and_then(|df| export_batch(df, &private.field)),
+        // a released array marks the end of the stream
+        None => Ok(ArrowArray::empty()),
+    }))
+    .unwrap_or_else(|_| {
+        Err(PolarsError::ComputeError(
+            "panicked while producing the next batch".into(),
+        ))
+    });
+
+    match result {
+        Ok(array) => {
+            ptr::write(out, array);
+            0
+        }
+        Err(e) => {
+            private.error = CString::new(e.to_string()).ok();
+            EIO
+        }
+    }
+}
+
+unsafe extern "C" fn get_last_error(stream: *mut ArrowArrayStream) -> *const c_char {
+    let private = &*((*stream).private_data as *const PrivateData);
+    private
+        .error
+        .as_ref()
+        .map_or(ptr::null(), |error| error.as_ptr())
+}
+
+unsafe extern "C" fn release(stream: *mut ArrowArrayStream) {
+    if stream.is_null() {
+        return;
+    }
+    let stream = &mut *stream;
+    drop(Box::from_raw(stream.private_data as *mut PrivateData));
+    stream.private_data = ptr::null_mut();
+    stream.release = None;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::df;
+
+    fn is_released(array: &ArrowArray) -> bool {
+        // the `release` callback follows the five int64 and three pointer fields of the
+        // C struct, and a `None` callback is a null pointer
+        let raw = array as *const ArrowArray as *const [usize; 10];
+        unsafe { (*raw)[8] == 0 }
+    }
+
+    /// Consume the stream like a C consumer would.
+    fn collect_stream(stream: &mut ArrowArrayStream) -> Result<Vec<DataFrame>> {
+        unsafe {
+            let mut schema = ArrowSchema::empty();
+            assert_eq!((stream.get_schema.unwrap())(stream, &mut schema), 0);
+            let field = ffi::import_field_from_c(&schema)?;
+
+            let mut out = vec![];
+            loop {
+                let mut array = ArrowArray::empty();
+                if (stream.get_next.unwrap())(stream, &mut array) != 0 {
+                    let error = std::ffi::CStr::from_ptr((stream.get_last_error.unwrap())(stream));
+                    return Err(PolarsError::ComputeError(
+                        error.to_string_lossy().into_owned().into(),
+                    ));
+                }
+                if is_released(&array) {
+                    return Ok(out);
+                }
+                let arr = ffi::import_array_from_c(Box::new(array), &field)?;
+                let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+                let columns = arr
+                    .fields()
+                    .iter()
+                    .zip(arr.values())
+                    .map(|(field, arr)| Series::try_from((field.name.as_str(), arr.clone())))
+                    .collect::<Result<Vec<_>>>()?;
+                out.push(DataFrame::new(columns)?);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dataframe_ffi_stream() -> Result<()> {
+        let mut df = df![
+            "a" => [1, 2, 3],
+            "b" => ["foo", "bar", "ham"]
+        ]?;
+        df.vstack_mut(&df.clone())?;
+
+        let mut stream = df.to_ffi_stream()?;
+        let batches = collect_stream(&mut stream)?;
+        assert_eq!(batches.len(), 2);
+        for batch in &batches {
+            assert!(batch.frame_equal(&df.slice(0, 3)));
+        }
+
+        // an error of a batch is reported by `get_last_error`
+        let schema = df.schema();
+        let batches = vec![Ok(df.clone()), Ok(df.select(["a"])?)].into_iter();
+        let mut stream = ArrowArrayStream::from_batches(&schema, batches)?;
+        assert!(collect_stream(&mut stream).is_err());
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::config::Config;
 use crate::prelude::*;
 
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
@@ -289,6 +290,15 @@ impl Debug for Series {
                 self.name(),
                 "Series"
             ),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(_) => format_array!(
+                limit,
+                f,
+                self.categorical().unwrap(),
+                "enum",
+                self.name(),
+                "Series"
+            ),
             dt => panic!("{:?} not impl", dt),
         }
     }
@@ -345,17 +355,12 @@ impl Display for DataFrame {
             "The columns lengths in the DataFrame are not equal."
         );
 
-        let max_n_cols = std::env::var("POLARS_FMT_MAX_COLS")
-            .unwrap_or_else(|_| "8".to_string())
-            .parse()
-            .unwrap_or(8);
+        let config = Config::current();
+        let max_n_cols = config.fmt_max_cols;
 
         #[cfg(any(feature = "plain_fmt", feature = "pretty_fmt"))]
         let max_n_rows = {
-            let max_n_rows = std::env::var("POLARS_FMT_MAX_ROWS")
-                .unwrap_or_else(|_| "8".to_string())
-                .parse()
-                .unwrap_or(8);
+            let max_n_rows = config.fmt_max_rows;
             if max_n_rows < 2 {
                 2
             } else {
@@ -416,7 +421,7 @@ impl Display for DataFrame {
                 constraints.push(tbl_lower_bounds(l));
             }
             let mut table = Table::new();
-            let preset = if std::env::var("POLARS_FMT_NO_UTF8").is_ok() {
+            let preset = if config.fmt_no_utf8 {
                 ASCII_FULL
             } else {
                 UTF8_FULL
@@ -454,14 +459,7 @@ impl Display for DataFrame {
 
             table.set_header(names).set_constraints(constraints);
 
-            let tbl_width = std::env::var("POLARS_TABLE_WIDTH")
-                .map(|s| {
-                    Some(
-                        s.parse::<u16>()
-                            .expect("could not parse table width argument"),
-                    )
-                })
-                .unwrap_or(None);
+            let tbl_width = config.table_width;
             // if tbl_width is explicitly set, use it
             if let Some(w) = tbl_width {
                 table.set_table_width(w);
@@ -3,6 +3,17 @@ use crate::prelude::*;
 use crate::utils::accumulate_dataframes_vertical_unchecked;
 use arrow::buffer::Buffer;
 
+/// Arguments for [`DataFrame::melt2`].
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub struct MeltArgs {
+    pub id_vars: Vec<String>,
+    pub value_vars: Vec<String>,
+    /// Name of the column that holds the melted column names. Defaults to `"variable"`.
+    pub variable_name: Option<String>,
+    /// Name of the column that holds the melted values. Defaults to `"value"`.
+    pub value_name: Option<String>,
+}
+
 fn get_exploded(series: &Series) -> Result<(Series, Buffer<i64>)> {
     match series.dtype() {
         DataType::List(_) => series.list().unwrap().explode_and_offsets(),
@@ -35,7 +46,7 @@ impl DataFrame {
                 // expand all the other columns based the exploded first column
                 if i == 0 {
                     let row_idx = offsets_to_indexes(&offsets, exploded.len());
-                    let row_idx = UInt32Chunked::from_vec("", row_idx);
+                    let row_idx = IdxCa::from_vec("", row_idx);
                     // Safety
                     // We just created indices that are in bounds.
                     df = unsafe { df.take_unchecked(&row_idx) };
@@ -186,18 +197,34 @@ impl DataFrame {
         J: IntoIterator<Item = S>,
         S: AsRef<str>,
     {
-        let ids = self.select(id_vars)?;
-        let len = self.height();
+        self.melt2(MeltArgs {
+            id_vars: id_vars
+                .into_iter()
+                .map(|s| s.as_ref().to_string())
+                .collect(),
+            value_vars: value_vars
+                .into_iter()
+                .map(|s| s.as_ref().to_string())
+                .collect(),
+            ..Default::default()
+        })
+    }
 
-        let value_vars = value_vars.into_iter();
+    /// Similar to [`melt`](DataFrame::melt), but the names of the `variable` and `value`
+    /// columns can be set with [`MeltArgs`].
+    pub fn melt2(&self, args: MeltArgs) -> Result<Self> {
+        let variable_name = args.variable_name.as_deref().unwrap_or("variable");
+        let value_name = args.value_name.as_deref().unwrap_or("value");
+        let ids = self.select(&args.id_vars)?;
+        let len = self.height();
 
-        let mut dataframe_chunks = Vec::with_capacity(value_vars.size_hint().0);
+        let mut dataframe_chunks = Vec::with_capacity(args.value_vars.len());
 
-        for value_column_name in value_vars {
-            let value_column_name = value_column_name.as_ref();
-            let variable_col = Utf8Chunked::full("variable", value_column_name, len).into_series();
+        for value_column_name in &args.value_vars {
+            let variable_col =
+                Utf8Chunked::full(variable_name, value_column_name, len).into_series();
             let mut value_col = self.column(value_column_name)?.clone();
-            value_col.rename("value");
+            value_col.rename(value_name);
 
             let mut df_chunk = ids.clone();
             df_chunk.hstack_mut(&[variable_col, value_col])?;
@@ -279,6 +306,15 @@ mod test {
         assert_eq!(
             Vec::from(melted.column("value").unwrap().i32().unwrap()),
             &[Some(10), Some(11), Some(12), Some(2), Some(4), Some(6)]
-        )
+        );
+
+        let args = MeltArgs {
+            id_vars: vec!["A".into()],
+            value_vars: vec!["C".into(), "D".into()],
+            variable_name: Some("col".into()),
+            value_name: Some("val".into()),
+        };
+        let melted = df.melt2(args).unwrap();
+        assert_eq!(melted.get_column_names(), &["A", "col", "val"]);
     }
 }
@@ -0,0 +1,501 @@
+//! External merge sort.
+//!
+//! The input is collected in runs that fit in the memory budget. Every run is sorted in memory
+//! and spilled to a temporary IPC file in batches. The sorted runs are then k-way merged with a
+//! binary heap that holds the current row of every run, so that only one batch per run is loaded
+//! at a time.
+use crate::frame::spill::{SpillDir, SpillFile, SpillFileReader, SpillOptions};
+use crate::prelude::*;
+use crate::utils::accumulate_dataframes_vertical;
+use arrow::array::{Array, BooleanArray, PrimitiveArray, Utf8Array};
+use std::cmp::Ordering;
+use std::collections::binary_heap::PeekMut;
+use std::collections::BinaryHeap;
+
+/// Number of batches a run is spilled in. This bounds the memory used by the merge phase
+/// as long as the number of runs doesn't exceed this number.
+const BATCHES_PER_RUN: usize = 16;
+
+/// Create the sort keys of `df`. Columns with nulls get an extra key in front that moves the
+/// nulls to the requested side, independent of the sort direction of the column.
+fn sort_keys(
+    df: &DataFrame,
+    by: &[String],
+    options: &[SortOptions],
+) -> Result<(Vec<Series>, Vec<bool>)> {
+    let mut keys = Vec::with_capacity(by.len() * 2);
+    let mut reverse = Vec::with_capacity(by.len() * 2);
+    for (i, (name, options)) in by.iter().zip(options).enumerate() {
+        let s = df.column(name)?;
+        if s.null_count() > 0 {
+            let mut is_null = s.is_null().into_series();
+            is_null.rename(&format!("_POLARS_SORT_NULLS_{}", i));
+            keys.push(is_null);
+            reverse.push(!options.nulls_last);
+        }
+        keys.push(s.clone());
+        reverse.push(options.descending);
+    }
+    Ok((keys, reverse))
+}
+
+fn sort_run(df: &DataFrame, by: &[String], options: &[SortOptions]) -> Result<DataFrame> {
+    let (keys, reverse) = sort_keys(df, by, options)?;
+    df.sort_impl(keys, reverse)
+}
+
+/// A key column of a loaded batch as a single typed array, so that rows are compared without
+/// creating an `AnyValue` per comparison. Integers are widened to 64 bits.
+enum KeyColumn {
+    Boolean(BooleanArray),
+    Int64(PrimitiveArray<i64>),
+    UInt64(PrimitiveArray<u64>),
+    Float64(PrimitiveArray<f64>),
+    Utf8(Utf8Array<i64>),
+}
+
+impl KeyColumn {
+    /// Lexically ordered categoricals are compared by their strings and other logical types by
+    /// their physical values.
+    fn new(s: &Series) -> Result<Self> {
+        macro_rules! array {
+            ($ca:expr) => {
+                $ca.rechunk().downcast_iter().next().unwrap().clone()
+            };
+        }
+
+        #[cfg(feature = "dtype-categorical")]
+        if let Ok(ca) = s.categorical() {
+            if ca.sorts_lexically() {
+                return KeyColumn::new(&s.cast(&DataType::Utf8)?);
+            }
+        }
+        let s = s.to_physical_repr();
+        let key = match s.dtype() {
+            DataType::Boolean => KeyColumn::Boolean(array!(s.bool()?)),
+            DataType::Utf8 => KeyColumn::Utf8(array!(s.utf8()?)),
+            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+                KeyColumn::Int64(array!(s.cast(&DataType::Int64)?.i64()?))
+            }
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+                KeyColumn::UInt64(array!(s.cast(&DataType::UInt64)?.u64()?))
+            }
+            DataType::Float32 | DataType::Float64 => {
+                KeyColumn::Float64(array!(s.cast(&DataType::Float64)?.f64()?))
+            }
+            dt => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("cannot sort by a column of dtype {:?}", dt).into(),
+                ))
+            }
+        };
+        Ok(key)
+    }
+
+    fn cmp_rows(
+        &self,
+        row: usize,
+        other: &Self,
+        other_row: usize,
+        options: &SortOptions,
+    ) -> Ordering {
+        macro_rules! cmp_values {
+            ($a:expr, $b:expr, $cmp:expr) => {
+                compare_opt(
+                    $a.is_valid(row).then(|| $a.value(row)),
+                    $b.is_valid(other_row).then(|| $b.value(other_row)),
+                    options,
+                    $cmp,
+                )
+            };
+        }
+
+        match (self, other) {
+            (KeyColumn::Boolean(a), KeyColumn::Boolean(b)) => cmp_values!(a, b, Ord::cmp),
+            (KeyColumn::Int64(a), KeyColumn::Int64(b)) => cmp_values!(a, b, Ord::cmp),
+            (KeyColumn::UInt64(a), KeyColumn::UInt64(b)) => cmp_values!(a, b, Ord::cmp),
+            // NaN is the largest value, like in the in-memory sort
+            (KeyColumn::Float64(a), KeyColumn::Float64(b)) => {
+                cmp_values!(a, b, |a: &f64, b: &f64| a
+                    .partial_cmp(b)
+                    .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan())))
+            }
+            (KeyColumn::Utf8(a), KeyColumn::Utf8(b)) => cmp_values!(a, b, Ord::cmp),
+            // all runs are sorted by the same columns, so their key types match
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn merge_keys(df: &DataFrame, by: &[String]) -> Result<Vec<KeyColumn>> {
+    by.iter()
+        .map(|name| KeyColumn::new(df.column(name)?))
+        .collect()
+}
+
+fn compare_opt<T>(
+    a: Option<T>,
+    b: Option<T>,
+    options: &SortOptions,
+    cmp: impl Fn(&T, &T) -> Ordering,
+) -> Ordering {
+    let ord = match (a, b) {
+        (None, None) => return Ordering::Equal,
+        // nulls are placed independent of the sort direction
+        (None, _) if options.nulls_last => return Ordering::Greater,
+        (None, _) => return Ordering::Less,
+        (_, None) if options.nulls_last => return Ordering::Less,
+        (_, None) => return Ordering::Greater,
+        (Some(a), Some(b)) => cmp(&a, &b),
+    };
+    if options.descending {
+        ord.reverse()
+    } else {
+        ord
+    }
+}
+
+/// The current row of a run in the merge heap.
+struct Cursor<'a> {
+    run_idx: usize,
+    batch_idx: usize,
+    row: usize,
+    keys: Vec<KeyColumn>,
+    sort_options: &'a [SortOptions],
+}
+
+impl Cursor<'_> {
+    fn cmp_rows(&self, other: &Self) -> Ordering {
+        for ((l, r), options) in self.keys.iter().zip(&other.keys).zip(self.sort_options) {
+            match l.cmp_rows(self.row, r, other.row, options) {
+                Ordering::Equal => continue,
+                ord => return ord,
+            }
+        }
+        // keep equal rows in the order of the runs
+        self.run_idx.cmp(&other.run_idx)
+    }
+}
+
+impl PartialEq for Cursor<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Cursor<'_> {}
+
+impl PartialOrd for Cursor<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cursor<'_> {
+    // `BinaryHeap` is a max heap, so the smallest row must compare as the largest.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cmp_rows(self)
+    }
+}
+
+/// The batches of the runs that are loaded during the merge.
+struct LoadedBatches {
+    batches: Vec<Option<DataFrame>>,
+    /// Index of the loaded batch of every run.
+    current: Vec<Option<usize>>,
+}
+
+impl LoadedBatches {
+    /// Load the next non-empty batch of run `run_idx`, if any, and return its index.
+    fn load_next(
+        &mut self,
+        run_idx: usize,
+        batches: &mut SpillFileReader,
+        by: &[String],
+    ) -> Result<Option<(usize, Vec<KeyColumn>)>> {
+        self.current[run_idx] = None;
+        for batch in batches {
+            let batch = batch?;
+            if batch.height() == 0 {
+                continue;
+            }
+            let keys = merge_keys(&batch, by)?;
+            self.batches.push(Some(batch));
+            let batch_idx = self.batches.len() - 1;
+            self.current[run_idx] = Some(batch_idx);
+            return Ok(Some((batch_idx, keys)));
+        }
+        Ok(None)
+    }
+
+    fn height(&self, batch_idx: usize) -> usize {
+        self.batches[batch_idx].as_ref().unwrap().height()
+    }
+
+    /// Gather the rows `(batch_idx, row)` in order and release the batches that are
+    /// no longer loaded by any run.
+    fn take(&mut self, rows: &[(usize, usize)]) -> Result<DataFrame> {
+        let mut offsets = PlHashMap::new();
+        let mut used = vec![];
+        let mut height = 0;
+        let idx = rows
+            .iter()
+            .map(|&(batch_idx, row)| {
+                let offset = *offsets.entry(batch_idx).or_insert_with(|| {
+                    let offset = height;
+                    height += self.height(batch_idx);
+                    used.push(self.batches[batch_idx].clone().unwrap());
+                    offset
+                });
+                (offset + row) as IdxSize
+            })
+            .collect::<NoNull<IdxCa>>()
+            .into_inner();
+        let df = accumulate_dataframes_vertical(used)?.take(&idx)?;
+
+        for (batch_idx, batch) in self.batches.iter_mut().enumerate() {
+            if batch.is_some() && !self.current.contains(&Some(batch_idx)) {
+                *batch = None;
+            }
+        }
+        Ok(df)
+    }
+}
+
+/// Sorts `DataFrame` chunks that together may not fit in memory.
+///
+/// Chunks are buffered until they exceed the memory budget. The buffered chunks are then sorted
+/// as a run and spilled to a temporary IPC file. The runs are merged when the sorter is finished.
+/// If all chunks fit in the memory budget, nothing is spilled.
+pub struct ExternalSorter {
+    by: Vec<String>,
+    sort_options: Vec<SortOptions>,
+    memory_budget: usize,
+    spill_dir: SpillDir,
+    files: Vec<SpillFile>,
+    buffer: Vec<DataFrame>,
+    buffered_bytes: usize,
+}
+
+impl ExternalSorter {
+    /// * `by` - the columns to sort by, in order of priority.
+    /// * `sort_options` - sort direction and placement of nulls per column. A single value is
+    ///   broadcast to all columns. Nulls are placed first or last regardless of the direction.
+    pub fn new(
+        by: impl IntoVec<String>,
+        sort_options: &[SortOptions],
+        options: &SpillOptions,
+    ) -> Result<Self> {
+        let by = by.into_vec();
+        let mut sort_options = sort_options.to_vec();
+        if by.is_empty() {
+            return Err(PolarsError::ValueError(
+                "expected at least one column to sort by".into(),
+            ));
+        }
+        if sort_options.len() == 1 && by.len() > 1 {
+            sort_options = vec![sort_options[0]; by.len()];
+        }
+        if sort_options.len() != by.len() {
+            return Err(PolarsError::ValueError(
+                format!(
+                    "the number of sort options: {} does not match the number of columns: {}",
+                    sort_options.len(),
+                    by.len()
+                )
+                .into(),
+            ));
+        }
+        Ok(ExternalSorter {
+            by,
+            sort_options,
+            memory_budget: options.memory_budget,
+            spill_dir: SpillDir::new(options.spill_dir.as_deref())?,
+            files: vec![],
+            buffer: vec![],
+            buffered_bytes: 0,
+        })
+    }
+
+    /// Add a chunk. The buffered chunks are sorted and spilled once they exceed the budget.
+    pub fn push(&mut self, df: DataFrame) -> Result<()> {
+        self.buffered_bytes += df.estimated_size();
+        self.buffer.push(df);
+        if self.buffered_bytes > self.memory_budget {
+            self.spill_run()?;
+        }
+        Ok(())
+    }
+
+    fn spill_run(&mut self) -> Result<()> {
+        let run = accumulate_dataframes_vertical(std::mem::take(&mut self.buffer))?;
+        let mut run = sort_run(&run, &self.by, &self.sort_options)?;
+        let batch_size = run.height() / BATCHES_PER_RUN + 1;
+        self.files
+            .push(self.spill_dir.spill_batched(&mut run, batch_size)?);
+        self.buffered_bytes = 0;
+        Ok(())
+    }
+
+    /// Sort the chunks that are pushed.
+    pub fn finish(mut self) -> Result<DataFrame> {
+        if self.files.is_empty() {
+            if self.buffer.is_empty() {
+                return Err(PolarsError::NoData(
+                    "cannot sort an empty sequence of DataFrames".into(),
+                ));
+            }
+            let df = accumulate_dataframes_vertical(self.buffer)?;
+            return sort_run(&df, &self.by, &self.sort_options);
+        }
+        if !self.buffer.is_empty() {
+            self.spill_run()?;
+        }
+        let by = &self.by;
+        let sort_options = &self.sort_options;
+        let files = &self.files;
+
+        let mut readers = files
+            .iter()
+            .map(|file| file.batches())
+            .collect::<Result<Vec<_>>>()?;
+        let mut loaded = LoadedBatches {
+            batches: vec![],
+            current: vec![None; readers.len()],
+        };
+        let mut heap = BinaryHeap::with_capacity(readers.len());
+        for (run_idx, reader) in readers.iter_mut().enumerate() {
+            if let Some((batch_idx, keys)) = loaded.load_next(run_idx, reader, by)? {
+                heap.push(Cursor {
+                    run_idx,
+                    batch_idx,
+                    row: 0,
+                    keys,
+                    sort_options,
+                });
+            }
+        }
+
+        // Rows are gathered from the loaded batches in chunks of roughly one batch per run.
+        let flush_len = std::cmp::max(
+            files.iter().map(|f| f.height()).sum::<usize>() / BATCHES_PER_RUN,
+            1,
+        );
+        let mut out = vec![];
+        let mut rows = Vec::with_capacity(flush_len);
+        while let Some(mut cursor) = heap.peek_mut() {
+            rows.push((cursor.batch_idx, cursor.row));
+            cursor.row += 1;
+            // updating the cursor in place restores the heap order when it is dropped
+            if cursor.row == loaded.height(cursor.batch_idx) {
+                let run_idx = cursor.run_idx;
+                match loaded.load_next(run_idx, &mut readers[run_idx], by)? {
+                    Some((batch_idx, keys)) => {
+                        cursor.batch_idx = batch_idx;
+                        cursor.row = 0;
+                        cursor.keys = keys;
+                    }
+                    None => {
+                        PeekMut::pop(cursor);
+                    }
+                }
+            }
+            if rows.len() >= flush_len {
+                out.push(loaded.take(&rows)?);
+                rows.clear();
+            }
+        }
+        if !rows.is_empty() {
+            out.push(loaded.take(&rows)?);
+        }
+        accumulate_dataframes_vertical(out)
+    }
+}
+
+/// Sort a sequence of `DataFrame` chunks that together may not fit in memory with an
+/// [`ExternalSorter`].
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// use polars_core::frame::external_sort::sort_external;
+/// use polars_core::frame::spill::SpillOptions;
+///
+/// fn example(chunks: Vec<DataFrame>) -> Result<DataFrame> {
+///     sort_external(
+///         chunks.into_iter().map(Ok),
+///         ["date", "temp"],
+///         &[SortOptions {
+///             descending: true,
+///             nulls_last: true,
+///         }],
+///         &SpillOptions::new(1 << 30),
+///     )
+/// }
+/// ```
+pub fn sort_external<I>(
+    chunks: I,
+    by: impl IntoVec<String>,
+    sort_options: &[SortOptions],
+    options: &SpillOptions,
+) -> Result<DataFrame>
+where
+    I: IntoIterator<Item = Result<DataFrame>>,
+{
+    let mut sorter = ExternalSorter::new(by, sort_options, options)?;
+    for df in chunks {
+        sorter.push(df?)?;
+    }
+    sorter.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sort_external() -> Result<()> {
+        let df = df![
+            "a" => (0..1000).map(|i| if i % 11 == 0 { None } else { Some((i * 7) % 13) }).collect::<Vec<_>>(),
+            "b" => (0..1000).map(|i| (i * 3) % 17).collect::<Vec<i32>>()
+        ]?;
+        let chunks = (0..10).map(|i| Ok(df.slice(i * 100, 100)));
+
+        let options = [
+            SortOptions {
+                descending: true,
+                nulls_last: true,
+            },
+            SortOptions {
+                descending: false,
+                nulls_last: false,
+            },
+        ];
+        let out = sort_external(chunks, ["a", "b"], &options, &SpillOptions::new(1024))?;
+        assert_eq!(out.height(), df.height());
+
+        // the in memory sort places nulls last when the first column is reversed
+        let expected = df.sort(["a", "b"], vec![true, false])?;
+        assert!(out.frame_equal_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_external_utf8() -> Result<()> {
+        let df = df![
+            "a" => (0..1000).map(|i| format!("{}", (i * 7) % 31)).collect::<Vec<_>>(),
+            "b" => (0..1000).collect::<Vec<i32>>()
+        ]?;
+        let chunks = (0..10).map(|i| Ok(df.slice(i * 100, 100)));
+
+        let options = [SortOptions {
+            descending: false,
+            nulls_last: false,
+        }];
+        let out = sort_external(chunks, ["a", "b"], &options, &SpillOptions::new(1024))?;
+        let expected = df.sort(["a", "b"], false)?;
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
+}
@@ -33,7 +33,8 @@ pub(crate) fn groupby<T>(a: impl Iterator<Item = T>, sorted: bool) -> GroupsProx
 where
     T: Hash + Eq,
 {
-    let mut hash_tbl: PlHashMap<T, (u32, Vec<u32>)> = PlHashMap::with_capacity(HASHMAP_INIT_SIZE);
+    let mut hash_tbl: PlHashMap<T, (IdxSize, Vec<IdxSize>)> =
+        PlHashMap::with_capacity(HASHMAP_INIT_SIZE);
     let mut cnt = 0;
     a.for_each(|k| {
         let idx = cnt;
@@ -80,6 +81,7 @@ where
     IntoSlice: AsRef<[T]> + Send + Sync,
 {
     assert!(n_partitions.is_power_of_two());
+    let token = CancellationToken::current();
 
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
@@ -89,19 +91,21 @@ where
             (0..n_partitions).into_par_iter().map(|thread_no| {
                 let thread_no = thread_no as u64;
 
-                let mut hash_tbl: PlHashMap<T, (u32, Vec<u32>)> =
+                let mut hash_tbl: PlHashMap<T, (IdxSize, Vec<IdxSize>)> =
                     PlHashMap::with_capacity(HASHMAP_INIT_SIZE);
 
                 let mut offset = 0;
-                for keys in &keys {
+                'keys: for keys in &keys {
                     let keys = keys.as_ref();
-                    let len = keys.len() as u32;
+                    let len = keys.len() as IdxSize;
                     let hasher = hash_tbl.hasher().clone();
 
-                    let mut cnt = 0;
-                    keys.iter().for_each(|k| {
-                        let idx = cnt + offset;
-                        cnt += 1;
+                    for (cnt, k) in keys.iter().enumerate() {
+                        // the caller returns the error of the token
+                        if token.is_cancelled_at(cnt) {
+                            break 'keys;
+                        }
+                        let idx = cnt as IdxSize + offset;
 
                         if this_partition(k.as_u64(), thread_no, n_partitions) {
                             let hash = T::get_hash(k, &hasher);
@@ -121,7 +125,7 @@ where
                                 }
                             }
                         }
-                    });
+                    }
                     offset += len;
                 }
                 hash_tbl
@@ -160,7 +164,7 @@ pub(crate) unsafe fn compare_df_rows(keys: &DataFrame, idx_a: usize, idx_b: usiz
 pub(crate) fn populate_multiple_key_hashmap<V, H, F, G>(
     hash_tbl: &mut HashMap<IdxHash, V, H>,
     // row index
-    idx: u32,
+    idx: IdxSize,
     // hash
     original_h: u64,
     // keys of the hash table (will not be inserted, the indexes will be used)
@@ -218,7 +222,7 @@ pub(crate) unsafe fn compare_keys<'a>(
 pub(crate) fn populate_multiple_key_hashmap2<'a, V, H, F, G>(
     hash_tbl: &mut HashMap<IdxHash, V, H>,
     // row index
-    idx: u32,
+    idx: IdxSize,
     // hash
     original_h: u64,
     // keys of the hash table (will not be inserted, the indexes will be used)
@@ -265,6 +269,7 @@ pub(crate) fn groupby_threaded_multiple_keys_flat(
     let dfs = split_df(&keys, n_partitions).unwrap();
     let (hashes, _random_state) = df_rows_to_hashes_threaded(&dfs, None);
     let n_partitions = n_partitions as u64;
+    let token = CancellationToken::current();
 
     // trait object to compare inner types.
     let keys_cmp = keys
@@ -281,16 +286,20 @@ pub(crate) fn groupby_threaded_multiple_keys_flat(
                 let hashes = &hashes;
                 let thread_no = thread_no as u64;
 
-                let mut hash_tbl: HashMap<IdxHash, (u32, Vec<u32>), IdBuildHasher> =
+                let mut hash_tbl: HashMap<IdxHash, (IdxSize, Vec<IdxSize>), IdBuildHasher> =
                     HashMap::with_capacity_and_hasher(HASHMAP_INIT_SIZE, Default::default());
 
                 let mut offset = 0;
-                for hashes in hashes {
-                    let len = hashes.len() as u32;
+                'hashes: for hashes in hashes {
+                    let len = hashes.len() as IdxSize;
 
                     let mut idx = 0;
                     for hashes_chunk in hashes.data_views() {
                         for &h in hashes_chunk {
+                            // the caller returns the error of the token
+                            if token.is_cancelled_at(idx as usize) {
+                                break 'hashes;
+                            }
                             // partition hashes by thread no.
                             // So only a part of the hashes go to this hashmap
                             if this_partition(h, thread_no, n_partitions) {
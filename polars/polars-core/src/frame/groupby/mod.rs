@@ -22,13 +22,17 @@ pub(crate) mod hashing;
 #[cfg(feature = "rows")]
 pub(crate) mod pivot;
 mod proxy;
+#[cfg(feature = "spill")]
+mod spill;
 
 #[cfg(feature = "rows")]
 pub use pivot::PivotAgg;
+#[cfg(feature = "spill")]
+pub use spill::{groupby_spilled, HashPartitioner};
 
 pub use proxy::*;
 
-pub type GroupedMap<T> = HashMap<T, Vec<u32>, RandomState>;
+pub type GroupedMap<T> = HashMap<T, Vec<IdxSize>, RandomState>;
 
 /// Used to create the tuples for a groupby operation.
 pub trait IntoGroupsProxy {
@@ -337,12 +341,10 @@ impl DataFrame {
                         .collect::<Vec<_>>()
                 });
 
-                return Ok(GroupBy::new(
-                    self,
-                    by,
-                    groupby_threaded_num(keys, 0, n_partitions as u64, sorted),
-                    None,
-                ));
+                let groups = groupby_threaded_num(keys, 0, n_partitions as u64, sorted);
+                // the groups are incomplete if the query was cancelled
+                CancellationToken::current().check()?;
+                return Ok(GroupBy::new(self, by, groups, None));
             }};
         }
 
@@ -357,7 +359,7 @@ impl DataFrame {
         let keys_df = DataFrame::new(
             by.iter()
                 .map(|s| match s.dtype() {
-                    Categorical | Int8 | UInt8 | Int16 | UInt16 => {
+                    Categorical | Enum(_) | Int8 | UInt8 | Int16 | UInt16 => {
                         s.cast(&DataType::UInt32).unwrap()
                     }
                     Float32 => s.bit_repr_small().into_series(),
@@ -419,6 +421,8 @@ impl DataFrame {
                 groupby_threaded_multiple_keys_flat(keys_df, n_partitions, sorted)
             }
         };
+        // the groups are incomplete if the query was cancelled
+        CancellationToken::current().check()?;
         Ok(GroupBy::new(self, by, groups, None))
     }
 
@@ -239,7 +239,10 @@ impl DataFrame {
                             // we offset 1 because the first is the group index (can be removed?)
                             let current_result = &im_r[column_index + 1];
                             let key = &current_result.get_columns()[0];
-                            let tuples = unique_vals.hash_join_left(key);
+                            // without validation the join can't fail
+                            let tuples = unique_vals
+                                .hash_join_left(key, JoinValidation::ManyToMany)
+                                .unwrap();
                             let mut iter = tuples.iter().map(|t| t.1.map(|i| i as usize));
 
                             let values = &current_result.get_columns()[1];
@@ -259,7 +262,7 @@ impl DataFrame {
                 match (dtype.clone(), &agg_fn) {
                     (DataType::Float32, PivotAgg::Mean | PivotAgg::Median) => {}
                     (_, PivotAgg::Mean | PivotAgg::Median) => dtype = DataType::Float64,
-                    (_, PivotAgg::Count) => dtype = DataType::UInt32,
+                    (_, PivotAgg::Count) => dtype = IDX_DTYPE,
                     _ => {}
                 }
                 let mut out = results.transpose_from_dtype(&dtype).unwrap();
@@ -409,7 +412,7 @@ fn finish_logical_types(
     // We cast the column headers to another string repr
     match columns.dtype() {
         #[cfg(feature = "dtype-categorical")]
-        DataType::Categorical => {
+        DataType::Categorical | DataType::Enum(_) => {
             let piv = columns.categorical().unwrap();
             let rev_map = piv.categorical_map.as_ref().unwrap().clone();
             for s in out.columns.iter_mut() {
@@ -446,7 +449,7 @@ fn finish_logical_types(
     let dtype = values.dtype();
     match dtype {
         #[cfg(feature = "dtype-categorical")]
-        DataType::Categorical => {
+        DataType::Categorical | DataType::Enum(_) => {
             let piv = columns.categorical().unwrap();
             let rev_map = piv.categorical_map.as_ref().cloned();
 
@@ -579,7 +582,7 @@ mod test {
             .count()
             .unwrap();
         assert_eq!(
-            Vec::from(&pvt.column("m").unwrap().u32().unwrap().sort(false)),
+            Vec::from(&pvt.column("m").unwrap().idx().unwrap().sort(false)),
             &[None, None, Some(2)]
         );
     }
@@ -0,0 +1,186 @@
+//! Groupby that spills its partitions to disk.
+//!
+//! The input is hash partitioned on the group keys, so that every group ends up in a single
+//! partition. Partitions are buffered in memory and spilled to disk when the memory budget is
+//! exceeded. Finally every partition is aggregated separately and the results are concatenated.
+//! The input is pushed in chunks, so only a caller that produces the chunks one by one keeps
+//! the input itself out of memory.
+use crate::frame::spill::{SpillDir, SpillFile, SpillOptions};
+use crate::prelude::*;
+use crate::utils::accumulate_dataframes_vertical;
+use crate::vector_hasher::df_rows_to_hashes;
+use ahash::RandomState;
+
+/// Hash partitions `DataFrame`s on a set of keys and spills the partitions to disk
+/// when they don't fit in the memory budget.
+///
+/// Note that `Categorical` keys are hashed on their physical representation, so chunks with
+/// categorical keys should be created under a global string cache.
+pub struct HashPartitioner {
+    memory_budget: usize,
+    random_state: RandomState,
+    buffers: Vec<Vec<DataFrame>>,
+    buffered_bytes: Vec<usize>,
+    files: Vec<Vec<SpillFile>>,
+    spill_dir: SpillDir,
+}
+
+impl HashPartitioner {
+    pub fn new(options: &SpillOptions) -> Result<Self> {
+        let n_partitions = std::cmp::max(options.n_partitions, 1);
+        Ok(HashPartitioner {
+            memory_budget: options.memory_budget,
+            random_state: RandomState::new(),
+            buffers: (0..n_partitions).map(|_| vec![]).collect(),
+            buffered_bytes: vec![0; n_partitions],
+            files: (0..n_partitions).map(|_| vec![]).collect(),
+            spill_dir: SpillDir::new(options.spill_dir.as_deref())?,
+        })
+    }
+
+    pub fn n_partitions(&self) -> usize {
+        self.buffers.len()
+    }
+
+    /// Partition the rows of `df` by the hashes of `keys`.
+    pub fn push(&mut self, df: &DataFrame, keys: Vec<Series>) -> Result<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+        let n_partitions = self.n_partitions();
+        let keys = DataFrame::new_no_checks(keys);
+        let (hashes, _) = df_rows_to_hashes(&keys, Some(self.random_state.clone()));
+
+        let mut partition_idx = vec![vec![]; n_partitions];
+        for (idx, h) in hashes.into_no_null_iter().enumerate() {
+            partition_idx[(h % n_partitions as u64) as usize].push(idx as IdxSize);
+        }
+
+        for (partition, idx) in partition_idx.into_iter().enumerate() {
+            if idx.is_empty() {
+                continue;
+            }
+            let idx = IdxCa::from_vec("", idx);
+            let part = df.take(&idx)?;
+            self.buffered_bytes[partition] += part.estimated_size();
+            self.buffers[partition].push(part);
+        }
+
+        // spill the largest partitions until we are within budget again
+        while self.buffered_bytes.iter().sum::<usize>() > self.memory_budget {
+            let (partition, _) = self
+                .buffered_bytes
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, bytes)| **bytes)
+                .unwrap();
+            let dfs = std::mem::take(&mut self.buffers[partition]);
+            let mut df = accumulate_dataframes_vertical(dfs)?;
+            self.buffered_bytes[partition] = 0;
+            let file = self.spill_dir.spill(&mut df)?;
+            self.files[partition].push(file);
+        }
+        Ok(())
+    }
+
+    /// Load a partition into memory. Returns `None` if the partition is empty.
+    /// The partition is cleared afterwards.
+    pub fn load_partition(&mut self, partition: usize) -> Result<Option<DataFrame>> {
+        let mut dfs = std::mem::take(&mut self.files[partition])
+            .iter()
+            .map(|file| file.read())
+            .collect::<Result<Vec<_>>>()?;
+        dfs.extend(std::mem::take(&mut self.buffers[partition]));
+        self.buffered_bytes[partition] = 0;
+
+        if dfs.is_empty() {
+            Ok(None)
+        } else {
+            accumulate_dataframes_vertical(dfs).map(Some)
+        }
+    }
+}
+
+/// Groupby and aggregate a sequence of `DataFrame` chunks that together may not fit in memory.
+///
+/// The chunks are hash partitioned on the `by` columns. Partitions that don't fit in the memory
+/// budget of `options` are spilled to temporary IPC files. Every partition is aggregated
+/// separately, so all aggregations of [`GroupBy::agg`] are supported: `"min"`, `"max"`, `"sum"`,
+/// `"mean"`, `"count"`, `"first"`, `"last"`, `"n_unique"`, `"median"`, `"std"` and `"var"`.
+///
+/// The order of the groups in the output is not defined.
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// use polars_core::frame::groupby::groupby_spilled;
+/// use polars_core::frame::spill::SpillOptions;
+///
+/// fn example(chunks: Vec<DataFrame>) -> Result<DataFrame> {
+///     groupby_spilled(
+///         chunks.into_iter().map(Ok),
+///         &["date"],
+///         &[("temp", &["sum", "n_unique"])],
+///         &SpillOptions::new(1 << 30),
+///     )
+/// }
+/// ```
+pub fn groupby_spilled<I, S, Column, Agg, Slice>(
+    chunks: I,
+    by: &[S],
+    column_to_agg: &[(Column, Slice)],
+    options: &SpillOptions,
+) -> Result<DataFrame>
+where
+    I: IntoIterator<Item = Result<DataFrame>>,
+    S: AsRef<str>,
+    Column: AsRef<str>,
+    Agg: AsRef<str>,
+    Slice: AsRef<[Agg]>,
+{
+    let mut partitioner = HashPartitioner::new(options)?;
+    for df in chunks {
+        let df = df?;
+        let keys = df.select_series(by)?;
+        partitioner.push(&df, keys)?;
+    }
+
+    let mut out = vec![];
+    for partition in 0..partitioner.n_partitions() {
+        if let Some(df) = partitioner.load_partition(partition)? {
+            out.push(df.groupby(by)?.agg(column_to_agg)?);
+        }
+    }
+    if out.is_empty() {
+        return Err(PolarsError::NoData(
+            "cannot groupby an empty sequence of DataFrames".into(),
+        ));
+    }
+    accumulate_dataframes_vertical(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_groupby_spilled() -> Result<()> {
+        let df = df![
+            "key" => (0..1000).map(|i| i % 7).collect::<Vec<i32>>(),
+            "value" => (0..1000).collect::<Vec<i32>>()
+        ]?;
+        let chunks = (0..10).map(|i| Ok(df.slice(i * 100, 100)));
+
+        let mut options = SpillOptions::new(1024);
+        options.n_partitions = 4;
+        let out = groupby_spilled(chunks, &["key"], &[("value", &["sum", "count"])], &options)?
+            .sort(["key"], false)?;
+        let expected = df
+            .groupby(["key"])?
+            .agg(&[("value", &["sum", "count"])])?
+            .sort(["key"], false)?;
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
+}
@@ -1,10 +1,14 @@
 pub(crate) mod multiple_keys;
+mod sort_merge;
+mod validation;
 
 use polars_arrow::utils::CustomIterTools;
 
 use crate::frame::hash_join::multiple_keys::{
     inner_join_multiple_keys, left_join_multiple_keys, outer_join_multiple_keys,
 };
+use crate::frame::hash_join::sort_merge::{merge_join_inner, merge_join_left};
+use crate::frame::hash_join::validation::{NullKeyFilter, NullKeyRows};
 use crate::prelude::*;
 use crate::utils::{set_partition_size, split_ca};
 use crate::vector_hasher::{
@@ -24,6 +28,7 @@ use unsafe_unwrap::UnsafeUnwrap;
 
 #[cfg(feature = "private")]
 pub use self::multiple_keys::private_left_join_multiple_keys;
+pub use self::validation::JoinValidation;
 use crate::frame::groupby::hashing::HASHMAP_INIT_SIZE;
 use crate::utils::series::to_physical_and_bit_repr;
 
@@ -69,11 +74,22 @@ pub enum JoinType {
     Cross,
 }
 
+/// Comparison between a left and a right column in a [`DataFrame::join_where`].
+/// The left column is on the left hand side of the operator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinOperator {
+    Eq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
 pub(crate) unsafe fn get_hash_tbl_threaded_join_partitioned<T, H>(
     h: u64,
-    hash_tables: &[HashMap<T, Vec<u32>, H>],
+    hash_tables: &[HashMap<T, Vec<IdxSize>, H>],
     len: u64,
-) -> &HashMap<T, Vec<u32>, H> {
+) -> &HashMap<T, Vec<IdxSize>, H> {
     let mut idx = 0;
     for i in 0..len {
         // can only be done for powers of two.
@@ -88,9 +104,9 @@ pub(crate) unsafe fn get_hash_tbl_threaded_join_partitioned<T, H>(
 #[allow(clippy::type_complexity)]
 unsafe fn get_hash_tbl_threaded_join_mut_partitioned<T, H>(
     h: u64,
-    hash_tables: &mut [HashMap<T, (bool, Vec<u32>), H>],
+    hash_tables: &mut [HashMap<T, (bool, Vec<IdxSize>), H>],
     len: u64,
-) -> &mut HashMap<T, (bool, Vec<u32>), H> {
+) -> &mut HashMap<T, (bool, Vec<IdxSize>), H> {
     let mut idx = 0;
     for i in 0..len {
         // can only be done for powers of two.
@@ -105,18 +121,22 @@ unsafe fn get_hash_tbl_threaded_join_mut_partitioned<T, H>(
 /// Probe the build table and add tuples to the results (inner join)
 fn probe_inner<T, F>(
     probe: &[T],
-    hash_tbls: &[PlHashMap<T, Vec<u32>>],
-    results: &mut Vec<(u32, u32)>,
+    hash_tbls: &[PlHashMap<T, Vec<IdxSize>>],
+    results: &mut Vec<(IdxSize, IdxSize)>,
     local_offset: usize,
     n_tables: u64,
+    token: &CancellationToken,
     swap_fn: F,
 ) where
     T: Send + Hash + Eq + Sync + Copy + AsU64,
-    F: Fn(u32, u32) -> (u32, u32),
+    F: Fn(IdxSize, IdxSize) -> (IdxSize, IdxSize),
 {
     assert!(hash_tbls.len().is_power_of_two());
-    probe.iter().enumerate().for_each(|(idx_a, k)| {
-        let idx_a = (idx_a + local_offset) as u32;
+    for (idx_a, k) in probe.iter().enumerate() {
+        if token.is_cancelled_at(idx_a) {
+            return;
+        }
+        let idx_a = (idx_a + local_offset) as IdxSize;
         // probe table that contains the hashed value
         let current_probe_table =
             unsafe { get_hash_tbl_threaded_join_partitioned(k.as_u64(), hash_tbls, n_tables) };
@@ -127,15 +147,21 @@ fn probe_inner<T, F>(
             let tuples = indexes_b.iter().map(|&idx_b| swap_fn(idx_a, idx_b));
             results.extend(tuples);
         }
-    });
+    }
 }
 
-pub(crate) fn create_probe_table<T, IntoSlice>(keys: Vec<IntoSlice>) -> Vec<PlHashMap<T, Vec<u32>>>
+/// Build the hash tables of a join. If the keys must be `unique`, `None` is returned at the
+/// first key that is inserted twice.
+pub(crate) fn create_probe_table<T, IntoSlice>(
+    keys: Vec<IntoSlice>,
+    unique: bool,
+) -> Option<Vec<PlHashMap<T, Vec<IdxSize>>>>
 where
     T: Send + Hash + Eq + Sync + Copy + AsU64,
     IntoSlice: AsRef<[T]> + Send + Sync,
 {
     let n_partitions = set_partition_size();
+    let token = CancellationToken::current();
 
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
@@ -144,18 +170,20 @@ where
         (0..n_partitions).into_par_iter().map(|partition_no| {
             let partition_no = partition_no as u64;
 
-            let mut hash_tbl: PlHashMap<T, Vec<u32>> = PlHashMap::with_capacity(HASHMAP_INIT_SIZE);
+            let mut hash_tbl: PlHashMap<T, Vec<IdxSize>> =
+                PlHashMap::with_capacity(HASHMAP_INIT_SIZE);
 
             let n_partitions = n_partitions as u64;
             let mut offset = 0;
             for keys in &keys {
                 let keys = keys.as_ref();
-                let len = keys.len() as u32;
+                let len = keys.len() as IdxSize;
 
-                let mut cnt = 0;
-                keys.iter().for_each(|k| {
-                    let idx = cnt + offset;
-                    cnt += 1;
+                for (cnt, k) in keys.iter().enumerate() {
+                    if token.is_cancelled_at(cnt) {
+                        return Some(hash_tbl);
+                    }
+                    let idx = cnt as IdxSize + offset;
 
                     if this_partition(k.as_u64(), partition_no, n_partitions) {
                         let entry = hash_tbl.entry(*k);
@@ -164,27 +192,43 @@ where
                             Entry::Vacant(entry) => {
                                 entry.insert(vec![idx]);
                             }
+                            Entry::Occupied(_) if unique => return None,
                             Entry::Occupied(mut entry) => {
                                 let v = entry.get_mut();
                                 v.push(idx);
                             }
                         }
                     }
-                });
+                }
                 offset += len;
             }
-            hash_tbl
+            Some(hash_tbl)
         })
     })
     .collect()
 }
 
+/// Whether the keys that probe the hash tables are unique.
+fn probe_is_unique<T, IntoSlice>(probe: &[IntoSlice]) -> bool
+where
+    T: Hash + Eq + Copy,
+    IntoSlice: AsRef<[T]>,
+{
+    let len = probe.iter().map(|keys| keys.as_ref().len()).sum();
+    let mut seen = PlHashSet::with_capacity(len);
+    probe
+        .iter()
+        .flat_map(|keys| keys.as_ref())
+        .all(|k| seen.insert(*k))
+}
+
 fn hash_join_tuples_inner<T, IntoSlice>(
     probe: Vec<IntoSlice>,
     build: Vec<IntoSlice>,
     // Because b should be the shorter relation we could need to swap to keep left left and right right.
     swap: bool,
-) -> Vec<(u32, u32)>
+    validate: JoinValidation,
+) -> Result<Vec<(IdxSize, IdxSize)>>
 where
     IntoSlice: AsRef<[T]> + Send + Sync,
     T: Send + Hash + Eq + Sync + Copy + AsU64,
@@ -192,7 +236,10 @@ where
     // NOTE: see the left join for more elaborate comments
 
     // first we hash one relation
-    let hash_tbls = create_probe_table(build);
+    let hash_tbls =
+        validate.check_build(create_probe_table(build, validate.needs_unique(swap)), swap)?;
+    validate.check_probe(swap, || probe_is_unique(&probe))?;
+    let token = CancellationToken::current();
 
     let n_tables = hash_tbls.len() as u64;
     debug_assert!(n_tables.is_power_of_two());
@@ -207,7 +254,7 @@ where
         .collect::<Vec<_>>();
     // next we probe the other relation
     // code duplication is because we want to only do the swap check once
-    POOL.install(|| {
+    Ok(POOL.install(|| {
         probe
             .into_par_iter()
             .zip(offsets)
@@ -226,6 +273,7 @@ where
                         &mut results,
                         local_offset,
                         n_tables,
+                        &token,
                         |idx_a, idx_b| (idx_b, idx_a),
                     )
                 } else {
@@ -235,6 +283,7 @@ where
                         &mut results,
                         local_offset,
                         n_tables,
+                        &token,
                         |idx_a, idx_b| (idx_a, idx_b),
                     )
                 }
@@ -243,19 +292,25 @@ where
             })
             .flatten()
             .collect()
-    })
+    }))
 }
 
 fn hash_join_tuples_left<T, IntoSlice>(
     probe: Vec<IntoSlice>,
     build: Vec<IntoSlice>,
-) -> Vec<(u32, Option<u32>)>
+    validate: JoinValidation,
+) -> Result<Vec<(IdxSize, Option<IdxSize>)>>
 where
     IntoSlice: AsRef<[T]> + Send + Sync,
     T: Send + Hash + Eq + Sync + Copy + AsU64,
 {
     // first we hash one relation
-    let hash_tbls = create_probe_table(build);
+    let hash_tbls = validate.check_build(
+        create_probe_table(build, validate.needs_unique(false)),
+        false,
+    )?;
+    validate.check_probe(false, || probe_is_unique(&probe))?;
+    let token = CancellationToken::current();
 
     // we determine the offset so that we later know which index to store in the join tuples
     let offsets = probe
@@ -272,7 +327,7 @@ where
     debug_assert!(n_tables.is_power_of_two());
 
     // next we probe the other relation
-    POOL.install(|| {
+    Ok(POOL.install(|| {
         probe
             .into_par_iter()
             .zip(offsets)
@@ -286,8 +341,11 @@ where
                 // assume the result tuples equal lenght of the no. of hashes processed by this thread.
                 let mut results = Vec::with_capacity(probe.len());
 
-                probe.iter().enumerate().for_each(|(idx_a, k)| {
-                    let idx_a = (idx_a + offset) as u32;
+                for (idx_a, k) in probe.iter().enumerate() {
+                    if token.is_cancelled_at(idx_a) {
+                        break;
+                    }
+                    let idx_a = (idx_a + offset) as IdxSize;
                     // probe table that contains the hashed value
                     let current_probe_table = unsafe {
                         get_hash_tbl_threaded_join_partitioned(k.as_u64(), hash_tbls, n_tables)
@@ -304,19 +362,19 @@ where
                         // only left values, right = null
                         None => results.push((idx_a, None)),
                     }
-                });
+                }
                 results
             })
             .flatten()
             .collect()
-    })
+    }))
 }
 
 /// Probe the build table and add tuples to the results (inner join)
 fn probe_outer<T, F, G, H>(
     probe_hashes: &[Vec<(u64, T)>],
-    hash_tbls: &mut [PlHashMap<T, (bool, Vec<u32>)>],
-    results: &mut Vec<(Option<u32>, Option<u32>)>,
+    hash_tbls: &mut [PlHashMap<T, (bool, Vec<IdxSize>)>],
+    results: &mut Vec<(Option<IdxSize>, Option<IdxSize>)>,
     n_tables: u64,
     // Function that get index_a, index_b when there is a match and pushes to result
     swap_fn_match: F,
@@ -327,17 +385,21 @@ fn probe_outer<T, F, G, H>(
 ) where
     T: Send + Hash + Eq + Sync + Copy,
     // idx_a, idx_b -> ...
-    F: Fn(u32, u32) -> (Option<u32>, Option<u32>),
+    F: Fn(IdxSize, IdxSize) -> (Option<IdxSize>, Option<IdxSize>),
     // idx_a -> ...
-    G: Fn(u32) -> (Option<u32>, Option<u32>),
+    G: Fn(IdxSize) -> (Option<IdxSize>, Option<IdxSize>),
     // idx_b -> ...
-    H: Fn(u32) -> (Option<u32>, Option<u32>),
+    H: Fn(IdxSize) -> (Option<IdxSize>, Option<IdxSize>),
 {
     // needed for the partition shift instead of modulo to make sense
     assert!(n_tables.is_power_of_two());
+    let token = CancellationToken::current();
     let mut idx_a = 0;
     for probe_hashes in probe_hashes {
         for (h, key) in probe_hashes {
+            if token.is_cancelled_at(idx_a as usize) {
+                return;
+            }
             let h = *h;
             // probe table that contains the hashed value
             let current_probe_table =
@@ -376,7 +438,8 @@ fn hash_join_tuples_outer<T, I, J>(
     a: Vec<I>,
     b: Vec<J>,
     swap: bool,
-) -> Vec<(Option<u32>, Option<u32>)>
+    validate: JoinValidation,
+) -> Result<Vec<(Option<IdxSize>, Option<IdxSize>)>>
 where
     I: Iterator<Item = T> + Send + TrustedLen,
     J: Iterator<Item = T> + Send + TrustedLen,
@@ -395,11 +458,18 @@ where
     let mut results = Vec::with_capacity(size);
 
     // prepare hash table
-    let mut hash_tbls = prepare_hashed_relation_threaded(b);
+    let mut hash_tbls = validate.check_build(
+        prepare_hashed_relation_threaded(b, validate.needs_unique(swap)),
+        swap,
+    )?;
     let random_state = hash_tbls[0].hasher().clone();
 
     // we pre hash the probing values
     let (probe_hashes, _) = create_hash_and_keys_threaded_vectorized(a, Some(random_state));
+    validate.check_probe(swap, || {
+        let mut seen = PlHashSet::with_capacity(probe_hashes.iter().map(|h| h.len()).sum());
+        probe_hashes.iter().flatten().all(|(_, k)| seen.insert(*k))
+    })?;
 
     let n_tables = hash_tbls.len() as u64;
 
@@ -429,70 +499,98 @@ where
             |idx_b| (None, Some(idx_b)),
         )
     }
-    results
+    Ok(results)
 }
 
 pub(crate) trait HashJoin<T> {
-    fn hash_join_inner(&self, _other: &ChunkedArray<T>) -> Vec<(u32, u32)> {
+    fn hash_join_inner(
+        &self,
+        _other: &ChunkedArray<T>,
+        _validate: JoinValidation,
+    ) -> Result<Vec<(IdxSize, IdxSize)>> {
         unimplemented!()
     }
-    fn hash_join_left(&self, _other: &ChunkedArray<T>) -> Vec<(u32, Option<u32>)> {
+    fn hash_join_left(
+        &self,
+        _other: &ChunkedArray<T>,
+        _validate: JoinValidation,
+    ) -> Result<Vec<(IdxSize, Option<IdxSize>)>> {
         unimplemented!()
     }
-    fn hash_join_outer(&self, _other: &ChunkedArray<T>) -> Vec<(Option<u32>, Option<u32>)> {
+    fn hash_join_outer(
+        &self,
+        _other: &ChunkedArray<T>,
+        _validate: JoinValidation,
+    ) -> Result<Vec<(Option<IdxSize>, Option<IdxSize>)>> {
         unimplemented!()
     }
 }
 
 impl HashJoin<Float32Type> for Float32Chunked {
-    fn hash_join_inner(&self, other: &Float32Chunked) -> Vec<(u32, u32)> {
+    fn hash_join_inner(&self, other: &Float32Chunked) -> Vec<(IdxSize, IdxSize)> {
         let ca = self.bit_repr_small();
         let other = other.bit_repr_small();
-        ca.hash_join_inner(&other)
+        ca.hash_join_inner(&other, validate)
     }
-    fn hash_join_left(&self, other: &Float32Chunked) -> Vec<(u32, Option<u32>)> {
+    fn hash_join_left(&self, other: &Float32Chunked) -> Vec<(IdxSize, Option<IdxSize>)> {
         let ca = self.bit_repr_small();
         let other = other.bit_repr_small();
-        ca.hash_join_left(&other)
+        ca.hash_join_left(&other, validate)
     }
-    fn hash_join_outer(&self, other: &Float32Chunked) -> Vec<(Option<u32>, Option<u32>)> {
+    fn hash_join_outer(&self, other: &Float32Chunked) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
         let ca = self.bit_repr_small();
         let other = other.bit_repr_small();
-        ca.hash_join_outer(&other)
+        ca.hash_join_outer(&other, validate)
     }
 }
 
 impl HashJoin<Float64Type> for Float64Chunked {
-    fn hash_join_inner(&self, other: &Float64Chunked) -> Vec<(u32, u32)> {
+    fn hash_join_inner(&self, other: &Float64Chunked) -> Vec<(IdxSize, IdxSize)> {
         let ca = self.bit_repr_large();
         let other = other.bit_repr_large();
-        ca.hash_join_inner(&other)
+        ca.hash_join_inner(&other, validate)
     }
-    fn hash_join_left(&self, other: &Float64Chunked) -> Vec<(u32, Option<u32>)> {
+    fn hash_join_left(&self, other: &Float64Chunked) -> Vec<(IdxSize, Option<IdxSize>)> {
         let ca = self.bit_repr_large();
         let other = other.bit_repr_large();
-        ca.hash_join_left(&other)
+        ca.hash_join_left(&other, validate)
     }
-    fn hash_join_outer(&self, other: &Float64Chunked) -> Vec<(Option<u32>, Option<u32>)> {
+    fn hash_join_outer(&self, other: &Float64Chunked) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
         let ca = self.bit_repr_large();
         let other = other.bit_repr_large();
-        ca.hash_join_outer(&other)
+        ca.hash_join_outer(&other, validate)
     }
 }
 
 impl HashJoin<CategoricalType> for CategoricalChunked {
-    fn hash_join_inner(&self, other: &CategoricalChunked) -> Vec<(u32, u32)> {
-        self.deref().hash_join_inner(other.deref())
+    fn hash_join_inner(
+        &self,
+        other: &CategoricalChunked,
+        validate: JoinValidation,
+    ) -> Result<Vec<(IdxSize, IdxSize)>> {
+        self.deref().hash_join_inner(other.deref(), validate)
     }
-    fn hash_join_left(&self, other: &CategoricalChunked) -> Vec<(u32, Option<u32>)> {
-        self.deref().hash_join_left(other.deref())
+    fn hash_join_left(
+        &self,
+        other: &CategoricalChunked,
+        validate: JoinValidation,
+    ) -> Result<Vec<(IdxSize, Option<IdxSize>)>> {
+        self.deref().hash_join_left(other.deref(), validate)
     }
-    fn hash_join_outer(&self, other: &CategoricalChunked) -> Vec<(Option<u32>, Option<u32>)> {
-        self.deref().hash_join_outer(other.deref())
+    fn hash_join_outer(
+        &self,
+        other: &CategoricalChunked,
+        validate: JoinValidation,
+    ) -> Result<Vec<(Option<IdxSize>, Option<IdxSize>)>> {
+        self.deref().hash_join_outer(other.deref(), validate)
     }
 }
 
-fn num_group_join_inner<T>(left: &ChunkedArray<T>, right: &ChunkedArray<T>) -> Vec<(u32, u32)>
+fn num_group_join_inner<T>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    validate: JoinValidation,
+) -> Result<Vec<(IdxSize, IdxSize)>>
 where
     T: PolarsIntegerType,
     T::Native: Hash + Eq + Send + AsU64 + Copy,
@@ -517,7 +615,7 @@ where
                 .iter()
                 .map(|ca| ca.cont_slice().unwrap())
                 .collect::<Vec<_>>();
-            hash_join_tuples_inner(keys_a, keys_b, swap)
+            hash_join_tuples_inner(keys_a, keys_b, swap, validate)
         }
         (true, true, _, _) => {
             let keys_a = splitted_a
@@ -528,7 +626,7 @@ where
                 .iter()
                 .map(|ca| ca.into_no_null_iter().collect::<Vec<_>>())
                 .collect::<Vec<_>>();
-            hash_join_tuples_inner(keys_a, keys_b, swap)
+            hash_join_tuples_inner(keys_a, keys_b, swap, validate)
         }
         (_, _, 1, 1) => {
             let keys_a = splitted_a
@@ -548,7 +646,7 @@ where
                         .collect::<Vec<_>>()
                 })
                 .collect::<Vec<_>>();
-            hash_join_tuples_inner(keys_a, keys_b, swap)
+            hash_join_tuples_inner(keys_a, keys_b, swap, validate)
         }
         _ => {
             let keys_a = splitted_a
@@ -559,7 +657,7 @@ where
                 .iter()
                 .map(|ca| ca.into_iter().map(|v| v.as_u64()).collect::<Vec<_>>())
                 .collect::<Vec<_>>();
-            hash_join_tuples_inner(keys_a, keys_b, swap)
+            hash_join_tuples_inner(keys_a, keys_b, swap, validate)
         }
     }
 }
@@ -567,7 +665,8 @@ where
 fn num_group_join_left<T>(
     left: &ChunkedArray<T>,
     right: &ChunkedArray<T>,
-) -> Vec<(u32, Option<u32>)>
+    validate: JoinValidation,
+) -> Result<Vec<(IdxSize, Option<IdxSize>)>>
 where
     T: PolarsIntegerType,
     T::Native: Hash + Eq + Send + AsU64,
@@ -591,7 +690,7 @@ where
                 .iter()
                 .map(|ca| ca.cont_slice().unwrap())
                 .collect::<Vec<_>>();
-            hash_join_tuples_left(keys_a, keys_b)
+            hash_join_tuples_left(keys_a, keys_b, validate)
         }
         (0, 0, _, _) => {
             let keys_a = splitted_a
@@ -602,7 +701,7 @@ where
                 .iter()
                 .map(|ca| ca.into_no_null_iter().collect_trusted::<Vec<_>>())
                 .collect::<Vec<_>>();
-            hash_join_tuples_left(keys_a, keys_b)
+            hash_join_tuples_left(keys_a, keys_b, validate)
         }
         (_, _, 1, 1) => {
             let keys_a = splitted_a
@@ -630,7 +729,7 @@ where
                     }
                 })
                 .collect::<Vec<_>>();
-            hash_join_tuples_left(keys_a, keys_b)
+            hash_join_tuples_left(keys_a, keys_b, validate)
         }
         _ => {
             let keys_a = splitted_a
@@ -649,7 +748,7 @@ where
                         .collect_trusted::<Vec<_>>()
                 })
                 .collect::<Vec<_>>();
-            hash_join_tuples_left(keys_a, keys_b)
+            hash_join_tuples_left(keys_a, keys_b, validate)
         }
     }
 }
@@ -659,7 +758,11 @@ where
     T: PolarsIntegerType + Sync,
     T::Native: Eq + Hash + num::NumCast,
 {
-    fn hash_join_inner(&self, other: &ChunkedArray<T>) -> Vec<(u32, u32)> {
+    fn hash_join_inner(
+        &self,
+        other: &ChunkedArray<T>,
+        validate: JoinValidation,
+    ) -> Result<Vec<(IdxSize, IdxSize)>> {
         match self.dtype() {
             DataType::UInt64 => {
                 // convince the compiler that we are this type.
@@ -669,7 +772,7 @@ where
                 let other: &UInt64Chunked = unsafe {
                     &*(other as *const ChunkedArray<T> as *const ChunkedArray<UInt64Type>)
                 };
-                num_group_join_inner(ca, other)
+                num_group_join_inner(ca, other, validate)
             }
             DataType::UInt32 => {
                 // convince the compiler that we are this type.
@@ -679,29 +782,33 @@ where
                 let other: &UInt32Chunked = unsafe {
                     &*(other as *const ChunkedArray<T> as *const ChunkedArray<UInt32Type>)
                 };
-                num_group_join_inner(ca, other)
+                num_group_join_inner(ca, other, validate)
             }
             DataType::Int64 | DataType::Float64 => {
                 let ca = self.bit_repr_large();
                 let other = other.bit_repr_large();
-                num_group_join_inner(&ca, &other)
+                num_group_join_inner(&ca, &other, validate)
             }
             DataType::Int32 | DataType::Float32 => {
                 let ca = self.bit_repr_small();
                 let other = other.bit_repr_small();
-                num_group_join_inner(&ca, &other)
+                num_group_join_inner(&ca, &other, validate)
             }
             _ => {
                 let ca = self.cast(&DataType::UInt32).unwrap();
                 let ca = ca.u32().unwrap();
                 let other = other.cast(&DataType::UInt32).unwrap();
                 let other = other.u32().unwrap();
-                num_group_join_inner(ca, other)
+                num_group_join_inner(ca, other, validate)
             }
         }
     }
 
-    fn hash_join_left(&self, other: &ChunkedArray<T>) -> Vec<(u32, Option<u32>)> {
+    fn hash_join_left(
+        &self,
+        other: &ChunkedArray<T>,
+        validate: JoinValidation,
+    ) -> Result<Vec<(IdxSize, Option<IdxSize>)>> {
         match self.dtype() {
             DataType::UInt64 => {
                 // convince the compiler that we are this type.
@@ -711,7 +818,7 @@ where
                 let other: &UInt64Chunked = unsafe {
                     &*(other as *const ChunkedArray<T> as *const ChunkedArray<UInt64Type>)
                 };
-                num_group_join_left(ca, other)
+                num_group_join_left(ca, other, validate)
             }
             DataType::UInt32 => {
                 // convince the compiler that we are this type.
@@ -721,29 +828,33 @@ where
                 let other: &UInt32Chunked = unsafe {
                     &*(other as *const ChunkedArray<T> as *const ChunkedArray<UInt32Type>)
                 };
-                num_group_join_left(ca, other)
+                num_group_join_left(ca, other, validate)
             }
             DataType::Int64 | DataType::Float64 => {
                 let ca = self.bit_repr_large();
                 let other = other.bit_repr_large();
-                num_group_join_left(&ca, &other)
+                num_group_join_left(&ca, &other, validate)
             }
             DataType::Int32 | DataType::Float32 => {
                 let ca = self.bit_repr_small();
                 let other = other.bit_repr_small();
-                num_group_join_left(&ca, &other)
+                num_group_join_left(&ca, &other, validate)
             }
             _ => {
                 let ca = self.cast(&DataType::UInt32).unwrap();
                 let ca = ca.u32().unwrap();
                 let other = other.cast(&DataType::UInt32).unwrap();
                 let other = other.u32().unwrap();
-                num_group_join_left(ca, other)
+                num_group_join_left(ca, other, validate)
             }
         }
     }
 
-    fn hash_join_outer(&self, other: &ChunkedArray<T>) -> Vec<(Option<u32>, Option<u32>)> {
+    fn hash_join_outer(
+        &self,
+        other: &ChunkedArray<T>,
+        validate: JoinValidation,
+    ) -> Result<Vec<(Option<IdxSize>, Option<IdxSize>)>> {
         let (a, b, swap) = det_hash_prone_order!(self, other);
 
         let n_partitions = set_partition_size();
@@ -760,7 +871,7 @@ where
                     .iter()
                     .map(|ca| ca.into_no_null_iter())
                     .collect::<Vec<_>>();
-                hash_join_tuples_outer(iters_a, iters_b, swap)
+                hash_join_tuples_outer(iters_a, iters_b, swap, validate)
             }
             _ => {
                 let iters_a = splitted_a
@@ -771,30 +882,42 @@ where
                     .iter()
                     .map(|ca| ca.into_iter())
                     .collect::<Vec<_>>();
-                hash_join_tuples_outer(iters_a, iters_b, swap)
+                hash_join_tuples_outer(iters_a, iters_b, swap, validate)
             }
         }
     }
 }
 
 impl HashJoin<BooleanType> for BooleanChunked {
-    fn hash_join_inner(&self, other: &BooleanChunked) -> Vec<(u32, u32)> {
+    fn hash_join_inner(
+        &self,
+        other: &BooleanChunked,
+        validate: JoinValidation,
+    ) -> Result<Vec<(IdxSize, IdxSize)>> {
         let ca = self.cast(&DataType::UInt32).unwrap();
         let ca = ca.u32().unwrap();
         let other = other.cast(&DataType::UInt32).unwrap();
         let other = other.u32().unwrap();
-        ca.hash_join_inner(other)
+        ca.hash_join_inner(other, validate)
     }
 
-    fn hash_join_left(&self, other: &BooleanChunked) -> Vec<(u32, Option<u32>)> {
+    fn hash_join_left(
+        &self,
+        other: &BooleanChunked,
+        validate: JoinValidation,
+    ) -> Result<Vec<(IdxSize, Option<IdxSize>)>> {
         let ca = self.cast(&DataType::UInt32).unwrap();
         let ca = ca.u32().unwrap();
         let other = other.cast(&DataType::UInt32).unwrap();
         let other = other.u32().unwrap();
-        ca.hash_join_left(other)
+        ca.hash_join_left(other, validate)
     }
 
-    fn hash_join_outer(&self, other: &BooleanChunked) -> Vec<(Option<u32>, Option<u32>)> {
+    fn hash_join_outer(
+        &self,
+        other: &BooleanChunked,
+        validate: JoinValidation,
+    ) -> Result<Vec<(Option<IdxSize>, Option<IdxSize>)>> {
         let (a, b, swap) = det_hash_prone_order!(self, other);
 
         let n_partitions = set_partition_size();
@@ -811,7 +934,7 @@ impl HashJoin<BooleanType> for BooleanChunked {
                     .iter()
                     .map(|ca| ca.into_no_null_iter())
                     .collect::<Vec<_>>();
-                hash_join_tuples_outer(iters_a, iters_b, swap)
+                hash_join_tuples_outer(iters_a, iters_b, swap, validate)
             }
             _ => {
                 let iters_a = splitted_a
@@ -822,7 +945,7 @@ impl HashJoin<BooleanType> for BooleanChunked {
                     .iter()
                     .map(|ca| ca.into_iter())
                     .collect::<Vec<_>>();
-                hash_join_tuples_outer(iters_a, iters_b, swap)
+                hash_join_tuples_outer(iters_a, iters_b, swap, validate)
             }
         }
     }
@@ -847,7 +970,7 @@ fn prepare_strs<'a>(been_split: &'a [Utf8Chunked], hb: &RandomState) -> Vec<Vec<
 }
 
 impl HashJoin<Utf8Type> for Utf8Chunked {
-    fn hash_join_inner(&self, other: &Utf8Chunked) -> Vec<(u32, u32)> {
+    fn hash_join_inner(&self, other: &Utf8Chunked) -> Vec<(IdxSize, IdxSize)> {
         let n_threads = POOL.current_num_threads();
 
         let (a, b, swap) = det_hash_prone_order!(self, other);
@@ -858,10 +981,10 @@ impl HashJoin<Utf8Type> for Utf8Chunked {
 
         let str_hashes_a = prepare_strs(&splitted_a, &hb);
         let str_hashes_b = prepare_strs(&splitted_b, &hb);
-        hash_join_tuples_inner(str_hashes_a, str_hashes_b, swap)
+        hash_join_tuples_inner(str_hashes_a, str_hashes_b, swap, validate)
     }
 
-    fn hash_join_left(&self, other: &Utf8Chunked) -> Vec<(u32, Option<u32>)> {
+    fn hash_join_left(&self, other: &Utf8Chunked) -> Vec<(IdxSize, Option<IdxSize>)> {
         let n_threads = POOL.current_num_threads();
 
         let hb = RandomState::default();
@@ -870,10 +993,10 @@ impl HashJoin<Utf8Type> for Utf8Chunked {
 
         let str_hashes_a = prepare_strs(&splitted_a, &hb);
         let str_hashes_b = prepare_strs(&splitted_b, &hb);
-        hash_join_tuples_left(str_hashes_a, str_hashes_b)
+        hash_join_tuples_left(str_hashes_a, str_hashes_b, validate)
     }
 
-    fn hash_join_outer(&self, other: &Utf8Chunked) -> Vec<(Option<u32>, Option<u32>)> {
+    fn hash_join_outer(&self, other: &Utf8Chunked) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
         let (a, b, swap) = det_hash_prone_order!(self, other);
 
         let n_partitions = set_partition_size();
@@ -890,7 +1013,7 @@ impl HashJoin<Utf8Type> for Utf8Chunked {
                     .iter()
                     .map(|ca| ca.into_no_null_iter())
                     .collect::<Vec<_>>();
-                hash_join_tuples_outer(iters_a, iters_b, swap)
+                hash_join_tuples_outer(iters_a, iters_b, swap, validate)
             }
             _ => {
                 let iters_a = splitted_a
@@ -901,17 +1024,59 @@ impl HashJoin<Utf8Type> for Utf8Chunked {
                     .iter()
                     .map(|ca| ca.into_iter())
                     .collect::<Vec<_>>();
-                hash_join_tuples_outer(iters_a, iters_b, swap)
+                hash_join_tuples_outer(iters_a, iters_b, swap, validate)
             }
         }
     }
 }
 
+/// Join a single key with `join`.
+///
+/// If nulls join, `filter` removes the matches between a null and a valid key. Otherwise the
+/// rows with a null key are left out of the hash tables and `finish` adds them to the tuples,
+/// so they don't count as duplicates for the join validation.
+/// The tuples are incomplete if the current [`CancellationToken`] is cancelled, which returns
+/// an error.
+fn single_key_join_tuples<T, J, F, G>(
+    s_left: &Series,
+    s_right: &Series,
+    join_nulls: bool,
+    join: J,
+    filter: F,
+    finish: G,
+) -> Result<T>
+where
+    J: Fn(&Series, &Series) -> Result<T>,
+    F: FnOnce(&NullKeyFilter, T) -> T,
+    G: FnOnce(&NullKeyRows, T) -> T,
+{
+    let left = std::slice::from_ref(s_left);
+    let right = std::slice::from_ref(s_right);
+    let join_tuples = if join_nulls {
+        let join_tuples = join(s_left, s_right)?;
+        match NullKeyFilter::new(left, right) {
+            Some(null_filter) => filter(&null_filter, join_tuples),
+            None => join_tuples,
+        }
+    } else {
+        match NullKeyRows::new(left, right) {
+            Some(null_rows) => {
+                let s_left = null_rows.take_left(left).pop().unwrap();
+                let s_right = null_rows.take_right(right).pop().unwrap();
+                finish(&null_rows, join(&s_left, &s_right)?)
+            }
+            None => join(s_left, s_right)?,
+        }
+    };
+    CancellationToken::current().check()?;
+    Ok(join_tuples)
+}
+
 pub trait ZipOuterJoinColumn {
     fn zip_outer_join_column(
         &self,
         _right_column: &Series,
-        _opt_join_tuples: &[(Option<u32>, Option<u32>)],
+        _opt_join_tuples: &[(Option<IdxSize>, Option<IdxSize>)],
     ) -> Series {
         unimplemented!()
     }
@@ -925,7 +1090,7 @@ where
     fn zip_outer_join_column(
         &self,
         right_column: &Series,
-        opt_join_tuples: &[(Option<u32>, Option<u32>)],
+        opt_join_tuples: &[(Option<IdxSize>, Option<IdxSize>)],
     ) -> Series {
         let right_ca = self.unpack_series_matching_type(right_column).unwrap();
 
@@ -955,7 +1120,7 @@ macro_rules! impl_zip_outer_join {
             fn zip_outer_join_column(
                 &self,
                 right_column: &Series,
-                opt_join_tuples: &[(Option<u32>, Option<u32>)],
+                opt_join_tuples: &[(Option<IdxSize>, Option<IdxSize>)],
             ) -> Series {
                 let right_ca = self.unpack_series_matching_type(right_column).unwrap();
 
@@ -987,7 +1152,7 @@ impl ZipOuterJoinColumn for Float32Chunked {
     fn zip_outer_join_column(
         &self,
         right_column: &Series,
-        opt_join_tuples: &[(Option<u32>, Option<u32>)],
+        opt_join_tuples: &[(Option<IdxSize>, Option<IdxSize>)],
     ) -> Series {
         self.apply_as_ints(|s| {
             s.zip_outer_join_column(
@@ -1002,7 +1167,7 @@ impl ZipOuterJoinColumn for Float64Chunked {
     fn zip_outer_join_column(
         &self,
         right_column: &Series,
-        opt_join_tuples: &[(Option<u32>, Option<u32>)],
+        opt_join_tuples: &[(Option<IdxSize>, Option<IdxSize>)],
     ) -> Series {
         self.apply_as_ints(|s| {
             s.zip_outer_join_column(
@@ -1044,7 +1209,7 @@ impl DataFrame {
         Ok(df_left)
     }
 
-    fn create_left_df<B: Sync>(&self, join_tuples: &[(u32, B)], left_join: bool) -> DataFrame {
+    fn create_left_df<B: Sync>(&self, join_tuples: &[(IdxSize, B)], left_join: bool) -> DataFrame {
         if left_join && join_tuples.len() == self.height() {
             self.clone()
         } else {
@@ -1061,6 +1226,8 @@ impl DataFrame {
         selected_right: Vec<Series>,
         how: JoinType,
         suffix: Option<String>,
+        validate: JoinValidation,
+        join_nulls: bool,
     ) -> Result<DataFrame> {
         if selected_right.len() != selected_left.len() {
             return Err(PolarsError::ValueError(
@@ -1079,15 +1246,18 @@ impl DataFrame {
         for (l, r) in selected_left.iter().zip(&selected_right) {
             check_categorical_src(l, r)?
         }
-
         // Single keys
         if selected_left.len() == 1 {
             let s_left = self.column(selected_left[0].name())?;
             let s_right = other.column(selected_right[0].name())?;
             return match how {
-                JoinType::Inner => self.inner_join_from_series(other, s_left, s_right, suffix),
-                JoinType::Left => self.left_join_from_series(other, s_left, s_right, suffix),
-                JoinType::Outer => self.outer_join_from_series(other, s_left, s_right, suffix),
+                JoinType::Inner => self
+                    .inner_join_from_series(other, s_left, s_right, suffix, validate, join_nulls),
+                JoinType::Left => {
+                    self.left_join_from_series(other, s_left, s_right, suffix, validate, join_nulls)
+                }
+                JoinType::Outer => self
+                    .outer_join_from_series(other, s_left, s_right, suffix, validate, join_nulls),
                 #[cfg(feature = "asof_join")]
                 JoinType::AsOf => {
                     self.join_asof(other, selected_left[0].name(), selected_right[0].name())
@@ -1117,8 +1287,18 @@ impl DataFrame {
         }
         // make sure that we don't have logical types.
         // we don't overwrite the original selected as that might be used to create a column in the new df
-        let selected_left_physical = to_physical_and_bit_repr(&selected_left);
-        let selected_right_physical = to_physical_and_bit_repr(&selected_right);
+        let mut selected_left_physical = to_physical_and_bit_repr(&selected_left);
+        let mut selected_right_physical = to_physical_and_bit_repr(&selected_right);
+        // the rows compare equal on null keys, so nulls only need to be left out if they don't join
+        let null_rows = if join_nulls {
+            None
+        } else {
+            NullKeyRows::new(&selected_left, &selected_right)
+        };
+        if let Some(null_rows) = &null_rows {
+            selected_left_physical = null_rows.take_left(&selected_left_physical);
+            selected_right_physical = null_rows.take_right(&selected_right_physical);
+        }
 
         // multiple keys
         match how {
@@ -1126,7 +1306,11 @@ impl DataFrame {
                 let left = DataFrame::new_no_checks(selected_left_physical);
                 let right = DataFrame::new_no_checks(selected_right_physical);
                 let (left, right, swap) = det_hash_prone_order!(left, right);
-                let join_tuples = inner_join_multiple_keys(&left, &right, swap);
+                let mut join_tuples = inner_join_multiple_keys(&left, &right, swap, validate)?;
+                CancellationToken::current().check()?;
+                if let Some(null_rows) = &null_rows {
+                    join_tuples = null_rows.finish_inner(join_tuples)
+                }
 
                 let (df_left, df_right) = POOL.join(
                     || self.create_left_df(&join_tuples, false),
@@ -1142,7 +1326,11 @@ impl DataFrame {
             JoinType::Left => {
                 let left = DataFrame::new_no_checks(selected_left_physical);
                 let right = DataFrame::new_no_checks(selected_right_physical);
-                let join_tuples = left_join_multiple_keys(&left, &right);
+                let mut join_tuples = left_join_multiple_keys(&left, &right, validate)?;
+                CancellationToken::current().check()?;
+                if let Some(null_rows) = &null_rows {
+                    join_tuples = null_rows.finish_left(join_tuples)
+                }
 
                 let (df_left, df_right) = POOL.join(
                     || self.create_left_df(&join_tuples, true),
@@ -1162,7 +1350,11 @@ impl DataFrame {
                 let right = DataFrame::new_no_checks(selected_right_physical);
 
                 let (left, right, swap) = det_hash_prone_order!(left, right);
-                let opt_join_tuples = outer_join_multiple_keys(&left, &right, swap);
+                let mut opt_join_tuples = outer_join_multiple_keys(&left, &right, swap, validate)?;
+                CancellationToken::current().check()?;
+                if let Some(null_rows) = &null_rows {
+                    opt_join_tuples = null_rows.finish_outer(opt_join_tuples)
+                }
 
                 // Take the left and right dataframes by join tuples
                 let (mut df_left, df_right) = POOL.join(
@@ -1200,6 +1392,10 @@ impl DataFrame {
 
     /// Generic join method. Can be used to join on multiple columns.
     ///
+    /// A single integer or temporal key that is sorted on both sides is merge joined instead
+    /// of hashed. Float keys are always hashed, so they match on their bits: `-0.0` doesn't
+    /// match `0.0` and a `NaN` matches a `NaN` with the same bits.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -1252,7 +1448,69 @@ impl DataFrame {
         let mut selected_left = self.select_series(left_on)?;
         #[allow(unused_mut)]
         let mut selected_right = other.select_series(right_on)?;
-        self.join_impl(other, selected_left, selected_right, how, suffix)
+        self.join_impl(
+            other,
+            selected_left,
+            selected_right,
+            how,
+            suffix,
+            JoinValidation::default(),
+            true,
+        )
+    }
+
+    /// Generic join method that also checks the relation between the join keys.
+    ///
+    /// * `validate` - the expected uniqueness of the join keys. A violation returns an error.
+    /// * `join_nulls` - if null keys match each other. [`DataFrame::join`] joins nulls.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let df1: DataFrame = df!("a" => &[Some(1), Some(2), None])?;
+    /// let df2: DataFrame = df!("a" => &[Some(1), Some(1), None], "b" => &[1, 2, 3])?;
+    ///
+    /// let join = |validate| {
+    ///     df1.join_with_options(&df2, ["a"], ["a"], JoinType::Inner, None, validate, false)
+    /// };
+    /// // the null keys don't match
+    /// assert_eq!(join(JoinValidation::ManyToMany)?.shape(), (2, 2));
+    /// // the right keys are not unique
+    /// assert!(join(JoinValidation::OneToOne).is_err());
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn join_with_options<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        right_on: I,
+        how: JoinType,
+        suffix: Option<String>,
+        validate: JoinValidation,
+        join_nulls: bool,
+    ) -> Result<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        #[cfg(feature = "cross_join")]
+        if let JoinType::Cross = how {
+            return self.cross_join(other);
+        }
+
+        let selected_left = self.select_series(left_on)?;
+        let selected_right = other.select_series(right_on)?;
+        self.join_impl(
+            other,
+            selected_left,
+            selected_right,
+            how,
+            suffix,
+            validate,
+            join_nulls,
+        )
     }
 
     /// Perform an inner join on two DataFrames.
@@ -1279,10 +1537,26 @@ impl DataFrame {
         s_left: &Series,
         s_right: &Series,
         suffix: Option<String>,
+        validate: JoinValidation,
+        join_nulls: bool,
     ) -> Result<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
         check_categorical_src(s_left, s_right)?;
-        let join_tuples = s_left.hash_join_inner(s_right);
+        // sorted keys don't need a hash table
+        let join_tuples = single_key_join_tuples(
+            s_left,
+            s_right,
+            join_nulls,
+            |l, r| match merge_join_inner(l, r, validate)? {
+                Some(join_tuples) => Ok(join_tuples),
+                None => l.hash_join_inner(r, validate),
+            },
+            |null_filter, mut join_tuples| {
+                null_filter.filter_inner(&mut join_tuples);
+                join_tuples
+            },
+            |null_rows, join_tuples| null_rows.finish_inner(join_tuples),
+        )?;
 
         let (df_left, df_right) = POOL.join(
             || self.create_left_df(&join_tuples, false),
@@ -1344,10 +1618,23 @@ impl DataFrame {
         s_left: &Series,
         s_right: &Series,
         suffix: Option<String>,
+        validate: JoinValidation,
+        join_nulls: bool,
     ) -> Result<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
         check_categorical_src(s_left, s_right)?;
-        let opt_join_tuples = s_left.hash_join_left(s_right);
+        // sorted keys don't need a hash table
+        let opt_join_tuples = single_key_join_tuples(
+            s_left,
+            s_right,
+            join_nulls,
+            |l, r| match merge_join_left(l, r, validate)? {
+                Some(join_tuples) => Ok(join_tuples),
+                None => l.hash_join_left(r, validate),
+            },
+            |null_filter, join_tuples| null_filter.filter_left(join_tuples),
+            |null_rows, join_tuples| null_rows.finish_left(join_tuples),
+        )?;
 
         let (df_left, df_right) = POOL.join(
             || self.create_left_df(&opt_join_tuples, true),
@@ -1384,6 +1671,8 @@ impl DataFrame {
         s_left: &Series,
         s_right: &Series,
         suffix: Option<String>,
+        validate: JoinValidation,
+        join_nulls: bool,
     ) -> Result<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
         check_categorical_src(s_left, s_right)?;
@@ -1392,7 +1681,14 @@ impl DataFrame {
         let join_column_index = self.iter().position(|s| s.name() == s_left.name()).unwrap();
 
         // Get the indexes of the joined relations
-        let opt_join_tuples = s_left.hash_join_outer(s_right);
+        let opt_join_tuples = single_key_join_tuples(
+            s_left,
+            s_right,
+            join_nulls,
+            |l, r| l.hash_join_outer(r, validate),
+            |null_filter, join_tuples| null_filter.filter_outer(join_tuples),
+            |null_rows, join_tuples| null_rows.finish_outer(join_tuples),
+        )?;
 
         // Take the left and right dataframes by join tuples
         let (mut df_left, df_right) = POOL.join(
@@ -1417,11 +1713,11 @@ impl DataFrame {
         s.rename(s_left.name());
         let s = match s_left.dtype() {
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical => {
+            DataType::Categorical | DataType::Enum(_) => {
                 let ca_or = s_left.categorical().unwrap();
                 let ca_new = s.cast(&DataType::Categorical).unwrap();
                 let mut ca_new = ca_new.categorical().unwrap().clone();
-                ca_new.categorical_map = ca_or.categorical_map.clone();
+                ca_new.copy_cat_state(ca_or);
                 ca_new.into_series()
             }
             dt @ DataType::Datetime(_, _)
@@ -1453,6 +1749,19 @@ mod test {
         (temp, rain)
     }
 
+    #[test]
+    fn test_join_cancelled() {
+        let (temp, rain) = create_frames();
+        let token = CancellationToken::new();
+        token.cancel();
+        for on in [&["days"][..], &["days", "rain"][..]] {
+            for how in [JoinType::Inner, JoinType::Left, JoinType::Outer] {
+                let out = token.scoped(|| temp.join(&rain, on, on, how, None));
+                assert!(matches!(out, Err(PolarsError::Cancelled)));
+            }
+        }
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_inner_join() {
@@ -2024,4 +2333,135 @@ mod test {
         assert_eq!(out.shape(), (1, 2));
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_join_sorted_keys() -> Result<()> {
+        for reverse in [false, true] {
+            let mut a = vec![1i64, 2, 2, 4, 5, 7];
+            let mut b = vec![0i64, 2, 2, 3, 5, 7, 8];
+            if reverse {
+                a.reverse();
+                b.reverse();
+            }
+            // duplicate keys have equal rows, so sorting doesn't change the data
+            let x = a.iter().map(|v| v * 10).collect::<Vec<_>>();
+            let y = b.iter().map(|v| v * 100).collect::<Vec<_>>();
+            let left = df!["a" => &a, "x" => x]?;
+            let right = df!["a" => &b, "y" => y]?;
+            // the same data, but flagged sorted
+            let left_sorted = left.sort(["a"], reverse)?;
+            let right_sorted = right.sort(["a"], reverse)?;
+
+            let expected = left.left_join(&right, ["a"], ["a"])?;
+            let out = left_sorted.left_join(&right_sorted, ["a"], ["a"])?;
+            assert!(out.frame_equal(&expected));
+
+            let expected = left.inner_join(&right, ["a"], ["a"])?;
+            let out = left_sorted.inner_join(&right_sorted, ["a"], ["a"])?;
+            assert_eq!(out.shape(), expected.shape());
+            // the order of the left frame is preserved
+            let x = out.column("x")?;
+            assert!(x.series_equal(&x.sort(reverse)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_join_validation() -> Result<()> {
+        let left = df!["a" => [1, 2, 3], "b" => [1, 1, 2]]?;
+        let right = df!["a" => [1, 1, 2], "b" => [1, 1, 2]]?;
+        let join = |validate, on: &[&str]| {
+            left.join_with_options(&right, on, on, JoinType::Inner, None, validate, true)
+        };
+
+        assert!(join(JoinValidation::ManyToMany, &["a"]).is_ok());
+        assert!(join(JoinValidation::OneToMany, &["a"]).is_ok());
+        assert!(join(JoinValidation::ManyToOne, &["a"]).is_err());
+        assert!(join(JoinValidation::OneToOne, &["a"]).is_err());
+        // the multiple keys of the left table are unique
+        assert!(join(JoinValidation::OneToMany, &["a", "b"]).is_ok());
+        assert!(join(JoinValidation::OneToMany, &["b"]).is_err());
+
+        // the duplicates are found in the hash table and in the keys that probe it
+        for how in [JoinType::Left, JoinType::Outer] {
+            for on in [&["a"][..], &["a", "b"][..]] {
+                let join =
+                    |validate| left.join_with_options(&right, on, on, how, None, validate, true);
+                assert!(join(JoinValidation::OneToMany).is_ok());
+                assert!(join(JoinValidation::ManyToOne).is_err());
+                let join =
+                    |validate| right.join_with_options(&left, on, on, how, None, validate, true);
+                assert!(join(JoinValidation::ManyToOne).is_ok());
+                assert!(join(JoinValidation::OneToMany).is_err());
+            }
+        }
+
+        // sorted keys are merge joined
+        let left = left.sort(["a"], false)?;
+        let right = right.sort(["a"], false)?;
+        let join = |validate| {
+            left.join_with_options(&right, ["a"], ["a"], JoinType::Inner, None, validate, true)
+        };
+        assert!(join(JoinValidation::OneToMany).is_ok());
+        assert!(join(JoinValidation::OneToOne).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_join_nulls_validation() -> Result<()> {
+        let left = df![
+            "a" => [Some(1), None, Some(2)],
+            "b" => ["x", "x", "y"],
+            "c" => [1, 2, 3]
+        ]?;
+        let right = df![
+            "a" => [Some(1), None, None],
+            "b" => ["x", "x", "x"],
+            "d" => [1, 2, 3]
+        ]?;
+        for on in [&["a"][..], &["a", "b"][..]] {
+            let join = |how, join_nulls| {
+                left.join_with_options(
+                    &right,
+                    on,
+                    on,
+                    how,
+                    None,
+                    JoinValidation::ManyToMany,
+                    join_nulls,
+                )
+            };
+            assert_eq!(join(JoinType::Inner, true)?.height(), 3);
+            assert_eq!(join(JoinType::Inner, false)?.height(), 1);
+            assert_eq!(join(JoinType::Left, true)?.height(), 4);
+            let out = join(JoinType::Left, false)?;
+            assert_eq!(out.height(), 3);
+            assert_eq!(out.column("d")?.null_count(), 2);
+            // the left rows with a null key keep their position
+            assert_eq!(
+                Vec::from(out.column("c")?.i32()?),
+                &[Some(1), Some(2), Some(3)]
+            );
+            assert_eq!(join(JoinType::Outer, true)?.height(), 4);
+            assert_eq!(join(JoinType::Outer, false)?.height(), 5);
+        }
+        // a null key is not unique if nulls are joined
+        let join = |join_nulls| {
+            right.join_with_options(
+                &left,
+                ["a"],
+                ["a"],
+                JoinType::Left,
+                None,
+                JoinValidation::OneToMany,
+                join_nulls,
+            )
+        };
+        assert!(join(true).is_err());
+        assert!(join(false).is_ok());
+        Ok(())
+    }
 }
@@ -1,4 +1,6 @@
-use crate::frame::groupby::hashing::{populate_multiple_key_hashmap, HASHMAP_INIT_SIZE};
+use crate::frame::groupby::hashing::{
+    compare_df_rows, populate_multiple_key_hashmap, HASHMAP_INIT_SIZE,
+};
 use crate::frame::hash_join::{
     get_hash_tbl_threaded_join_mut_partitioned, get_hash_tbl_threaded_join_partitioned,
 };
@@ -27,11 +29,67 @@ pub(crate) unsafe fn compare_df_rows2(
     true
 }
 
+/// Insert row `idx` in the hash table of its partition. Returns `false` if the row must be
+/// `unique` but is already in the table.
+fn insert_row<V, G, F>(
+    hash_tbl: &mut HashMap<IdxHash, V, IdBuildHasher>,
+    idx: IdxSize,
+    h: u64,
+    keys: &DataFrame,
+    unique: bool,
+    vacant_fn: G,
+    occupied_fn: F,
+) -> bool
+where
+    G: Fn() -> V,
+    F: Fn(&mut V),
+{
+    if unique {
+        let entry = hash_tbl.raw_entry_mut().from_hash(h, |idx_hash| {
+            // Safety:
+            // indices in a join operation are always in bounds.
+            unsafe { compare_df_rows(keys, idx_hash.idx as usize, idx as usize) }
+        });
+        match entry {
+            RawEntryMut::Vacant(entry) => {
+                entry.insert_hashed_nocheck(h, IdxHash::new(idx, h), vacant_fn());
+                true
+            }
+            RawEntryMut::Occupied(_) => false,
+        }
+    } else {
+        populate_multiple_key_hashmap(hash_tbl, idx, h, keys, vacant_fn, occupied_fn);
+        true
+    }
+}
+
+/// Whether the rows that probe the hash tables are unique. The hashes of the rows are reused.
+fn probe_is_unique(hashes: &[UInt64Chunked], keys: &DataFrame) -> bool {
+    let mut hash_tbl: HashMap<IdxHash, (), IdBuildHasher> =
+        HashMap::with_capacity_and_hasher(keys.height(), Default::default());
+    let mut idx = 0;
+    for hashes in hashes {
+        for hashes in hashes.data_views() {
+            for &h in hashes {
+                if !insert_row(&mut hash_tbl, idx, h, keys, true, || (), |_| {}) {
+                    return false;
+                }
+                idx += 1;
+            }
+        }
+    }
+    true
+}
+
+/// Build the hash tables of a join. If the rows must be `unique`, `None` is returned at the
+/// first row that is inserted twice.
 pub(crate) fn create_build_table(
     hashes: &[UInt64Chunked],
     keys: &DataFrame,
-) -> Vec<HashMap<IdxHash, Vec<u32>, IdBuildHasher>> {
+    unique: bool,
+) -> Option<Vec<HashMap<IdxHash, Vec<IdxSize>, IdBuildHasher>>> {
     let n_partitions = set_partition_size();
+    let token = CancellationToken::current();
 
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
@@ -39,7 +97,7 @@ pub(crate) fn create_build_table(
     POOL.install(|| {
         (0..n_partitions).into_par_iter().map(|part_no| {
             let part_no = part_no as u64;
-            let mut hash_tbl: HashMap<IdxHash, Vec<u32>, IdBuildHasher> =
+            let mut hash_tbl: HashMap<IdxHash, Vec<IdxSize>, IdBuildHasher> =
                 HashMap::with_capacity_and_hasher(HASHMAP_INIT_SIZE, Default::default());
 
             let n_partitions = n_partitions as u64;
@@ -48,39 +106,48 @@ pub(crate) fn create_build_table(
                 for hashes in hashes.data_views() {
                     let len = hashes.len();
                     let mut idx = 0;
-                    hashes.iter().for_each(|h| {
+                    for h in hashes {
+                        if token.is_cancelled_at(idx as usize) {
+                            return Some(hash_tbl);
+                        }
                         // partition hashes by thread no.
                         // So only a part of the hashes go to this hashmap
                         if this_partition(*h, part_no, n_partitions) {
                             let idx = idx + offset;
-                            populate_multiple_key_hashmap(
+                            if !insert_row(
                                 &mut hash_tbl,
                                 idx,
                                 *h,
                                 keys,
+                                unique,
                                 || vec![idx],
                                 |v| v.push(idx),
-                            )
+                            ) {
+                                return None;
+                            }
                         }
                         idx += 1;
-                    });
+                    }
 
-                    offset += len as u32;
+                    offset += len as IdxSize;
                 }
             }
-            hash_tbl
+            Some(hash_tbl)
         })
     })
     .collect()
 }
 
+#[allow(clippy::type_complexity)]
 fn create_build_table_outer(
     hashes: &[UInt64Chunked],
     keys: &DataFrame,
-) -> Vec<HashMap<IdxHash, (bool, Vec<u32>), IdBuildHasher>> {
+    unique: bool,
+) -> Option<Vec<HashMap<IdxHash, (bool, Vec<IdxSize>), IdBuildHasher>>> {
     // Outer join equivalent of create_build_table() adds a bool in the hashmap values for tracking
     // whether a value in the hash table has already been matched to a value in the probe hashes.
     let n_partitions = set_partition_size();
+    let token = CancellationToken::current();
 
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
@@ -88,7 +155,7 @@ fn create_build_table_outer(
     POOL.install(|| {
         (0..n_partitions).into_par_iter().map(|part_no| {
             let part_no = part_no as u64;
-            let mut hash_tbl: HashMap<IdxHash, (bool, Vec<u32>), IdBuildHasher> =
+            let mut hash_tbl: HashMap<IdxHash, (bool, Vec<IdxSize>), IdBuildHasher> =
                 HashMap::with_capacity_and_hasher(HASHMAP_INIT_SIZE, Default::default());
 
             let n_partitions = n_partitions as u64;
@@ -97,27 +164,33 @@ fn create_build_table_outer(
                 for hashes in hashes.data_views() {
                     let len = hashes.len();
                     let mut idx = 0;
-                    hashes.iter().for_each(|h| {
+                    for h in hashes {
+                        if token.is_cancelled_at(idx as usize) {
+                            return Some(hash_tbl);
+                        }
                         // partition hashes by thread no.
                         // So only a part of the hashes go to this hashmap
                         if this_partition(*h, part_no, n_partitions) {
                             let idx = idx + offset;
-                            populate_multiple_key_hashmap(
+                            if !insert_row(
                                 &mut hash_tbl,
                                 idx,
                                 *h,
                                 keys,
+                                unique,
                                 || (false, vec![idx]),
                                 |v| v.1.push(idx),
-                            )
+                            ) {
+                                return None;
+                            }
                         }
                         idx += 1;
-                    });
+                    }
 
-                    offset += len as u32;
+                    offset += len as IdxSize;
                 }
             }
-            hash_tbl
+            Some(hash_tbl)
         })
     })
     .collect()
@@ -127,19 +200,23 @@ fn create_build_table_outer(
 #[allow(clippy::too_many_arguments)]
 fn probe_inner<F>(
     probe_hashes: &UInt64Chunked,
-    hash_tbls: &[HashMap<IdxHash, Vec<u32>, IdBuildHasher>],
-    results: &mut Vec<(u32, u32)>,
+    hash_tbls: &[HashMap<IdxHash, Vec<IdxSize>, IdBuildHasher>],
+    results: &mut Vec<(IdxSize, IdxSize)>,
     local_offset: usize,
     n_tables: u64,
     a: &DataFrame,
     b: &DataFrame,
+    token: &CancellationToken,
     swap_fn: F,
 ) where
-    F: Fn(u32, u32) -> (u32, u32),
+    F: Fn(IdxSize, IdxSize) -> (IdxSize, IdxSize),
 {
-    let mut idx_a = local_offset as u32;
+    let mut idx_a = local_offset as IdxSize;
     for probe_hashes in probe_hashes.data_views() {
         for &h in probe_hashes {
+            if token.is_cancelled_at(idx_a as usize) {
+                return;
+            }
             // probe table that contains the hashed value
             let current_probe_table =
                 unsafe { get_hash_tbl_threaded_join_partitioned(h, hash_tbls, n_tables) };
@@ -176,7 +253,8 @@ pub(crate) fn inner_join_multiple_keys(
     a: &DataFrame,
     b: &DataFrame,
     swap: bool,
-) -> Vec<(u32, u32)> {
+    validate: JoinValidation,
+) -> Result<Vec<(IdxSize, IdxSize)>> {
     // we assume that the b DataFrame is the shorter relation.
     // b will be used for the build phase.
 
@@ -187,15 +265,20 @@ pub(crate) fn inner_join_multiple_keys(
     let (build_hashes, random_state) = df_rows_to_hashes_threaded(&dfs_b, None);
     let (probe_hashes, _) = df_rows_to_hashes_threaded(&dfs_a, Some(random_state));
 
-    let hash_tbls = create_build_table(&build_hashes, b);
+    let hash_tbls = validate.check_build(
+        create_build_table(&build_hashes, b, validate.needs_unique(swap)),
+        swap,
+    )?;
     // early drop to reduce memory pressure
     drop(build_hashes);
+    validate.check_probe(swap, || probe_is_unique(&probe_hashes, a))?;
+    let token = CancellationToken::current();
 
     let n_tables = hash_tbls.len() as u64;
     let offsets = get_offsets(&probe_hashes);
     // next we probe the other relation
     // code duplication is because we want to only do the swap check once
-    POOL.install(|| {
+    Ok(POOL.install(|| {
         probe_hashes
             .into_par_iter()
             .zip(offsets)
@@ -215,6 +298,7 @@ pub(crate) fn inner_join_multiple_keys(
                         n_tables,
                         a,
                         b,
+                        &token,
                         |idx_a, idx_b| (idx_b, idx_a),
                     )
                 } else {
@@ -226,6 +310,7 @@ pub(crate) fn inner_join_multiple_keys(
                         n_tables,
                         a,
                         b,
+                        &token,
                         |idx_a, idx_b| (idx_a, idx_b),
                     )
                 }
@@ -234,17 +319,24 @@ pub(crate) fn inner_join_multiple_keys(
             })
             .flatten()
             .collect()
-    })
+    }))
 }
 
 #[cfg(feature = "private")]
-pub fn private_left_join_multiple_keys(a: &DataFrame, b: &DataFrame) -> Vec<(u32, Option<u32>)> {
+pub fn private_left_join_multiple_keys(
+    a: &DataFrame,
+    b: &DataFrame,
+) -> Vec<(IdxSize, Option<IdxSize>)> {
     let a = DataFrame::new_no_checks(to_physical_and_bit_repr(a.get_columns()));
     let b = DataFrame::new_no_checks(to_physical_and_bit_repr(b.get_columns()));
-    left_join_multiple_keys(&a, &b)
+    left_join_multiple_keys(&a, &b, JoinValidation::ManyToMany).unwrap()
 }
 
-pub(crate) fn left_join_multiple_keys(a: &DataFrame, b: &DataFrame) -> Vec<(u32, Option<u32>)> {
+pub(crate) fn left_join_multiple_keys(
+    a: &DataFrame,
+    b: &DataFrame,
+    validate: JoinValidation,
+) -> Result<Vec<(IdxSize, Option<IdxSize>)>> {
     // we should not join on logical types
     debug_assert!(!a.iter().any(|s| s.is_logical()));
     debug_assert!(!b.iter().any(|s| s.is_logical()));
@@ -256,16 +348,21 @@ pub(crate) fn left_join_multiple_keys(a: &DataFrame, b: &DataFrame) -> Vec<(u32,
     let (build_hashes, random_state) = df_rows_to_hashes_threaded(&dfs_b, None);
     let (probe_hashes, _) = df_rows_to_hashes_threaded(&dfs_a, Some(random_state));
 
-    let hash_tbls = create_build_table(&build_hashes, b);
+    let hash_tbls = validate.check_build(
+        create_build_table(&build_hashes, b, validate.needs_unique(false)),
+        false,
+    )?;
     // early drop to reduce memory pressure
     drop(build_hashes);
+    validate.check_probe(false, || probe_is_unique(&probe_hashes, a))?;
+    let token = CancellationToken::current();
 
     let n_tables = hash_tbls.len() as u64;
     let offsets = get_offsets(&probe_hashes);
 
     // next we probe the other relation
     // code duplication is because we want to only do the swap check once
-    POOL.install(|| {
+    Ok(POOL.install(|| {
         probe_hashes
             .into_par_iter()
             .zip(offsets)
@@ -276,9 +373,12 @@ pub(crate) fn left_join_multiple_keys(a: &DataFrame, b: &DataFrame) -> Vec<(u32,
                     Vec::with_capacity(probe_hashes.len() / POOL.current_num_threads());
                 let local_offset = offset;
 
-                let mut idx_a = local_offset as u32;
-                for probe_hashes in probe_hashes.data_views() {
+                let mut idx_a = local_offset as IdxSize;
+                'probe: for probe_hashes in probe_hashes.data_views() {
                     for &h in probe_hashes {
+                        if token.is_cancelled_at(idx_a as usize) {
+                            break 'probe;
+                        }
                         // probe table that contains the hashed value
                         let current_probe_table = unsafe {
                             get_hash_tbl_threaded_join_partitioned(h, hash_tbls, n_tables)
@@ -307,7 +407,7 @@ pub(crate) fn left_join_multiple_keys(a: &DataFrame, b: &DataFrame) -> Vec<(u32,
             })
             .flatten()
             .collect()
-    })
+    }))
 }
 
 /// Probe the build table and add tuples to the results (inner join)
@@ -315,8 +415,8 @@ pub(crate) fn left_join_multiple_keys(a: &DataFrame, b: &DataFrame) -> Vec<(u32,
 #[allow(clippy::type_complexity)]
 fn probe_outer<F, G, H>(
     probe_hashes: &[UInt64Chunked],
-    hash_tbls: &mut [HashMap<IdxHash, (bool, Vec<u32>), IdBuildHasher>],
-    results: &mut Vec<(Option<u32>, Option<u32>)>,
+    hash_tbls: &mut [HashMap<IdxHash, (bool, Vec<IdxSize>), IdBuildHasher>],
+    results: &mut Vec<(Option<IdxSize>, Option<IdxSize>)>,
     n_tables: u64,
     a: &DataFrame,
     b: &DataFrame,
@@ -328,12 +428,13 @@ fn probe_outer<F, G, H>(
     swap_fn_drain: H,
 ) where
     // idx_a, idx_b -> ...
-    F: Fn(u32, u32) -> (Option<u32>, Option<u32>),
+    F: Fn(IdxSize, IdxSize) -> (Option<IdxSize>, Option<IdxSize>),
     // idx_a -> ...
-    G: Fn(u32) -> (Option<u32>, Option<u32>),
+    G: Fn(IdxSize) -> (Option<IdxSize>, Option<IdxSize>),
     // idx_b -> ...
-    H: Fn(u32) -> (Option<u32>, Option<u32>),
+    H: Fn(IdxSize) -> (Option<IdxSize>, Option<IdxSize>),
 {
+    let token = CancellationToken::current();
     let mut idx_a = 0;
 
     // vec<ca>
@@ -342,6 +443,9 @@ fn probe_outer<F, G, H>(
         for probe_hashes in probe_hashes.data_views() {
             // chunk slices
             for &h in probe_hashes {
+                if token.is_cancelled_at(idx_a as usize) {
+                    return;
+                }
                 // probe table that contains the hashed value
                 let current_probe_table =
                     unsafe { get_hash_tbl_threaded_join_mut_partitioned(h, hash_tbls, n_tables) };
@@ -384,7 +488,8 @@ pub(crate) fn outer_join_multiple_keys(
     a: &DataFrame,
     b: &DataFrame,
     swap: bool,
-) -> Vec<(Option<u32>, Option<u32>)> {
+    validate: JoinValidation,
+) -> Result<Vec<(Option<IdxSize>, Option<IdxSize>)>> {
     // we assume that the b DataFrame is the shorter relation.
     // b will be used for the build phase.
 
@@ -398,9 +503,13 @@ pub(crate) fn outer_join_multiple_keys(
     let (build_hashes, random_state) = df_rows_to_hashes_threaded(&dfs_b, None);
     let (probe_hashes, _) = df_rows_to_hashes_threaded(&dfs_a, Some(random_state));
 
-    let mut hash_tbls = create_build_table_outer(&build_hashes, b);
+    let mut hash_tbls = validate.check_build(
+        create_build_table_outer(&build_hashes, b, validate.needs_unique(swap)),
+        swap,
+    )?;
     // early drop to reduce memory pressure
     drop(build_hashes);
+    validate.check_probe(swap, || probe_is_unique(&probe_hashes, a))?;
 
     let n_tables = hash_tbls.len() as u64;
     // probe the hash table.
@@ -433,5 +542,5 @@ pub(crate) fn outer_join_multiple_keys(
             |idx_b| (None, Some(idx_b)),
         )
     }
-    results
+    Ok(results)
 }
@@ -0,0 +1,220 @@
+//! Merge joins on join keys that are already sorted.
+//!
+//! If both keys carry the sorted flag in the same direction we don't need to build a hash
+//! table. A single linear pass over both keys finds the matches, in the order of the left key.
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+/// Determine if a merge join can be used and if so, if the keys are sorted in descending order.
+fn merge_join_order(s_left: &Series, s_right: &Series) -> Option<bool> {
+    let descending = match (s_left.is_sorted(), s_right.is_sorted()) {
+        (IsSorted::Ascending, IsSorted::Ascending) => false,
+        (IsSorted::Descending, IsSorted::Descending) => true,
+        _ => return None,
+    };
+    // the physical order of categoricals is not the order of the strings, and floats are
+    // compared by value while the hash joins compare their bits (-0.0 != 0.0, NaN == NaN)
+    if !s_left.is_numeric_physical()
+        || matches!(
+            s_left.dtype(),
+            DataType::Categorical | DataType::Enum(_) | DataType::Float32 | DataType::Float64
+        )
+    {
+        return None;
+    }
+    // null keys are only passed if they join each other, we leave that to the hash joins
+    if s_left.null_count() > 0 || s_right.null_count() > 0 {
+        return None;
+    }
+    Some(descending)
+}
+
+fn compare<T: PartialOrd>(a: &T, b: &T, descending: bool) -> Option<Ordering> {
+    let ord = a.partial_cmp(b)?;
+    if descending {
+        Some(ord.reverse())
+    } else {
+        Some(ord)
+    }
+}
+
+/// Merge two sorted slices. `on_match` is called with every matching pair and with `None`
+/// for every left index that has no match. Returns `None` if the keys are not totally
+/// ordered (NaN).
+fn merge_slices<T, F>(left: &[T], right: &[T], descending: bool, mut on_match: F) -> Option<()>
+where
+    T: PartialOrd,
+    F: FnMut(IdxSize, Option<IdxSize>),
+{
+    // start of the run of right values that are not smaller than the current left value
+    let mut start = 0;
+    for (idx_l, l) in left.iter().enumerate() {
+        while start < right.len() && compare(&right[start], l, descending)? == Ordering::Less {
+            start += 1;
+        }
+        let mut idx_r = start;
+        // equal left values rescan the same run of right values
+        while idx_r < right.len() && compare(&right[idx_r], l, descending)? == Ordering::Equal {
+            on_match(idx_l as IdxSize, Some(idx_r as IdxSize));
+            idx_r += 1;
+        }
+        if idx_r == start {
+            on_match(idx_l as IdxSize, None)
+        }
+    }
+    Some(())
+}
+
+fn merge_join_inner_ca<T>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    descending: bool,
+) -> Option<Vec<(IdxSize, IdxSize)>>
+where
+    T: PolarsNumericType,
+{
+    let left = left.rechunk();
+    let right = right.rechunk();
+    let left = left.cont_slice().ok()?;
+    let right = right.cont_slice().ok()?;
+
+    let mut out = Vec::with_capacity(std::cmp::min(left.len(), right.len()));
+    merge_slices(left, right, descending, |idx_l, idx_r| {
+        if let Some(idx_r) = idx_r {
+            out.push((idx_l, idx_r))
+        }
+    })?;
+    Some(out)
+}
+
+fn merge_join_left_ca<T>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    descending: bool,
+) -> Option<Vec<(IdxSize, Option<IdxSize>)>>
+where
+    T: PolarsNumericType,
+{
+    let left = left.rechunk();
+    let right = right.rechunk();
+    let left = left.cont_slice().ok()?;
+    let right = right.cont_slice().ok()?;
+
+    let mut out = Vec::with_capacity(left.len());
+    merge_slices(left, right, descending, |idx_l, idx_r| {
+        out.push((idx_l, idx_r))
+    })?;
+    Some(out)
+}
+
+macro_rules! impl_merge_join {
+    ($ca:expr, $other:expr, $descending:expr, $join_fn:ident) => {{
+        let other: &Series = &$other;
+        $join_fn($ca, other.as_ref().as_ref(), $descending)
+    }};
+}
+
+/// Inner join tuples of two sorted integer or temporal keys, in the order of the left key.
+/// Returns `None` if the keys are not eligible for a merge join.
+pub(crate) fn merge_join_inner(
+    s_left: &Series,
+    s_right: &Series,
+    validate: JoinValidation,
+) -> Result<Option<Vec<(IdxSize, IdxSize)>>> {
+    let descending = match merge_join_order(s_left, s_right) {
+        Some(descending) => descending,
+        None => return Ok(None),
+    };
+    validate.check_sorted(s_left, s_right)?;
+    let s_left = s_left.to_physical_repr();
+    let s_right = s_right.to_physical_repr();
+    Ok(match_arrow_data_type_apply_macro_ca_logical_num!(
+        s_left,
+        impl_merge_join,
+        s_right,
+        descending,
+        merge_join_inner_ca
+    ))
+}
+
+/// Left join tuples of two sorted integer or temporal keys, in the order of the left key.
+/// Returns `None` if the keys are not eligible for a merge join.
+pub(crate) fn merge_join_left(
+    s_left: &Series,
+    s_right: &Series,
+    validate: JoinValidation,
+) -> Result<Option<Vec<(IdxSize, Option<IdxSize>)>>> {
+    let descending = match merge_join_order(s_left, s_right) {
+        Some(descending) => descending,
+        None => return Ok(None),
+    };
+    validate.check_sorted(s_left, s_right)?;
+    let s_left = s_left.to_physical_repr();
+    let s_right = s_right.to_physical_repr();
+    Ok(match_arrow_data_type_apply_macro_ca_logical_num!(
+        s_left,
+        impl_merge_join,
+        s_right,
+        descending,
+        merge_join_left_ca
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_merge_join() {
+        let left = Series::new("a", &[1, 2, 2, 4, 5]).sort(false);
+        let right = Series::new("b", &[0, 2, 2, 3, 5]).sort(false);
+
+        let tuples = merge_join_inner(&left, &right, JoinValidation::ManyToMany)
+            .unwrap()
+            .unwrap();
+        assert_eq!(tuples, &[(1, 1), (1, 2), (2, 1), (2, 2), (4, 4)]);
+        let tuples = merge_join_left(&left, &right, JoinValidation::ManyToMany)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            tuples,
+            &[
+                (0, None),
+                (1, Some(1)),
+                (1, Some(2)),
+                (2, Some(1)),
+                (2, Some(2)),
+                (3, None),
+                (4, Some(4))
+            ]
+        );
+
+        let left = left.sort(true);
+        let right = right.sort(true);
+        let tuples = merge_join_inner(&left, &right, JoinValidation::ManyToMany)
+            .unwrap()
+            .unwrap();
+        assert_eq!(tuples, &[(0, 0), (2, 2), (2, 3), (3, 2), (3, 3)]);
+
+        // not flagged sorted in the same direction
+        let right = right.sort(false);
+        assert!(merge_join_inner(&left, &right, JoinValidation::ManyToMany)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_merge_join_float() {
+        // floats are left to the hash joins
+        let left = Series::new("a", &[-0.0, 1.0]).sort(false);
+        let right = Series::new("b", &[0.0, 1.0]).sort(false);
+        assert!(merge_join_inner(&left, &right, JoinValidation::ManyToMany)
+            .unwrap()
+            .is_none());
+        let left = Series::new("a", &[1.0, f64::NAN]).sort(false);
+        let right = Series::new("b", &[1.0, f64::NAN]).sort(false);
+        assert!(merge_join_inner(&left, &right, JoinValidation::ManyToMany)
+            .unwrap()
+            .is_none());
+    }
+}
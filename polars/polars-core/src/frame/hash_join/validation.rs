@@ -0,0 +1,311 @@
+use crate::prelude::*;
+
+/// Expected relation between the join keys of the left and the right table.
+///
+/// The keys are checked while the hash tables of the join are built and a violation returns an
+/// error instead of silently duplicating rows. Null keys only count if they are joined.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinValidation {
+    /// No check.
+    ManyToMany,
+    /// The keys of the right table are unique.
+    ManyToOne,
+    /// The keys of the left table are unique.
+    OneToMany,
+    /// The keys of both tables are unique.
+    OneToOne,
+}
+
+impl Default for JoinValidation {
+    fn default() -> Self {
+        JoinValidation::ManyToMany
+    }
+}
+
+impl JoinValidation {
+    fn as_str(&self) -> &'static str {
+        match self {
+            JoinValidation::ManyToMany => "many_to_many",
+            JoinValidation::ManyToOne => "many_to_one",
+            JoinValidation::OneToMany => "one_to_many",
+            JoinValidation::OneToOne => "one_to_one",
+        }
+    }
+
+    /// Whether the keys of the left or the right table must be unique.
+    pub(crate) fn needs_unique(&self, left: bool) -> bool {
+        match self {
+            JoinValidation::ManyToMany => false,
+            JoinValidation::ManyToOne => !left,
+            JoinValidation::OneToMany => left,
+            JoinValidation::OneToOne => true,
+        }
+    }
+
+    fn not_unique(&self, left: bool) -> PolarsError {
+        PolarsError::ComputeError(
+            format!(
+                "the join keys of the {} table are not unique, \
+                 which is required by the '{}' join validation",
+                if left { "left" } else { "right" },
+                self.as_str()
+            )
+            .into(),
+        )
+    }
+
+    /// Check the hash tables of a join, which are `None` if a duplicate key was found while
+    /// they were built. The tables are built from the right keys, unless the sides are `swapped`.
+    pub(crate) fn check_build<T>(&self, hash_tbls: Option<T>, swapped: bool) -> Result<T> {
+        hash_tbls.ok_or_else(|| self.not_unique(swapped))
+    }
+
+    /// Check the keys that probe the hash tables. They aren't inserted in a table, so
+    /// `is_unique` is only called if their uniqueness is required.
+    pub(crate) fn check_probe<F>(&self, swapped: bool, is_unique: F) -> Result<()>
+    where
+        F: FnOnce() -> bool,
+    {
+        if self.needs_unique(!swapped) && !is_unique() {
+            Err(self.not_unique(!swapped))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Check keys that are joined without hash tables. The keys are sorted, so duplicates
+    /// are adjacent.
+    pub(crate) fn check_sorted(&self, s_left: &Series, s_right: &Series) -> Result<()> {
+        for (left, s) in [(true, s_left), (false, s_right)] {
+            if self.needs_unique(left) && s.len() > 1 {
+                let n = s.len() - 1;
+                if s.slice(1, n).equal(&s.slice(0, n)).any() {
+                    return Err(self.not_unique(left));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Validity of the join keys per row. `None` if no key has null values.
+fn key_validity(keys: &[Series]) -> Option<Vec<bool>> {
+    let mut validity: Option<Vec<bool>> = None;
+    for s in keys.iter().filter(|s| s.null_count() > 0) {
+        let validity = validity.get_or_insert_with(|| vec![true; s.len()]);
+        for (valid, is_valid) in validity.iter_mut().zip(s.is_not_null().into_no_null_iter()) {
+            *valid &= is_valid
+        }
+    }
+    validity
+}
+
+/// The rows of the join keys with and without a null key, if nulls don't join.
+///
+/// Only the rows without a null key are hashed and probed. The rows with a null key are added
+/// to the join tuples as rows without a match.
+pub(crate) struct NullKeyRows {
+    left: Option<(IdxCa, Vec<IdxSize>)>,
+    right: Option<(IdxCa, Vec<IdxSize>)>,
+}
+
+/// The rows with and without a null key. `None` if no key has null values.
+fn split_null_rows(keys: &[Series]) -> Option<(IdxCa, Vec<IdxSize>)> {
+    key_validity(keys).map(|validity| {
+        let mut valid = Vec::with_capacity(validity.len());
+        let mut null = vec![];
+        for (idx, is_valid) in validity.into_iter().enumerate() {
+            if is_valid {
+                valid.push(idx as IdxSize)
+            } else {
+                null.push(idx as IdxSize)
+            }
+        }
+        (IdxCa::from_vec("", valid), null)
+    })
+}
+
+/// Take the keys of the rows without a null key.
+fn take_valid(rows: &Option<(IdxCa, Vec<IdxSize>)>, keys: &[Series]) -> Vec<Series> {
+    match rows {
+        // Safety:
+        // the rows are in bounds of the keys.
+        Some((valid, _)) => keys
+            .iter()
+            .map(|s| unsafe { s.take_unchecked(valid).unwrap() })
+            .collect(),
+        None => keys.to_vec(),
+    }
+}
+
+/// Map an index of the keys without nulls back to the row of the original keys.
+#[inline]
+fn original_row(rows: &Option<(IdxCa, Vec<IdxSize>)>, idx: IdxSize) -> IdxSize {
+    match rows {
+        Some((valid, _)) => valid.cont_slice().unwrap()[idx as usize],
+        None => idx,
+    }
+}
+
+impl NullKeyRows {
+    /// Returns `None` if there are no null keys and the keys can be joined as they are.
+    pub(crate) fn new(selected_left: &[Series], selected_right: &[Series]) -> Option<Self> {
+        let left = split_null_rows(selected_left);
+        let right = split_null_rows(selected_right);
+        if left.is_none() && right.is_none() {
+            None
+        } else {
+            Some(NullKeyRows { left, right })
+        }
+    }
+
+    /// The left keys without the rows with a null key.
+    pub(crate) fn take_left(&self, keys: &[Series]) -> Vec<Series> {
+        take_valid(&self.left, keys)
+    }
+
+    /// The right keys without the rows with a null key.
+    pub(crate) fn take_right(&self, keys: &[Series]) -> Vec<Series> {
+        take_valid(&self.right, keys)
+    }
+
+    pub(crate) fn finish_inner(
+        &self,
+        mut join_tuples: Vec<(IdxSize, IdxSize)>,
+    ) -> Vec<(IdxSize, IdxSize)> {
+        for (l, r) in join_tuples.iter_mut() {
+            *l = original_row(&self.left, *l);
+            *r = original_row(&self.right, *r);
+        }
+        join_tuples
+    }
+
+    /// The left rows with a null key are merged in without a match, so that the left rows keep
+    /// their order.
+    pub(crate) fn finish_left(
+        &self,
+        join_tuples: Vec<(IdxSize, Option<IdxSize>)>,
+    ) -> Vec<(IdxSize, Option<IdxSize>)> {
+        let left_nulls = self.left.as_ref().map_or(&[][..], |(_, null)| null);
+        let mut left_nulls = left_nulls.iter().copied().peekable();
+        let mut out = Vec::with_capacity(join_tuples.len() + left_nulls.len());
+        for (l, r) in join_tuples {
+            let l = original_row(&self.left, l);
+            while let Some(null) = left_nulls.next_if(|null| *null < l) {
+                out.push((null, None))
+            }
+            out.push((l, r.map(|r| original_row(&self.right, r))))
+        }
+        out.extend(left_nulls.map(|null| (null, None)));
+        out
+    }
+
+    /// The rows with a null key of both sides are added without a match.
+    pub(crate) fn finish_outer(
+        &self,
+        join_tuples: Vec<(Option<IdxSize>, Option<IdxSize>)>,
+    ) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
+        let left_nulls = self.left.as_ref().map_or(&[][..], |(_, null)| null);
+        let right_nulls = self.right.as_ref().map_or(&[][..], |(_, null)| null);
+        let mut out = Vec::with_capacity(join_tuples.len() + left_nulls.len() + right_nulls.len());
+        out.extend(join_tuples.into_iter().map(|(l, r)| {
+            (
+                l.map(|l| original_row(&self.left, l)),
+                r.map(|r| original_row(&self.right, r)),
+            )
+        }));
+        out.extend(left_nulls.iter().map(|&null| (Some(null), None)));
+        out.extend(right_nulls.iter().map(|&null| (None, Some(null))));
+        out
+    }
+}
+
+/// Removes the matches between a null and a valid key if nulls join.
+///
+/// The hash tables of a single key hash a null like a valid value, so the two can collide.
+pub(crate) struct NullKeyFilter {
+    left: Option<Vec<bool>>,
+    right: Option<Vec<bool>>,
+}
+
+impl NullKeyFilter {
+    /// Returns `None` if there are no null keys and all matches can be kept.
+    pub(crate) fn new(selected_left: &[Series], selected_right: &[Series]) -> Option<Self> {
+        let left = key_validity(selected_left);
+        let right = key_validity(selected_right);
+        if left.is_none() && right.is_none() {
+            None
+        } else {
+            Some(NullKeyFilter { left, right })
+        }
+    }
+
+    fn keep(&self, idx_left: IdxSize, idx_right: IdxSize) -> bool {
+        let valid_left = self.left.as_ref().map_or(true, |v| v[idx_left as usize]);
+        let valid_right = self.right.as_ref().map_or(true, |v| v[idx_right as usize]);
+        valid_left == valid_right
+    }
+
+    pub(crate) fn filter_inner(&self, join_tuples: &mut Vec<(IdxSize, IdxSize)>) {
+        join_tuples.retain(|(l, r)| self.keep(*l, *r))
+    }
+
+    /// Removed matches become a left row without a match, if the left row has no other match.
+    pub(crate) fn filter_left(
+        &self,
+        join_tuples: Vec<(IdxSize, Option<IdxSize>)>,
+    ) -> Vec<(IdxSize, Option<IdxSize>)> {
+        let opt_join_tuples = join_tuples.into_iter().map(|(l, r)| (Some(l), r)).collect();
+        self.filter_opt(opt_join_tuples, false)
+            .into_iter()
+            .map(|(l, r)| (l.unwrap(), r))
+            .collect()
+    }
+
+    /// Removed matches become left and right rows without a match, if those rows have
+    /// no other match.
+    pub(crate) fn filter_outer(
+        &self,
+        join_tuples: Vec<(Option<IdxSize>, Option<IdxSize>)>,
+    ) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
+        self.filter_opt(join_tuples, true)
+    }
+
+    fn filter_opt(
+        &self,
+        join_tuples: Vec<(Option<IdxSize>, Option<IdxSize>)>,
+        outer: bool,
+    ) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
+        let keep = |tpl: &(Option<IdxSize>, Option<IdxSize>)| match tpl {
+            (Some(l), Some(r)) => self.keep(*l, *r),
+            _ => true,
+        };
+        // the rows that still occur in the output
+        let mut seen_left = PlHashSet::default();
+        let mut seen_right = PlHashSet::default();
+        for tpl in join_tuples.iter().filter(|tpl| keep(tpl)) {
+            if let Some(l) = tpl.0 {
+                seen_left.insert(l);
+            }
+            if let Some(r) = tpl.1 {
+                seen_right.insert(r);
+            }
+        }
+
+        let mut out = Vec::with_capacity(join_tuples.len());
+        for tpl in join_tuples {
+            if keep(&tpl) {
+                out.push(tpl);
+                continue;
+            }
+            let (l, r) = (tpl.0.unwrap(), tpl.1.unwrap());
+            if seen_left.insert(l) {
+                out.push((Some(l), None))
+            }
+            if outer && seen_right.insert(r) {
+                out.push((None, Some(r)))
+            }
+        }
+        out
+    }
+}
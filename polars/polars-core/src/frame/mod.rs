@@ -9,8 +9,11 @@ use ahash::{AHashSet, RandomState};
 use polars_arrow::prelude::QuantileInterpolOptions;
 use rayon::prelude::*;
 
+use crate::chunked_array::ops::estimated_size::SizeEstimator;
 use crate::chunked_array::ops::unique::is_unique_helper;
+use crate::config::Config;
 use crate::prelude::*;
+use crate::series::private::PrivateSeries;
 use crate::utils::{accumulate_dataframes_horizontal, get_supertype, split_ca, split_df, NoNull};
 
 #[cfg(feature = "dataframe_arithmetic")]
@@ -21,10 +24,16 @@ mod chunks;
 #[cfg(feature = "cross_join")]
 pub(crate) mod cross_join;
 pub mod explode;
+#[cfg(feature = "spill")]
+pub mod external_sort;
 pub mod groupby;
 pub mod hash_join;
+#[cfg(feature = "range_join")]
+pub(crate) mod range_join;
 #[cfg(feature = "rows")]
 pub mod row;
+#[cfg(feature = "spill")]
+pub mod spill;
 mod upstream_traits;
 
 #[cfg(feature = "sort_multiple")]
@@ -300,12 +309,15 @@ impl DataFrame {
     ///  | 3   | Patricia |
     ///  +-----+----------+
     /// ```
-    pub fn with_row_count(&self, name: &str, offset: Option<u32>) -> Result<Self> {
+    pub fn with_row_count(&self, name: &str, offset: Option<IdxSize>) -> Result<Self> {
         let mut columns = Vec::with_capacity(self.columns.len() + 1);
         let offset = offset.unwrap_or(0);
         columns.push(
-            UInt32Chunked::from_vec(name, (offset..(self.height() as u32) + offset).collect())
-                .into_series(),
+            IdxCa::from_vec(
+                name,
+                (offset..(self.height() as IdxSize) + offset).collect(),
+            )
+            .into_series(),
         );
 
         columns.extend_from_slice(&self.columns);
@@ -313,12 +325,15 @@ impl DataFrame {
     }
 
     /// Add a row count in place.
-    pub fn with_row_count_mut(&mut self, name: &str, offset: Option<u32>) -> &mut Self {
+    pub fn with_row_count_mut(&mut self, name: &str, offset: Option<IdxSize>) -> &mut Self {
         let offset = offset.unwrap_or(0);
         self.columns.insert(
             0,
-            UInt32Chunked::from_vec(name, (offset..(self.height() as u32) + offset).collect())
-                .into_series(),
+            IdxCa::from_vec(
+                name,
+                (offset..(self.height() as IdxSize) + offset).collect(),
+            )
+            .into_series(),
         );
         self
     }
@@ -351,6 +366,17 @@ impl DataFrame {
         }
     }
 
+    /// Estimate the number of bytes that are held by the buffers of the `DataFrame`. Buffers
+    /// that are shared between columns are counted once. See
+    /// [`ChunkEstimatedSize::estimated_size`] for what is counted.
+    pub fn estimated_size(&self) -> usize {
+        let mut estimator = SizeEstimator::default();
+        for s in &self.columns {
+            s._add_to_size_estimate(&mut estimator);
+        }
+        estimator.finish()
+    }
+
     /// Aggregate all the chunks in the DataFrame to a single chunk.
     pub fn as_single_chunk(&mut self) -> &mut Self {
         // Don't parallelize this. Memory overhead
@@ -1366,7 +1392,7 @@ impl DataFrame {
     /// }
     /// ```
     pub fn filter(&self, mask: &BooleanChunked) -> Result<Self> {
-        if std::env::var("POLARS_VERT_PAR").is_ok() {
+        if Config::current().vertical_parallel {
             return self.filter_vertical(mask);
         }
 
@@ -1419,8 +1445,8 @@ impl DataFrame {
     where
         I: Iterator<Item = usize> + Clone + Sync + TrustedLen,
     {
-        if std::env::var("POLARS_VERT_PAR").is_ok() {
-            let idx_ca: NoNull<UInt32Chunked> = iter.into_iter().map(|idx| idx as u32).collect();
+        if Config::current().vertical_parallel {
+            let idx_ca: NoNull<IdxCa> = iter.into_iter().map(|idx| idx as IdxSize).collect();
             return self.take_unchecked_vectical(&idx_ca.into_inner());
         }
 
@@ -1434,7 +1460,7 @@ impl DataFrame {
             .any(|s| matches!(s.dtype(), DataType::Utf8));
 
         if (n_chunks == 1 && self.width() > 1) || has_utf8 {
-            let idx_ca: NoNull<UInt32Chunked> = iter.into_iter().map(|idx| idx as u32).collect();
+            let idx_ca: NoNull<IdxCa> = iter.into_iter().map(|idx| idx as IdxSize).collect();
             let idx_ca = idx_ca.into_inner();
             return self.take_unchecked(&idx_ca);
         }
@@ -1469,8 +1495,11 @@ impl DataFrame {
     where
         I: Iterator<Item = Option<usize>> + Clone + Sync + TrustedLen,
     {
-        if std::env::var("POLARS_VERT_PAR").is_ok() {
-            let idx_ca: UInt32Chunked = iter.into_iter().map(|opt| opt.map(|v| v as u32)).collect();
+        if Config::current().vertical_parallel {
+            let idx_ca: IdxCa = iter
+                .into_iter()
+                .map(|opt| opt.map(|v| v as IdxSize))
+                .collect();
             return self.take_unchecked_vectical(&idx_ca);
         }
 
@@ -1485,7 +1514,10 @@ impl DataFrame {
             .any(|s| matches!(s.dtype(), DataType::Utf8));
 
         if (n_chunks == 1 && self.width() > 1) || has_utf8 {
-            let idx_ca: UInt32Chunked = iter.into_iter().map(|opt| opt.map(|v| v as u32)).collect();
+            let idx_ca: IdxCa = iter
+                .into_iter()
+                .map(|opt| opt.map(|v| v as IdxSize))
+                .collect();
             return self.take_unchecked(&idx_ca);
         }
 
@@ -1516,11 +1548,11 @@ impl DataFrame {
     /// ```
     /// # use polars_core::prelude::*;
     /// fn example(df: &DataFrame) -> Result<DataFrame> {
-    ///     let idx = UInt32Chunked::new("idx", &[0, 1, 9]);
+    ///     let idx = IdxCa::new("idx", &[0, 1, 9]);
     ///     df.take(&idx)
     /// }
     /// ```
-    pub fn take(&self, indices: &UInt32Chunked) -> Result<Self> {
+    pub fn take(&self, indices: &IdxCa) -> Result<Self> {
         let indices = if indices.chunks.len() > 1 {
             Cow::Owned(indices.rechunk())
         } else {
@@ -1540,11 +1572,11 @@ impl DataFrame {
     }
 
     #[cfg(feature = "rows")]
-    pub(crate) unsafe fn take_unchecked_slice(&self, idx: &[u32]) -> Self {
+    pub(crate) unsafe fn take_unchecked_slice(&self, idx: &[IdxSize]) -> Self {
         self.take_iter_unchecked(idx.iter().map(|i| *i as usize))
     }
 
-    pub(crate) unsafe fn take_unchecked(&self, idx: &UInt32Chunked) -> Self {
+    pub(crate) unsafe fn take_unchecked(&self, idx: &IdxCa) -> Self {
         let cols = POOL.install(|| {
             self.columns
                 .par_iter()
@@ -1557,7 +1589,7 @@ impl DataFrame {
         DataFrame::new_no_checks(cols)
     }
 
-    unsafe fn take_unchecked_vectical(&self, indices: &UInt32Chunked) -> Self {
+    unsafe fn take_unchecked_vectical(&self, indices: &IdxCa) -> Self {
         let n_threads = POOL.current_num_threads();
         let idxs = split_ca(indices, n_threads).unwrap();
 
@@ -1652,7 +1684,7 @@ impl DataFrame {
         };
         // Safety:
         // the created indices are in bounds
-        let mut df = if std::env::var("POLARS_VERT_PAR").is_ok() {
+        let mut df = if Config::current().vertical_parallel {
             unsafe { self.take_unchecked_vectical(&take) }
         } else {
             unsafe { self.take_unchecked(&take) }
@@ -2752,9 +2784,9 @@ impl DataFrame {
         let gb = self.groupby(names)?;
         let groups = gb.get_groups().idx_ref();
 
-        let finish_maintain_order = |mut groups: Vec<u32>| {
+        let finish_maintain_order = |mut groups: Vec<IdxSize>| {
             groups.sort_unstable();
-            let ca = UInt32Chunked::from_vec("", groups);
+            let ca = IdxCa::from_vec("", groups);
             unsafe { self.take_unchecked(&ca) }
         };
 
@@ -2798,7 +2830,12 @@ impl DataFrame {
     pub fn is_unique(&self) -> Result<BooleanChunked> {
         let mut gb = self.groupby(self.get_column_names())?;
         let groups = std::mem::take(&mut gb.groups);
-        Ok(is_unique_helper(groups, self.height() as u32, true, false))
+        Ok(is_unique_helper(
+            groups,
+            self.height() as IdxSize,
+            true,
+            false,
+        ))
     }
 
     /// Get a mask of all the duplicated rows in the `DataFrame`.
@@ -2817,7 +2854,12 @@ impl DataFrame {
     pub fn is_duplicated(&self) -> Result<BooleanChunked> {
         let mut gb = self.groupby(self.get_column_names())?;
         let groups = std::mem::take(&mut gb.groups);
-        Ok(is_unique_helper(groups, self.height() as u32, false, true))
+        Ok(is_unique_helper(
+            groups,
+            self.height() as IdxSize,
+            false,
+            true,
+        ))
     }
 
     /// Create a new `DataFrame` that shows the null counts per column.
@@ -0,0 +1,491 @@
+//! Joins on inequality predicates, for instance `left.ts >= right.start AND left.ts < right.end`.
+//!
+//! Rows are first grouped on the equality predicates. Within every group the right rows are
+//! sorted on the first inequality predicate, so that the right rows that match a left row on it
+//! form a contiguous range. With a second inequality predicate the right rows are also ranked
+//! on it, and a merge sort tree over these ranks finds the rows of the range that match on both
+//! predicates. The remaining predicates are checked on the candidate pairs.
+use crate::frame::groupby::GroupsIndicator;
+use crate::prelude::*;
+use crate::utils::get_supertype;
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// The right rows sorted on a predicate and the matching range of these rows for every left row.
+/// The rows are positions in the right indexes of a group. Right rows with a null value are left
+/// out and left rows with a null value have no range.
+type SortedRanges = (Vec<usize>, Vec<Option<Range<usize>>>);
+
+/// Sort the rows of `right_idx` on their values and find the range of sorted rows for which
+/// `left[l] op right[r]` holds for every row of `left_idx`.
+fn sorted_ranges<T>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    op: JoinOperator,
+    left_idx: &[IdxSize],
+    right_idx: &[IdxSize],
+) -> SortedRanges
+where
+    T: PolarsNumericType,
+{
+    let mut sorted = right_idx
+        .iter()
+        .enumerate()
+        .filter_map(|(pos, &idx)| right.get(idx as usize).map(|v| (v, pos)))
+        .collect::<Vec<_>>();
+    sorted.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+    let ranges = left_idx
+        .iter()
+        .map(|&l| {
+            left.get(l as usize).map(|v| match op {
+                JoinOperator::Eq => {
+                    sorted.partition_point(|(r, _)| *r < v)
+                        ..sorted.partition_point(|(r, _)| *r <= v)
+                }
+                JoinOperator::Lt => sorted.partition_point(|(r, _)| *r <= v)..sorted.len(),
+                JoinOperator::LtEq => sorted.partition_point(|(r, _)| *r < v)..sorted.len(),
+                JoinOperator::Gt => 0..sorted.partition_point(|(r, _)| *r < v),
+                JoinOperator::GtEq => 0..sorted.partition_point(|(r, _)| *r <= v),
+            })
+        })
+        .collect();
+    (sorted.into_iter().map(|(_, pos)| pos).collect(), ranges)
+}
+
+/// Cast both columns to their supertype and return the physical representation.
+fn to_physical_pair(left: &Series, right: &Series) -> Result<(Series, Series)> {
+    let st = get_supertype(left.dtype(), right.dtype())?;
+    let left = left.cast(&st)?.to_physical_repr().into_owned();
+    let right = right.cast(&st)?.to_physical_repr().into_owned();
+    // small integers are casted to reduce compiler bloat
+    let (left, right) = match left.dtype() {
+        DataType::Int32 | DataType::Int64 | DataType::UInt32 | DataType::UInt64 => (left, right),
+        DataType::Float32 | DataType::Float64 => (left, right),
+        #[cfg(feature = "dtype-i8")]
+        DataType::Int8 => (left.cast(&DataType::Int32)?, right.cast(&DataType::Int32)?),
+        #[cfg(feature = "dtype-i16")]
+        DataType::Int16 => (left.cast(&DataType::Int32)?, right.cast(&DataType::Int32)?),
+        #[cfg(feature = "dtype-u8")]
+        DataType::UInt8 => (left.cast(&DataType::Int32)?, right.cast(&DataType::Int32)?),
+        #[cfg(feature = "dtype-u16")]
+        DataType::UInt16 => (left.cast(&DataType::Int32)?, right.cast(&DataType::Int32)?),
+        dt => {
+            return Err(PolarsError::InvalidOperation(
+                format!(
+                    "join_where only supports numeric and temporal columns in inequality predicates, got {:?}",
+                    dt
+                )
+                .into(),
+            ))
+        }
+    };
+    Ok((left, right))
+}
+
+fn sorted_ranges_series(
+    left: &Series,
+    right: &Series,
+    op: JoinOperator,
+    left_idx: &[IdxSize],
+    right_idx: &[IdxSize],
+) -> Result<SortedRanges> {
+    macro_rules! dispatch {
+        ($method:ident) => {
+            sorted_ranges(left.$method()?, right.$method()?, op, left_idx, right_idx)
+        };
+    }
+    let out = match left.dtype() {
+        DataType::Int32 => dispatch!(i32),
+        DataType::Int64 => dispatch!(i64),
+        DataType::UInt32 => dispatch!(u32),
+        DataType::UInt64 => dispatch!(u64),
+        DataType::Float32 => dispatch!(f32),
+        DataType::Float64 => dispatch!(f64),
+        _ => unreachable!(),
+    };
+    Ok(out)
+}
+
+/// A segment tree over a sequence of `(rank, row)` pairs. Every node holds the pairs of its
+/// segment sorted on the rank, so the pairs of a segment within a range of ranks are found with
+/// a binary search per node.
+struct MergeSortTree {
+    len: usize,
+    nodes: Vec<Vec<(IdxSize, IdxSize)>>,
+}
+
+impl MergeSortTree {
+    fn new(pairs: Vec<(IdxSize, IdxSize)>) -> Self {
+        let len = pairs.len();
+        let mut nodes = vec![vec![]; 2 * len];
+        for (i, pair) in pairs.into_iter().enumerate() {
+            nodes[len + i] = vec![pair];
+        }
+        for i in (1..len).rev() {
+            let mut node = [nodes[2 * i].as_slice(), nodes[2 * i + 1].as_slice()].concat();
+            node.sort_unstable();
+            nodes[i] = node;
+        }
+        Self { len, nodes }
+    }
+
+    /// Call `f` with the row of every pair in `segment` with a rank in `ranks`.
+    fn query(&self, segment: Range<usize>, ranks: Range<usize>, mut f: impl FnMut(usize)) {
+        let mut visit = |node: &[(IdxSize, IdxSize)]| {
+            let start = node.partition_point(|(rank, _)| (*rank as usize) < ranks.start);
+            let end = node.partition_point(|(rank, _)| (*rank as usize) < ranks.end);
+            for (_, row) in &node[start..end] {
+                f(*row as usize)
+            }
+        };
+        let mut lo = segment.start + self.len;
+        let mut hi = segment.end + self.len;
+        while lo < hi {
+            if lo & 1 == 1 {
+                visit(&self.nodes[lo]);
+                lo += 1;
+            }
+            if hi & 1 == 1 {
+                hi -= 1;
+                visit(&self.nodes[hi]);
+            }
+            lo >>= 1;
+            hi >>= 1;
+        }
+    }
+}
+
+/// Append all pairs of `left_idx` and `right_idx` that match on one or two inequality predicates.
+fn range_search(
+    predicates: &[(Series, Series, JoinOperator)],
+    left_idx: &[IdxSize],
+    right_idx: &[IdxSize],
+    out_left: &mut Vec<IdxSize>,
+    out_right: &mut Vec<IdxSize>,
+) -> Result<()> {
+    let (l, r, op) = &predicates[0];
+    let (sorted, ranges) = sorted_ranges_series(l, r, *op, left_idx, right_idx)?;
+
+    match predicates.get(1) {
+        Some((l, r, op)) => {
+            let (sorted_second, ranges_second) =
+                sorted_ranges_series(l, r, *op, left_idx, right_idx)?;
+            // rows with a null value have no rank and never match
+            let mut ranks = vec![IdxSize::MAX; right_idx.len()];
+            for (rank, &pos) in sorted_second.iter().enumerate() {
+                ranks[pos] = rank as IdxSize;
+            }
+            let tree = MergeSortTree::new(
+                sorted
+                    .iter()
+                    .map(|&pos| (ranks[pos], pos as IdxSize))
+                    .collect(),
+            );
+            for ((&l, range), range_second) in left_idx.iter().zip(ranges).zip(ranges_second) {
+                if let (Some(range), Some(range_second)) = (range, range_second) {
+                    tree.query(range, range_second, |pos| {
+                        out_left.push(l);
+                        out_right.push(right_idx[pos]);
+                    });
+                }
+            }
+        }
+        None => {
+            for (&l, range) in left_idx.iter().zip(ranges) {
+                if let Some(range) = range {
+                    for &pos in &sorted[range] {
+                        out_left.push(l);
+                        out_right.push(right_idx[pos]);
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Group the rows of both tables on the equality keys.
+/// Returns the left and right row indexes of every group that has rows in both tables.
+fn equality_groups(
+    left: &DataFrame,
+    right: &DataFrame,
+    left_on: &[Series],
+    right_on: &[Series],
+) -> Result<Vec<(Vec<IdxSize>, Vec<IdxSize>)>> {
+    let n_left = left.height() as IdxSize;
+    if left_on.is_empty() {
+        return Ok(vec![(
+            (0..n_left).collect(),
+            (0..right.height() as IdxSize).collect(),
+        )]);
+    }
+
+    let keys = left_on
+        .iter()
+        .zip(right_on)
+        .map(|(l, r)| {
+            let st = get_supertype(l.dtype(), r.dtype())?;
+            let mut keys = l.cast(&st)?;
+            keys.append(&r.cast(&st)?)?;
+            Ok(keys)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let keys = DataFrame::new_no_checks(keys);
+    let groups = keys
+        .groupby_with_series(keys.get_columns().to_vec(), true, false)?
+        .take_groups();
+
+    let mut out = Vec::with_capacity(groups.len());
+    for group in groups.iter() {
+        let (first, all): (IdxSize, Vec<IdxSize>) = match group {
+            GroupsIndicator::Idx((first, all)) => (first, all.clone()),
+            GroupsIndicator::Slice([first, len]) => (first, (first..first + len).collect()),
+        };
+        // nulls don't match anything
+        if keys
+            .get_columns()
+            .iter()
+            .any(|s| matches!(s.get(first as usize), AnyValue::Null))
+        {
+            continue;
+        }
+        let (left_idx, right_idx): (Vec<IdxSize>, Vec<IdxSize>) =
+            all.iter().partition(|&&i| i < n_left);
+        if !left_idx.is_empty() && !right_idx.is_empty() {
+            out.push((
+                left_idx,
+                right_idx.into_iter().map(|i| i - n_left).collect(),
+            ));
+        }
+    }
+    Ok(out)
+}
+
+impl DataFrame {
+    /// Join on a conjunction of comparisons between the columns of both tables.
+    ///
+    /// Every row pair for which `left_on[i] operators[i] right_on[i]` holds for all `i` is
+    /// returned, ordered by the left rows. Equality comparisons may be on any type, the other
+    /// comparisons require numeric or temporal columns. Unlike an equi join, the right join
+    /// columns are kept in the output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let events = df!("ts" => &[1, 5, 12])?;
+    /// let sessions = df!("start" => &[0, 10], "end" => &[10, 20])?;
+    ///
+    /// // start <= ts < end
+    /// let out = events.join_where(
+    ///     &sessions,
+    ///     ["ts", "ts"],
+    ///     ["start", "end"],
+    ///     &[JoinOperator::GtEq, JoinOperator::Lt],
+    ///     None,
+    /// )?;
+    /// assert_eq!(out.shape(), (3, 3));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn join_where(
+        &self,
+        other: &DataFrame,
+        left_on: impl IntoVec<String>,
+        right_on: impl IntoVec<String>,
+        operators: &[JoinOperator],
+        suffix: Option<String>,
+    ) -> Result<DataFrame> {
+        let selected_left = self.select_series(left_on)?;
+        let selected_right = other.select_series(right_on)?;
+        self.join_where_from_series(other, &selected_left, &selected_right, operators, suffix)
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "private")))]
+    #[doc(hidden)]
+    pub fn join_where_from_series(
+        &self,
+        other: &DataFrame,
+        selected_left: &[Series],
+        selected_right: &[Series],
+        operators: &[JoinOperator],
+        suffix: Option<String>,
+    ) -> Result<DataFrame> {
+        if selected_left.len() != selected_right.len() || selected_left.len() != operators.len() {
+            return Err(PolarsError::ValueError(
+                "the number of left columns, right columns and operators should be equal".into(),
+            ));
+        }
+        if operators.is_empty() {
+            return Err(PolarsError::ValueError(
+                "join_where needs at least one predicate".into(),
+            ));
+        }
+
+        let mut eq_left = vec![];
+        let mut eq_right = vec![];
+        let mut inequalities = vec![];
+        for ((l, r), op) in selected_left.iter().zip(selected_right).zip(operators) {
+            if *op == JoinOperator::Eq {
+                eq_left.push(l.clone());
+                eq_right.push(r.clone());
+            } else {
+                let (l, r) = to_physical_pair(l, r)?;
+                inequalities.push((l, r, *op));
+            }
+        }
+
+        let groups = equality_groups(self, other, &eq_left, &eq_right)?;
+        let mut left_idx = vec![];
+        let mut right_idx = vec![];
+        // the first two inequality predicates bound the search
+        let n_searched = std::cmp::min(inequalities.len(), 2);
+        for (group_left, group_right) in &groups {
+            if n_searched > 0 {
+                range_search(
+                    &inequalities[..n_searched],
+                    group_left,
+                    group_right,
+                    &mut left_idx,
+                    &mut right_idx,
+                )?;
+            } else {
+                // only equality predicates, every pair in a group matches
+                for &l in group_left {
+                    for &r in group_right {
+                        left_idx.push(l);
+                        right_idx.push(r);
+                    }
+                }
+            }
+        }
+
+        let mut left_idx = IdxCa::from_vec("", left_idx);
+        let mut right_idx = IdxCa::from_vec("", right_idx);
+
+        // check the remaining predicates on the candidate pairs
+        if inequalities.len() > n_searched {
+            let mut mask: Option<BooleanChunked> = None;
+            for (l, r, op) in &inequalities[n_searched..] {
+                let l = l.take(&left_idx)?;
+                let r = r.take(&right_idx)?;
+                let m = match op {
+                    JoinOperator::Eq => unreachable!(),
+                    JoinOperator::Lt => l.lt(&r),
+                    JoinOperator::LtEq => l.lt_eq(&r),
+                    JoinOperator::Gt => l.gt(&r),
+                    JoinOperator::GtEq => l.gt_eq(&r),
+                };
+                mask = Some(match mask {
+                    Some(mask) => &mask & &m,
+                    None => m,
+                });
+            }
+            let mask = mask.unwrap();
+            left_idx = left_idx.filter(&mask)?;
+            right_idx = right_idx.filter(&mask)?;
+        }
+
+        // order by the left rows
+        let mut pairs = left_idx
+            .into_no_null_iter()
+            .zip(right_idx.into_no_null_iter())
+            .collect::<Vec<_>>();
+        pairs.sort_unstable();
+        let (left_idx, right_idx): (Vec<IdxSize>, Vec<IdxSize>) = pairs.into_iter().unzip();
+        let left_idx = IdxCa::from_vec("", left_idx);
+        let right_idx = IdxCa::from_vec("", right_idx);
+
+        // Safety:
+        // the indexes are in bounds
+        let (df_left, df_right) = unsafe {
+            (
+                self.take_unchecked(&left_idx),
+                other.take_unchecked(&right_idx),
+            )
+        };
+        self.finish_join(df_left, df_right, suffix)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_join_where() -> Result<()> {
+        let events = df![
+            "id" => ["a", "a", "b", "b", "c"],
+            "ts" => [1, 12, 5, 25, 3]
+        ]?;
+        let sessions = df![
+            "id" => ["a", "a", "b", "c"],
+            "start" => [0, 10, 0, 10],
+            "end" => [10, 20, 10, 20]
+        ]?;
+
+        let out = events.join_where(
+            &sessions,
+            ["id", "ts", "ts"],
+            ["id", "start", "end"],
+            &[JoinOperator::Eq, JoinOperator::GtEq, JoinOperator::Lt],
+            None,
+        )?;
+        let expected = df![
+            "id" => ["a", "a", "b"],
+            "ts" => [1, 12, 5],
+            "id_right" => ["a", "a", "b"],
+            "start" => [0, 10, 0],
+            "end" => [10, 20, 10]
+        ]?;
+        assert!(out.frame_equal(&expected));
+
+        let out = events.join_where(&sessions, ["ts"], ["start"], &[JoinOperator::Lt], None)?;
+        assert_eq!(out.height(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_where_bounds_both_predicates() -> Result<()> {
+        let left = df![
+            "a" => (0..60).map(|i| if i % 13 == 0 { None } else { Some((i * 7) % 50) }).collect::<Vec<_>>(),
+            "b" => (0..60).map(|i| (i % 9) as f64).collect::<Vec<_>>()
+        ]?;
+        let right = df![
+            "start" => (0..40).map(|i| if i % 11 == 0 { None } else { Some((i * 3) % 45) }).collect::<Vec<_>>(),
+            "end" => (0..40).map(|i| (i * 3) % 45 + (i % 7) + 1).collect::<Vec<i32>>(),
+            "c" => (0..40).map(|i| (i % 5) as f64).collect::<Vec<_>>()
+        ]?;
+        let out = left.join_where(
+            &right,
+            ["a", "a", "b"],
+            ["start", "end", "c"],
+            &[JoinOperator::GtEq, JoinOperator::Lt, JoinOperator::Gt],
+            None,
+        )?;
+
+        let a = left.column("a")?.i32()?;
+        let b = left.column("b")?.f64()?;
+        let start = right.column("start")?.i32()?;
+        let end = right.column("end")?.i32()?;
+        let c = right.column("c")?.f64()?;
+        let mut expected = vec![];
+        for l in 0..left.height() {
+            for r in 0..right.height() {
+                if let (Some(a), Some(start)) = (a.get(l), start.get(r)) {
+                    if a >= start && a < end.get(r).unwrap() && b.get(l) > c.get(r) {
+                        expected.push((a, start));
+                    }
+                }
+            }
+        }
+        let out = out
+            .column("a")?
+            .i32()?
+            .into_no_null_iter()
+            .zip(out.column("start")?.i32()?.into_no_null_iter())
+            .collect::<Vec<_>>();
+        assert_eq!(out, expected);
+        Ok(())
+    }
+}
@@ -5,6 +5,9 @@ use arrow::bitmap::Bitmap;
 use rayon::prelude::*;
 use std::fmt::{Debug, Formatter};
 
+mod typed;
+pub use typed::*;
+
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Row<'a>(pub Vec<AnyValue<'a>>);
 
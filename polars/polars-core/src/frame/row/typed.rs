@@ -0,0 +1,420 @@
+//! Conversion between `DataFrame`s and Rust structs. The conversion is columnar: the fields of
+//! the structs are collected in a `Vec` per column that is built into a `Series`, and the
+//! structs are read with an iterator per column.
+//!
+//! [`IntoDataFrame`] and [`FromDataFrame`] are implemented with the derive macros of the
+//! `polars-derive` crate.
+use crate::chunked_array::builder::get_list_builder;
+use crate::prelude::*;
+use arrow::array::{Array, BooleanArray, ListArray, PrimitiveArray, Utf8Array};
+#[cfg(feature = "dtype-date")]
+use arrow::temporal_conversions::date32_to_date;
+#[cfg(feature = "dtype-time")]
+use arrow::temporal_conversions::time64ns_to_time;
+#[cfg(feature = "dtype-datetime")]
+use arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
+#[cfg(feature = "dtype-date")]
+use chrono::NaiveDate;
+#[cfg(feature = "dtype-datetime")]
+use chrono::NaiveDateTime;
+#[cfg(feature = "dtype-time")]
+use chrono::NaiveTime;
+
+/// An iterator over the values of a column. It owns the (reference counted) arrays of the
+/// column, so it doesn't borrow the `DataFrame`.
+pub type ColumnIter<T> = Box<dyn Iterator<Item = T>>;
+
+/// A Rust type that can be stored in a column of a `DataFrame`.
+///
+/// `Option<T>` maps to a nullable column. Reading a column that has null values into a
+/// type that isn't an `Option` fails.
+pub trait ColumnValue: Sized + 'static {
+    /// The data type of the column.
+    fn dtype() -> DataType;
+
+    /// The data type a column of data type `dtype` is cast to before it is read. This is
+    /// [`ColumnValue::dtype`], unless the type can be read from other data types without
+    /// a loss, e.g. datetimes of any time unit.
+    fn read_dtype(_dtype: &DataType) -> DataType {
+        Self::dtype()
+    }
+
+    /// Build a `Series` from the values.
+    fn into_series(name: &str, values: Vec<Self>) -> Series;
+
+    /// Iterate over the values of a `Series`, which is cast to [`ColumnValue::read_dtype`]
+    /// first.
+    fn from_series(s: &Series) -> Result<ColumnIter<Self>>;
+}
+
+/// Conversion of a collection of structs to the columns of a `DataFrame`.
+pub trait IntoDataFrame: Sized {
+    /// Build a column per field. The names of the columns are prefixed with `prefix`, which
+    /// is used to flatten nested structs.
+    fn into_columns(rows: Vec<Self>, prefix: &str) -> Vec<Series>;
+
+    /// Create a `DataFrame` with a row per struct.
+    fn into_df(rows: Vec<Self>) -> Result<DataFrame> {
+        DataFrame::new(Self::into_columns(rows, ""))
+    }
+}
+
+/// Conversion of the rows of a `DataFrame` to structs.
+pub trait FromDataFrame: Sized {
+    /// Read the columns of the fields. The names of the columns are prefixed with `prefix`,
+    /// which is used to flatten nested structs.
+    fn from_columns(df: &DataFrame, prefix: &str) -> Result<Box<dyn Iterator<Item = Self>>>;
+}
+
+impl DataFrame {
+    /// Iterate over the rows of the `DataFrame` as structs that implement [`FromDataFrame`].
+    /// Every field is read from the arrays of its column, so the values don't go through
+    /// [`AnyValue`].
+    pub fn iter_as<T: FromDataFrame>(&self) -> Result<impl Iterator<Item = T>> {
+        T::from_columns(self, "")
+    }
+}
+
+fn null_error(s: &Series) -> PolarsError {
+    PolarsError::HasNullValues(
+        format!(
+            "column {} has null values, use an Option field to read it",
+            s.name()
+        )
+        .into(),
+    )
+}
+
+/// Cast `s` to the read data type of `T`.
+fn cast_for<T: ColumnValue>(s: &Series) -> Result<Series> {
+    s.cast(&T::read_dtype(s.dtype()))
+}
+
+/// Iterate over the (cheaply cloned) arrays of `chunks`, `get` is called with the valid
+/// indices.
+fn iter_arrays<A, V, F>(chunks: Vec<A>, get: F) -> ColumnIter<Option<V>>
+where
+    A: Array + 'static,
+    V: 'static,
+    F: Fn(&A, usize) -> V + Clone + 'static,
+{
+    Box::new(chunks.into_iter().flat_map(move |arr| {
+        let get = get.clone();
+        (0..arr.len()).map(move |i| {
+            if arr.is_valid(i) {
+                Some(get(&arr, i))
+            } else {
+                None
+            }
+        })
+    }))
+}
+
+fn iter_primitive<T, V, F>(ca: &ChunkedArray<T>, f: F) -> ColumnIter<Option<V>>
+where
+    T: PolarsNumericType,
+    V: 'static,
+    F: Fn(T::Native) -> V + Copy + 'static,
+{
+    let chunks = ca.downcast_iter().cloned().collect::<Vec<_>>();
+    iter_arrays(chunks, move |arr: &PrimitiveArray<T::Native>, i| {
+        f(arr.value(i))
+    })
+}
+
+/// Unwrap the values of a column that is read into a type that isn't an `Option`.
+fn no_nulls<V: 'static>(s: &Series, iter: ColumnIter<Option<V>>) -> Result<ColumnIter<V>> {
+    if s.null_count() > 0 {
+        return Err(null_error(s));
+    }
+    Ok(Box::new(iter.map(|opt_v| opt_v.unwrap())))
+}
+
+/// Implement [`ColumnValue`] for `$native` and `Option<$native>`. `$iter` converts the cast
+/// `Series` to an iterator of options.
+macro_rules! impl_column_value {
+    ($native:ty, $dtype:expr, $into_series:expr, $iter:expr) => {
+        impl_column_value!($native, $dtype, |_| $dtype, $into_series, $iter);
+    };
+    ($native:ty, $dtype:expr, $read_dtype:expr, $into_series:expr, $iter:expr) => {
+        impl ColumnValue for $native {
+            fn dtype() -> DataType {
+                $dtype
+            }
+
+            fn read_dtype(dtype: &DataType) -> DataType {
+                $read_dtype(dtype)
+            }
+
+            fn into_series(name: &str, values: Vec<Self>) -> Series {
+                $into_series(name, values.into_iter().map(Some).collect())
+            }
+
+            fn from_series(s: &Series) -> Result<ColumnIter<Self>> {
+                let s = cast_for::<Self>(s)?;
+                no_nulls(&s, $iter(&s)?)
+            }
+        }
+
+        impl ColumnValue for Option<$native> {
+            fn dtype() -> DataType {
+                $dtype
+            }
+
+            fn read_dtype(dtype: &DataType) -> DataType {
+                $read_dtype(dtype)
+            }
+
+            fn into_series(name: &str, values: Vec<Self>) -> Series {
+                $into_series(name, values)
+            }
+
+            fn from_series(s: &Series) -> Result<ColumnIter<Self>> {
+                let s = cast_for::<Self>(s)?;
+                $iter(&s)
+            }
+        }
+    };
+}
+
+macro_rules! impl_numeric_column_value {
+    ($native:ty, $dtype:expr, $getter:ident) => {
+        impl_column_value!(
+            $native,
+            $dtype,
+            |name: &str, values: Vec<Option<$native>>| Series::new(name, values),
+            |s: &Series| -> Result<_> { Ok(iter_primitive(s.$getter()?, |v| v)) }
+        );
+    };
+}
+
+#[cfg(feature = "dtype-u8")]
+impl_numeric_column_value!(u8, DataType::UInt8, u8);
+#[cfg(feature = "dtype-u16")]
+impl_numeric_column_value!(u16, DataType::UInt16, u16);
+impl_numeric_column_value!(u32, DataType::UInt32, u32);
+impl_numeric_column_value!(u64, DataType::UInt64, u64);
+#[cfg(feature = "dtype-i8")]
+impl_numeric_column_value!(i8, DataType::Int8, i8);
+#[cfg(feature = "dtype-i16")]
+impl_numeric_column_value!(i16, DataType::Int16, i16);
+impl_numeric_column_value!(i32, DataType::Int32, i32);
+impl_numeric_column_value!(i64, DataType::Int64, i64);
+impl_numeric_column_value!(f32, DataType::Float32, f32);
+impl_numeric_column_value!(f64, DataType::Float64, f64);
+
+impl_column_value!(
+    bool,
+    DataType::Boolean,
+    |name: &str, values: Vec<Option<bool>>| Series::new(name, values),
+    |s: &Series| -> Result<_> {
+        let chunks = s.bool()?.downcast_iter().cloned().collect::<Vec<_>>();
+        Ok(iter_arrays(chunks, |arr: &BooleanArray, i| arr.value(i)))
+    }
+);
+
+impl_column_value!(
+    String,
+    DataType::Utf8,
+    |name: &str, values: Vec<Option<String>>| Series::new(name, values),
+    |s: &Series| -> Result<_> {
+        let chunks = s.utf8()?.downcast_iter().cloned().collect::<Vec<_>>();
+        Ok(iter_arrays(chunks, |arr: &Utf8Array<i64>, i| {
+            arr.value(i).to_string()
+        }))
+    }
+);
+
+#[cfg(feature = "dtype-date")]
+impl_column_value!(
+    NaiveDate,
+    DataType::Date,
+    |name: &str, values: Vec<Option<NaiveDate>>| {
+        DateChunked::from_naive_date_options(name, values).into_series()
+    },
+    |s: &Series| -> Result<_> { Ok(iter_primitive(s.to_physical_repr().i32()?, date32_to_date,)) }
+);
+
+#[cfg(feature = "dtype-datetime")]
+impl_column_value!(
+    NaiveDateTime,
+    DataType::Datetime(TimeUnit::Milliseconds, None),
+    // datetimes are read in their own time unit, so we don't lose precision
+    |dtype: &DataType| match dtype {
+        DataType::Datetime(_, _) => dtype.clone(),
+        _ => DataType::Datetime(TimeUnit::Milliseconds, None),
+    },
+    |name: &str, values: Vec<Option<NaiveDateTime>>| {
+        DatetimeChunked::from_naive_datetime_options(name, values, TimeUnit::Milliseconds)
+            .into_series()
+    },
+    |s: &Series| -> Result<_> {
+        let func = match s.datetime()?.time_unit() {
+            TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+            TimeUnit::Microseconds => timestamp_us_to_datetime,
+            TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+        };
+        Ok(iter_primitive(s.to_physical_repr().i64()?, func))
+    }
+);
+
+#[cfg(feature = "dtype-time")]
+impl_column_value!(
+    NaiveTime,
+    DataType::Time,
+    |name: &str, values: Vec<Option<NaiveTime>>| {
+        TimeChunked::from_naive_time_options(name, values).into_series()
+    },
+    |s: &Series| -> Result<_> {
+        Ok(iter_primitive(
+            s.to_physical_repr().i64()?,
+            time64ns_to_time,
+        ))
+    }
+);
+
+fn list_into_series<T: ColumnValue>(name: &str, values: Vec<Option<Vec<T>>>) -> Series {
+    let dtype = T::dtype();
+    // the list builders only support flat lists
+    if let DataType::List(_) = dtype {
+        let mut ca: ListChunked = values
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| T::into_series("", v)))
+            .collect();
+        ca.rename(name);
+        return ca.into_series();
+    }
+
+    let mut builder = get_list_builder(&dtype, values.len() * 5, values.len(), name);
+    for opt_v in values {
+        match opt_v {
+            Some(v) => builder.append_series(&T::into_series("", v)),
+            None => builder.append_null(),
+        }
+    }
+    builder.finish().into_series()
+}
+
+fn list_read_dtype<T: ColumnValue>(dtype: &DataType) -> DataType {
+    let inner = match dtype {
+        DataType::List(inner) => T::read_dtype(inner),
+        _ => T::dtype(),
+    };
+    DataType::List(Box::new(inner))
+}
+
+/// Iterate over the sublists of a list column as `Vec`s.
+fn list_from_series<T: ColumnValue>(s: &Series) -> Result<ColumnIter<Option<Vec<T>>>> {
+    let s = s.cast(&list_read_dtype::<T>(s.dtype()))?;
+    let ca = s.list()?;
+    let inner_dtype = ca.inner_dtype().to_arrow();
+
+    // the values of all sublists of an array are read at once, the offsets split them
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| {
+            let offsets = arr.offsets().as_slice();
+            let first = offsets[0] as usize;
+            let values = arr
+                .values()
+                .slice(first, offsets[offsets.len() - 1] as usize - first);
+            // Safety: the values of the list array have the inner data type
+            let values =
+                unsafe { Series::try_from_unchecked("", vec![Arc::from(values)], &inner_dtype) }?;
+            Ok((arr.clone(), T::from_series(&values)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Box::new(chunks.into_iter().flat_map(
+        |(arr, mut values)| {
+            (0..arr.len()).map(move |i| {
+                let offsets = arr.offsets().as_slice();
+                let len = (offsets[i + 1] - offsets[i]) as usize;
+                // the values of a null sublist are skipped
+                let sublist = values.by_ref().take(len).collect::<Vec<_>>();
+                if arr.is_valid(i) {
+                    Some(sublist)
+                } else {
+                    None
+                }
+            })
+        },
+    )))
+}
+
+impl<T: ColumnValue> ColumnValue for Vec<T> {
+    fn dtype() -> DataType {
+        DataType::List(Box::new(T::dtype()))
+    }
+
+    fn read_dtype(dtype: &DataType) -> DataType {
+        list_read_dtype::<T>(dtype)
+    }
+
+    fn into_series(name: &str, values: Vec<Self>) -> Series {
+        list_into_series(name, values.into_iter().map(Some).collect())
+    }
+
+    fn from_series(s: &Series) -> Result<ColumnIter<Self>> {
+        no_nulls(s, list_from_series(s)?)
+    }
+}
+
+impl<T: ColumnValue> ColumnValue for Option<Vec<T>> {
+    fn dtype() -> DataType {
+        DataType::List(Box::new(T::dtype()))
+    }
+
+    fn read_dtype(dtype: &DataType) -> DataType {
+        list_read_dtype::<T>(dtype)
+    }
+
+    fn into_series(name: &str, values: Vec<Self>) -> Series {
+        list_into_series(name, values)
+    }
+
+    fn from_series(s: &Series) -> Result<ColumnIter<Self>> {
+        list_from_series(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_column_value_roundtrip() -> Result<()> {
+        let values = vec![Some(1i32), None, Some(3)];
+        let s = <Option<i32>>::into_series("a", values.clone());
+        assert_eq!(<Option<i32>>::from_series(&s)?.collect::<Vec<_>>(), values);
+        // a column with nulls can only be read into options
+        assert!(i32::from_series(&s).is_err());
+        // the column is cast to the type of the field
+        assert_eq!(
+            <Option<i64>>::from_series(&s)?.collect::<Vec<_>>(),
+            vec![Some(1i64), None, Some(3)]
+        );
+
+        let values = vec![vec!["a".to_string()], vec![], vec!["b".into(), "c".into()]];
+        let s = <Vec<String>>::into_series("b", values.clone());
+        assert_eq!(s.dtype(), &DataType::List(Box::new(DataType::Utf8)));
+        assert_eq!(<Vec<String>>::from_series(&s)?.collect::<Vec<_>>(), values);
+        // null items are checked before the sublists are read
+        let s = <Vec<Option<i32>>>::into_series("c", vec![vec![Some(1), None]]);
+        assert!(<Vec<i32>>::from_series(&s).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_column_value_datetime_unit() -> Result<()> {
+        let dt = chrono::NaiveDate::from_ymd(2021, 1, 1).and_hms_nano(1, 2, 3, 4);
+        let s =
+            DatetimeChunked::from_naive_datetime("a", [dt], TimeUnit::Nanoseconds).into_series();
+        // the datetimes are read in the time unit of the column
+        assert_eq!(NaiveDateTime::from_series(&s)?.collect::<Vec<_>>(), &[dt]);
+        Ok(())
+    }
+}
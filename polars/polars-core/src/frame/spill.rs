@@ -0,0 +1,197 @@
+//! Temporary storage of `DataFrame`s on disk for operations that don't fit in memory.
+//!
+//! The spilled `DataFrame`s are written as Arrow IPC files to a temporary directory that is
+//! removed when it is dropped. The directory is created in `POLARS_TEMP_DIR` if set and in the
+//! temporary directory of the system otherwise.
+use crate::config::Config;
+use crate::frame::ArrowChunk;
+use crate::prelude::*;
+use crate::utils::accumulate_dataframes_vertical;
+use arrow::io::ipc::write::WriteOptions;
+use arrow::io::ipc::{read, write};
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static SPILL_DIR_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Options of operations that spill to disk when they exceed their memory budget.
+#[derive(Clone, Debug)]
+pub struct SpillOptions {
+    /// Number of bytes that may be buffered in memory before data is spilled to disk.
+    pub memory_budget: usize,
+    /// Number of partitions used by partitioned operations, such as the spilled groupby.
+    pub n_partitions: usize,
+    /// Directory in which the temporary files are created.
+    pub spill_dir: Option<PathBuf>,
+}
+
+impl SpillOptions {
+    pub fn new(memory_budget: usize) -> Self {
+        SpillOptions {
+            memory_budget,
+            n_partitions: 64,
+            spill_dir: None,
+        }
+    }
+}
+
+/// A temporary directory with spilled `DataFrame`s.
+/// The directory and its files are removed on drop.
+pub struct SpillDir {
+    path: PathBuf,
+    n_files: usize,
+}
+
+impl SpillDir {
+    /// Create a new temporary directory in `base`. If `None`, the directory is created in
+    /// the `temp_dir` of the active [`Config`].
+    pub fn new(base: Option<&Path>) -> Result<Self> {
+        let base = match base {
+            Some(base) => base.to_path_buf(),
+            None => Config::current().temp_dir.clone(),
+        };
+        let path = base.join(format!(
+            "polars-spill-{}-{}",
+            std::process::id(),
+            SPILL_DIR_COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&path)?;
+        Ok(SpillDir { path, n_files: 0 })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write a `DataFrame` to a new IPC file in this directory.
+    pub fn spill(&mut self, df: &mut DataFrame) -> Result<SpillFile> {
+        let batch_size = df.height();
+        self.spill_batched(df, batch_size)
+    }
+
+    /// Write a `DataFrame` to a new IPC file in this directory in record batches of at most
+    /// `batch_size` rows, so that it can be read back in parts with [`SpillFile::batches`].
+    pub fn spill_batched(&mut self, df: &mut DataFrame, batch_size: usize) -> Result<SpillFile> {
+        let path = self.path.join(format!("{}.ipc", self.n_files));
+        self.n_files += 1;
+
+        let schema = df.schema();
+        let file = File::create(&path)?;
+        let mut writer = write::FileWriter::try_new(
+            file,
+            &schema.to_arrow(),
+            None,
+            WriteOptions { compression: None },
+        )?;
+        df.rechunk();
+        let batch_size = std::cmp::max(batch_size, 1);
+        let mut offset = 0;
+        while offset < df.height() {
+            for batch in df.slice(offset as i64, batch_size).iter_chunks() {
+                writer.write(&batch, None)?
+            }
+            offset += batch_size;
+        }
+        let _ = writer.finish()?;
+
+        Ok(SpillFile {
+            path,
+            schema,
+            height: df.height(),
+        })
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// A `DataFrame` that is spilled to disk.
+pub struct SpillFile {
+    path: PathBuf,
+    schema: Schema,
+    height: usize,
+}
+
+impl SpillFile {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Read the spilled `DataFrame` back into memory.
+    pub fn read(&self) -> Result<DataFrame> {
+        let dfs = self.batches()?.collect::<Result<Vec<_>>>()?;
+        if dfs.is_empty() {
+            let columns = self
+                .schema
+                .fields()
+                .iter()
+                .map(|fld| Series::full_null(fld.name(), 0, fld.data_type()))
+                .collect();
+            Ok(DataFrame::new_no_checks(columns))
+        } else {
+            accumulate_dataframes_vertical(dfs)
+        }
+    }
+
+    /// Iterate over the record batches of the spilled `DataFrame`.
+    pub fn batches(&self) -> Result<SpillFileReader> {
+        let mut file = BufReader::new(File::open(&self.path)?);
+        let metadata = read::read_file_metadata(&mut file)?;
+        let fields = metadata.schema.fields.clone();
+        Ok(SpillFileReader {
+            reader: read::FileReader::new(file, metadata, None),
+            fields,
+            schema: self.schema.clone(),
+        })
+    }
+}
+
+/// Reads the record batches of a [`SpillFile`] as `DataFrame`s.
+pub struct SpillFileReader {
+    reader: read::FileReader<BufReader<File>>,
+    fields: Vec<ArrowField>,
+    schema: Schema,
+}
+
+impl SpillFileReader {
+    fn to_df(&self, batch: ArrowChunk) -> Result<DataFrame> {
+        let df = DataFrame::try_from((batch, self.fields.as_slice()))?;
+        // the arrow round trip may change logical types, so we restore the original ones
+        let columns = df
+            .get_columns()
+            .iter()
+            .zip(self.schema.fields())
+            .map(|(s, fld)| {
+                if s.dtype() == fld.data_type() {
+                    Ok(s.clone())
+                } else {
+                    s.cast(fld.data_type())
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataFrame::new_no_checks(columns))
+    }
+}
+
+impl Iterator for SpillFileReader {
+    type Item = Result<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = self.reader.next()?;
+        Some(
+            batch
+                .map_err(PolarsError::from)
+                .and_then(|batch| self.to_df(batch)),
+        )
+    }
+}
@@ -1,12 +1,17 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #[macro_use]
 pub mod utils;
+pub mod cancel;
 pub mod chunked_array;
+pub mod config;
 pub mod datatypes;
 #[cfg(feature = "docs")]
 pub mod doc;
 pub mod error;
 pub mod export;
+#[cfg(feature = "ffi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
+pub mod ffi;
 mod fmt;
 pub mod frame;
 pub mod functions;
@@ -21,17 +26,15 @@ pub mod testing;
 mod tests;
 pub(crate) mod vector_hasher;
 
-#[cfg(any(feature = "dtype-categorical", feature = "object"))]
+#[cfg(feature = "object")]
 use std::time::{SystemTime, UNIX_EPOCH};
 
-#[cfg(feature = "dtype-categorical")]
-use ahash::AHashMap;
 use lazy_static::lazy_static;
 use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(test)]
 #[cfg(feature = "dtype-categorical")]
-use std::sync::atomic::{AtomicBool, Ordering};
-#[cfg(feature = "dtype-categorical")]
-use std::sync::{Mutex, MutexGuard};
+use std::sync::Mutex;
 
 #[cfg(feature = "object")]
 lazy_static! {
@@ -41,98 +44,164 @@ lazy_static! {
         .as_nanos();
 }
 
-// this is re-exported in utils for polars child crates
 lazy_static! {
-    pub static ref POOL: ThreadPool = ThreadPoolBuilder::new()
-        .num_threads(
-            std::env::var("POLARS_MAX_THREADS")
-                .map(|s| s.parse::<usize>().expect("integer"))
-                .unwrap_or_else(|_| num_cpus::get())
-        )
+    static ref GLOBAL_POOL: ThreadPool = ThreadPoolBuilder::new()
+        .num_threads(config::Config::current().max_threads)
         .build()
         .expect("could not spawn threads");
 }
 
-#[cfg(feature = "dtype-categorical")]
-struct SCacheInner {
-    map: AHashMap<String, u32>,
-    uuid: u128,
+/// Number of active [`with_thread_pool`] calls.
+static ACTIVE_INSTALLS: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts an install in [`ACTIVE_INSTALLS`] until it is dropped.
+struct InstalledPool;
+
+impl InstalledPool {
+    fn new() -> Self {
+        ACTIVE_INSTALLS.fetch_add(1, Ordering::AcqRel);
+        InstalledPool
+    }
 }
 
-#[cfg(feature = "dtype-categorical")]
-impl Default for SCacheInner {
-    fn default() -> Self {
-        Self {
-            map: Default::default(),
-            uuid: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_nanos(),
-        }
+impl Drop for InstalledPool {
+    fn drop(&mut self) {
+        ACTIVE_INSTALLS.fetch_sub(1, Ordering::AcqRel);
     }
 }
 
-/// Used by categorical data that need to share global categories.
-/// In *eager* you need to specifically toggle global string cache to have a global effect.
-/// In *lazy* it is toggled on at the start of a computation run and turned of (deleted) when a
-/// result is produced.
-#[cfg(feature = "dtype-categorical")]
-pub(crate) struct StringCache(pub(crate) Mutex<SCacheInner>);
+/// The thread pool that runs the parallel operations of polars.
+///
+/// Work runs in the global thread pool, sized by `POLARS_MAX_THREADS`, unless it is called from
+/// a thread pool that is installed with [`with_thread_pool`], in which case it runs in that
+/// pool. Other rayon thread pools don't change where the work runs. The workers of the pools
+/// are not tracked: while any pool is installed, work called from a worker of a rayon pool
+/// runs in that pool.
+///
+/// `POOL` is not a `ThreadPool` itself; it provides the methods of `ThreadPool` that polars
+/// uses, which run in the active thread pool.
+pub struct PolarsPool {
+    _private: (),
+}
 
-#[cfg(feature = "dtype-categorical")]
-impl StringCache {
-    pub(crate) fn lock_map(&self) -> MutexGuard<SCacheInner> {
-        self.0.lock().unwrap()
+pub static POOL: PolarsPool = PolarsPool { _private: () };
+
+impl PolarsPool {
+    /// Whether the current thread is a worker of the global pool or of an installed pool.
+    fn in_worker() -> bool {
+        if rayon::current_thread_index().is_none() {
+            return false;
+        }
+        GLOBAL_POOL.current_thread_index().is_some() || ACTIVE_INSTALLS.load(Ordering::Acquire) > 0
     }
 
-    pub(crate) fn clear(&self) {
-        let mut lock = self.lock_map();
-        *lock = Default::default();
+    /// Run `op` in the active thread pool.
+    pub fn install<OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        if Self::in_worker() {
+            op()
+        } else {
+            GLOBAL_POOL.install(op)
+        }
     }
-}
 
-#[cfg(feature = "dtype-categorical")]
-impl Default for StringCache {
-    fn default() -> Self {
-        StringCache(Mutex::new(Default::default()))
+    /// Run `oper_a` and `oper_b`, potentially in parallel, in the active thread pool.
+    pub fn join<A, B, RA, RB>(&self, oper_a: A, oper_b: B) -> (RA, RB)
+    where
+        A: FnOnce() -> RA + Send,
+        B: FnOnce() -> RB + Send,
+        RA: Send,
+        RB: Send,
+    {
+        if Self::in_worker() {
+            rayon::join(oper_a, oper_b)
+        } else {
+            GLOBAL_POOL.join(oper_a, oper_b)
+        }
     }
-}
 
-#[cfg(feature = "dtype-categorical")]
-pub(crate) static USE_STRING_CACHE: AtomicBool = AtomicBool::new(false);
-#[cfg(feature = "dtype-categorical")]
-lazy_static! {
-    pub(crate) static ref STRING_CACHE: StringCache = Default::default();
-}
+    /// Create a scope for spawning tasks in the active thread pool, see `ThreadPool::scope`.
+    pub fn scope<'scope, OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce(&rayon::Scope<'scope>) -> R + Send,
+        R: Send,
+    {
+        if Self::in_worker() {
+            rayon::scope(op)
+        } else {
+            GLOBAL_POOL.scope(op)
+        }
+    }
 
-#[cfg(test)]
-#[cfg(feature = "dtype-categorical")]
-lazy_static! {
-    // utility for the tests to ensure a single thread can execute
-    pub(crate) static ref SINGLE_LOCK: Mutex<()> = Mutex::new(());
+    /// Spawn a task in the active thread pool, see `ThreadPool::spawn`.
+    pub fn spawn<OP>(&self, op: OP)
+    where
+        OP: FnOnce() + Send + 'static,
+    {
+        if Self::in_worker() {
+            rayon::spawn(op)
+        } else {
+            GLOBAL_POOL.spawn(op)
+        }
+    }
+
+    /// Number of threads of the active thread pool.
+    pub fn current_num_threads(&self) -> usize {
+        if Self::in_worker() {
+            rayon::current_num_threads()
+        } else {
+            GLOBAL_POOL.current_num_threads()
+        }
+    }
 }
 
-/// Use a global string cache for the Categorical Types.
+/// Run `f` with `pool` as the thread pool of polars, instead of the global thread pool.
 ///
-/// This is used to cache the string categories locally.
-/// This allows join operations on categorical types.
-#[cfg(feature = "dtype-categorical")]
-pub fn toggle_string_cache(toggle: bool) {
-    USE_STRING_CACHE.store(toggle, Ordering::Release);
+/// The [`Config`](config::Config) of the calling thread stays active in `f`.
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::{with_thread_pool, POOL};
+///
+/// let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+/// let n_threads = with_thread_pool(&pool, || POOL.current_num_threads());
+/// assert_eq!(n_threads, 2);
+/// ```
+pub fn with_thread_pool<F, T>(pool: &ThreadPool, f: F) -> T
+where
+    F: FnOnce() -> T + Send,
+    T: Send,
+{
+    let config = config::Config::current();
+    let _installed = InstalledPool::new();
+    pool.install(|| config.scoped_arc(|_| f()))
+}
 
-    if !toggle {
-        STRING_CACHE.clear()
-    }
+/// Run `f` on a new thread pool with `n_threads` threads. See [`with_thread_pool`].
+pub fn with_n_threads<F, T>(n_threads: usize, f: F) -> error::Result<T>
+where
+    F: FnOnce() -> T + Send,
+    T: Send,
+{
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(n_threads)
+        .build()
+        .map_err(|e| error::PolarsError::ComputeError(e.to_string().into()))?;
+    Ok(with_thread_pool(&pool, f))
 }
 
-/// Reset the global string cache used for the Categorical Types.
+#[cfg(test)]
 #[cfg(feature = "dtype-categorical")]
-pub fn reset_string_cache() {
-    STRING_CACHE.clear()
+lazy_static! {
+    // utility for the tests to ensure a single thread can execute
+    pub(crate) static ref SINGLE_LOCK: Mutex<()> = Mutex::new(());
 }
 
-/// Check if string cache is set.
 #[cfg(feature = "dtype-categorical")]
-pub(crate) fn use_string_cache() -> bool {
-    USE_STRING_CACHE.load(Ordering::Acquire)
-}
+pub use chunked_array::categorical::string_cache::{
+    reset_string_cache, toggle_string_cache, StringCacheHolder,
+};
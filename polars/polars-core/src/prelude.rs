@@ -3,6 +3,7 @@ pub(crate) use crate::chunked_array::to_array;
 pub(crate) use crate::frame::groupby::aggregations::*;
 pub(crate) use crate::utils::CustomIterTools;
 pub use crate::{
+    cancel::CancellationToken,
     chunked_array::{
         arithmetic::Pow,
         builder::{
@@ -18,7 +19,12 @@ pub use crate::{
     datatypes::*,
     df,
     error::{PolarsError, Result},
-    frame::{groupby::GroupsProxy, hash_join::JoinType, *},
+    frame::{
+        explode::MeltArgs,
+        groupby::GroupsProxy,
+        hash_join::{JoinOperator, JoinType, JoinValidation},
+        *,
+    },
     named_from::{NamedFrom, NamedFromOwned},
     series::{
         arithmetic::{LhsNumOps, NumOpsDispatch},
@@ -41,6 +47,12 @@ pub use crate::chunked_array::temporal::conversion::*;
 #[cfg(feature = "checked_arithmetic")]
 pub use crate::series::arithmetic::checked::NumOpsDispatchChecked;
 
+#[cfg(feature = "list")]
+pub use crate::chunked_array::list::sets::SetOperation;
+
+#[cfg(feature = "dtype-categorical")]
+pub use crate::chunked_array::categorical::CategoricalOrdering;
+
 #[cfg(feature = "rank")]
 pub use crate::chunked_array::ops::unique::rank::{RankMethod, RankOptions};
 
@@ -2,6 +2,8 @@
 
 use super::Series;
 use crate::apply_method_numeric_series;
+#[cfg(feature = "dtype-categorical")]
+use crate::chunked_array::categorical::{lexical_order, lexical_rank};
 use crate::prelude::*;
 use crate::series::arithmetic::coerce_lhs_rhs;
 use std::ops::Deref;
@@ -40,10 +42,19 @@ macro_rules! impl_compare {
                 .$method(rhs.duration().unwrap().deref()),
             DataType::List(_) => lhs.list().unwrap().$method(rhs.list().unwrap()),
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical => lhs
-                .categorical()
-                .unwrap()
-                .$method(rhs.categorical().unwrap().deref()),
+            DataType::Categorical | DataType::Enum(_) => {
+                let lhs_ca = lhs.categorical().unwrap();
+                let rhs_ca = rhs.categorical().unwrap();
+                if lhs_ca.uses_lexical_ordering() {
+                    // compare the ranks of the categories instead of their strings
+                    let sorted = lexical_order(&[lhs_ca, rhs_ca]);
+                    lhs_ca
+                        .lexical_ranks(&sorted)
+                        .$method(&rhs_ca.lexical_ranks(&sorted))
+                } else {
+                    lhs_ca.$method(rhs_ca.deref())
+                }
+            }
 
             _ => unimplemented!(),
         }
@@ -72,6 +83,45 @@ where
     }
 }
 
+/// Compare a categorical to the first value of a `Utf8` `Series` with an ordered comparison.
+#[cfg(feature = "dtype-categorical")]
+fn compare_cat_to_str_scalar<Compare>(
+    cat: &Series,
+    string: &Series,
+    compare: Compare,
+) -> BooleanChunked
+where
+    Compare: Fn(&Series, &str) -> BooleanChunked,
+{
+    match string.utf8().expect("should be utf8 column").get(0) {
+        None => BooleanChunked::full_null(cat.name(), cat.len()),
+        Some(value) => compare(cat, value),
+    }
+}
+
+/// Compare a categorical to a string, according to the ordering of the categorical. With the
+/// physical ordering, values that are not a category compare as `false`.
+#[cfg(feature = "dtype-categorical")]
+fn compare_cat_to_str_ordered<Compare>(
+    cat: &Series,
+    value: &str,
+    compare: Compare,
+) -> BooleanChunked
+where
+    Compare: Fn(&Series, u32) -> BooleanChunked,
+{
+    let ca = cat.categorical().unwrap();
+    if ca.uses_lexical_ordering() {
+        let sorted = lexical_order(&[ca]);
+        compare(
+            &ca.lexical_ranks(&sorted).into_series(),
+            lexical_rank(&sorted, value),
+        )
+    } else {
+        compare_cat_to_str_value(cat, value, cat.name(), compare, false)
+    }
+}
+
 #[cfg(feature = "dtype-categorical")]
 fn compare_cat_to_str_series<Compare>(
     cat: &Series,
@@ -95,7 +145,7 @@ impl ChunkCompare<&Series> for Series {
         use DataType::*;
         match (self.dtype(), rhs.dtype(), self.len(), rhs.len()) {
             #[cfg(feature = "dtype-categorical")]
-            (Categorical, Utf8, _, 1) => {
+            (Categorical | Enum(_), Utf8, _, 1) => {
                 return compare_cat_to_str_series(
                     self,
                     rhs,
@@ -105,7 +155,7 @@ impl ChunkCompare<&Series> for Series {
                 );
             }
             #[cfg(feature = "dtype-categorical")]
-            (Utf8, Categorical, 1, _) => {
+            (Utf8, Categorical | Enum(_), 1, _) => {
                 return compare_cat_to_str_series(
                     rhs,
                     self,
@@ -126,7 +176,7 @@ impl ChunkCompare<&Series> for Series {
         use DataType::*;
         let mut out = match (self.dtype(), rhs.dtype(), self.len(), rhs.len()) {
             #[cfg(feature = "dtype-categorical")]
-            (Categorical, Utf8, _, 1) => {
+            (Categorical | Enum(_), Utf8, _, 1) => {
                 return compare_cat_to_str_series(
                     self,
                     rhs,
@@ -136,7 +186,7 @@ impl ChunkCompare<&Series> for Series {
                 );
             }
             #[cfg(feature = "dtype-categorical")]
-            (Utf8, Categorical, 1, _) => {
+            (Utf8, Categorical | Enum(_), 1, _) => {
                 return compare_cat_to_str_series(
                     rhs,
                     self,
@@ -159,7 +209,7 @@ impl ChunkCompare<&Series> for Series {
         use DataType::*;
         let mut out = match (self.dtype(), rhs.dtype(), self.len(), rhs.len()) {
             #[cfg(feature = "dtype-categorical")]
-            (Categorical, Utf8, _, 1) => {
+            (Categorical | Enum(_), Utf8, _, 1) => {
                 return compare_cat_to_str_series(
                     self,
                     rhs,
@@ -169,7 +219,7 @@ impl ChunkCompare<&Series> for Series {
                 );
             }
             #[cfg(feature = "dtype-categorical")]
-            (Utf8, Categorical, 1, _) => {
+            (Utf8, Categorical | Enum(_), 1, _) => {
                 return compare_cat_to_str_series(
                     rhs,
                     self,
@@ -188,28 +238,76 @@ impl ChunkCompare<&Series> for Series {
 
     /// Create a boolean mask by checking if self > rhs.
     fn gt(&self, rhs: &Series) -> BooleanChunked {
-        let mut out = impl_compare!(self, rhs, gt);
+        #[cfg(feature = "dtype-categorical")]
+        use DataType::*;
+        let mut out = match (self.dtype(), rhs.dtype(), self.len(), rhs.len()) {
+            #[cfg(feature = "dtype-categorical")]
+            (Categorical | Enum(_), Utf8, _, 1) => {
+                compare_cat_to_str_scalar(self, rhs, |s, value| s.gt(value))
+            }
+            #[cfg(feature = "dtype-categorical")]
+            (Utf8, Categorical | Enum(_), 1, _) => {
+                compare_cat_to_str_scalar(rhs, self, |s, value| s.lt(value))
+            }
+            _ => impl_compare!(self, rhs, gt),
+        };
         out.rename(self.name());
         out
     }
 
     /// Create a boolean mask by checking if self >= rhs.
     fn gt_eq(&self, rhs: &Series) -> BooleanChunked {
-        let mut out = impl_compare!(self, rhs, gt_eq);
+        #[cfg(feature = "dtype-categorical")]
+        use DataType::*;
+        let mut out = match (self.dtype(), rhs.dtype(), self.len(), rhs.len()) {
+            #[cfg(feature = "dtype-categorical")]
+            (Categorical | Enum(_), Utf8, _, 1) => {
+                compare_cat_to_str_scalar(self, rhs, |s, value| s.gt_eq(value))
+            }
+            #[cfg(feature = "dtype-categorical")]
+            (Utf8, Categorical | Enum(_), 1, _) => {
+                compare_cat_to_str_scalar(rhs, self, |s, value| s.lt_eq(value))
+            }
+            _ => impl_compare!(self, rhs, gt_eq),
+        };
         out.rename(self.name());
         out
     }
 
     /// Create a boolean mask by checking if self < rhs.
     fn lt(&self, rhs: &Series) -> BooleanChunked {
-        let mut out = impl_compare!(self, rhs, lt);
+        #[cfg(feature = "dtype-categorical")]
+        use DataType::*;
+        let mut out = match (self.dtype(), rhs.dtype(), self.len(), rhs.len()) {
+            #[cfg(feature = "dtype-categorical")]
+            (Categorical | Enum(_), Utf8, _, 1) => {
+                compare_cat_to_str_scalar(self, rhs, |s, value| s.lt(value))
+            }
+            #[cfg(feature = "dtype-categorical")]
+            (Utf8, Categorical | Enum(_), 1, _) => {
+                compare_cat_to_str_scalar(rhs, self, |s, value| s.gt(value))
+            }
+            _ => impl_compare!(self, rhs, lt),
+        };
         out.rename(self.name());
         out
     }
 
     /// Create a boolean mask by checking if self <= rhs.
     fn lt_eq(&self, rhs: &Series) -> BooleanChunked {
-        let mut out = impl_compare!(self, rhs, lt_eq);
+        #[cfg(feature = "dtype-categorical")]
+        use DataType::*;
+        let mut out = match (self.dtype(), rhs.dtype(), self.len(), rhs.len()) {
+            #[cfg(feature = "dtype-categorical")]
+            (Categorical | Enum(_), Utf8, _, 1) => {
+                compare_cat_to_str_scalar(self, rhs, |s, value| s.lt_eq(value))
+            }
+            #[cfg(feature = "dtype-categorical")]
+            (Utf8, Categorical | Enum(_), 1, _) => {
+                compare_cat_to_str_scalar(rhs, self, |s, value| s.gt_eq(value))
+            }
+            _ => impl_compare!(self, rhs, lt_eq),
+        };
         out.rename(self.name());
         out
     }
@@ -258,7 +356,7 @@ impl ChunkCompare<&str> for Series {
         match self.dtype() {
             Utf8 => self.utf8().unwrap().equal(rhs),
             #[cfg(feature = "dtype-categorical")]
-            Categorical => {
+            Categorical | Enum(_) => {
                 compare_cat_to_str_value(self, rhs, self.name(), |lhs, idx| lhs.equal(idx), false)
             }
             _ => BooleanChunked::full(self.name(), false, self.len()),
@@ -270,7 +368,7 @@ impl ChunkCompare<&str> for Series {
         match self.dtype() {
             Utf8 => self.utf8().unwrap().not_equal(rhs),
             #[cfg(feature = "dtype-categorical")]
-            Categorical => compare_cat_to_str_value(
+            Categorical | Enum(_) => compare_cat_to_str_value(
                 self,
                 rhs,
                 self.name(),
@@ -282,34 +380,46 @@ impl ChunkCompare<&str> for Series {
     }
 
     fn gt(&self, rhs: &str) -> BooleanChunked {
-        if let Ok(a) = self.utf8() {
-            a.gt(rhs)
-        } else {
-            BooleanChunked::full(self.name(), false, self.len())
+        match self.dtype() {
+            DataType::Utf8 => self.utf8().unwrap().gt(rhs),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical | DataType::Enum(_) => {
+                compare_cat_to_str_ordered(self, rhs, |lhs, idx| lhs.gt(idx))
+            }
+            _ => BooleanChunked::full(self.name(), false, self.len()),
         }
     }
 
     fn gt_eq(&self, rhs: &str) -> BooleanChunked {
-        if let Ok(a) = self.utf8() {
-            a.gt_eq(rhs)
-        } else {
-            BooleanChunked::full(self.name(), false, self.len())
+        match self.dtype() {
+            DataType::Utf8 => self.utf8().unwrap().gt_eq(rhs),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical | DataType::Enum(_) => {
+                compare_cat_to_str_ordered(self, rhs, |lhs, idx| lhs.gt_eq(idx))
+            }
+            _ => BooleanChunked::full(self.name(), false, self.len()),
         }
     }
 
     fn lt(&self, rhs: &str) -> BooleanChunked {
-        if let Ok(a) = self.utf8() {
-            a.lt(rhs)
-        } else {
-            BooleanChunked::full(self.name(), false, self.len())
+        match self.dtype() {
+            DataType::Utf8 => self.utf8().unwrap().lt(rhs),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical | DataType::Enum(_) => {
+                compare_cat_to_str_ordered(self, rhs, |lhs, idx| lhs.lt(idx))
+            }
+            _ => BooleanChunked::full(self.name(), false, self.len()),
         }
     }
 
     fn lt_eq(&self, rhs: &str) -> BooleanChunked {
-        if let Ok(a) = self.utf8() {
-            a.lt_eq(rhs)
-        } else {
-            BooleanChunked::full(self.name(), false, self.len())
+        match self.dtype() {
+            DataType::Utf8 => self.utf8().unwrap().lt_eq(rhs),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical | DataType::Enum(_) => {
+                compare_cat_to_str_ordered(self, rhs, |lhs, idx| lhs.lt_eq(idx))
+            }
+            _ => BooleanChunked::full(self.name(), false, self.len()),
         }
     }
 }
@@ -384,6 +384,38 @@ impl TryFrom<(&str, ArrayRef)> for Series {
     }
 }
 
+impl TryFrom<(&ArrowField, ArrayRef)> for Series {
+    type Error = PolarsError;
+
+    /// Create a `Series` with the dtype of the `field`. Unlike the arrow dtype of the array,
+    /// the field also knows the categories of an `Enum`.
+    fn try_from(field_arr: (&ArrowField, ArrayRef)) -> Result<Self> {
+        let (field, arr) = field_arr;
+        match Field::from(field).data_type() {
+            #[cfg(feature = "dtype-categorical")]
+            dt @ DataType::Enum(categories) => {
+                // the codes are the indexes of the categories if the dictionary holds them in
+                // order, which is how an `Enum` is written
+                if let Some(dict) = arr.as_any().downcast_ref::<DictionaryArray<u32>>() {
+                    let values = dict.values().as_any().downcast_ref::<Utf8Array<i64>>();
+                    if values.map_or(false, |values| {
+                        values.len() == categories.len()
+                            && values
+                                .values_iter()
+                                .zip(categories.iter())
+                                .all(|(v, c)| v == c)
+                    }) {
+                        let keys: ArrayRef = Arc::new(dict.keys().clone());
+                        return Series::try_from((field.name.as_str(), keys))?.cast(dt);
+                    }
+                }
+                Series::try_from((field.name.as_str(), arr))?.cast(dt)
+            }
+            _ => Series::try_from((field.name.as_str(), arr)),
+        }
+    }
+}
+
 impl TryFrom<(&str, Box<dyn Array>)> for Series {
     type Error = PolarsError;
 
@@ -3,6 +3,7 @@ use super::IntoSeries;
 use super::SeriesTrait;
 use super::*;
 use crate::chunked_array::comparison::*;
+use crate::chunked_array::ops::estimated_size::SizeEstimator;
 use crate::chunked_array::{
     ops::{
         compare_inner::{IntoPartialEqInner, IntoPartialOrdInner, PartialEqInner, PartialOrdInner},
@@ -23,6 +24,19 @@ use arrow::array::ArrayRef;
 use polars_arrow::prelude::QuantileInterpolOptions;
 use std::borrow::Cow;
 
+impl SeriesWrap<CategoricalChunked> {
+    fn min_max_as_series(&self, max: bool) -> Series {
+        match self.0.arg_min_max(max) {
+            Some(idx) => self.0.slice(idx as i64, 1).into_series(),
+            None => {
+                let mut out = CategoricalChunked::full_null(self.name(), 1);
+                out.copy_cat_state(&self.0);
+                out.into_series()
+            }
+        }
+    }
+}
+
 impl IntoSeries for CategoricalChunked {
     fn into_series(self) -> Series {
         Series(Arc::new(SeriesWrap(self)))
@@ -30,11 +44,18 @@ impl IntoSeries for CategoricalChunked {
 }
 
 impl private::PrivateSeries for SeriesWrap<CategoricalChunked> {
+    fn _add_to_size_estimate(&self, estimator: &mut SizeEstimator) {
+        self.0.add_to_size_estimate(estimator)
+    }
+
     fn _field(&self) -> Cow<Field> {
-        Cow::Borrowed(self.0.ref_field())
+        match self.0.logical_dtype() {
+            DataType::Categorical => Cow::Borrowed(self.0.ref_field()),
+            dtype => Cow::Owned(Field::new(self.0.name(), dtype.clone())),
+        }
     }
     fn _dtype(&self) -> &DataType {
-        self.0.ref_field().data_type()
+        self.0.logical_dtype()
     }
 
     fn explode_by_offsets(&self, offsets: &[i64]) -> Series {
@@ -72,19 +93,31 @@ impl private::PrivateSeries for SeriesWrap<CategoricalChunked> {
         self.0.agg_list(groups)
     }
 
-    fn hash_join_inner(&self, other: &Series) -> Vec<(u32, u32)> {
-        HashJoin::hash_join_inner(&self.0, other.as_ref().as_ref())
+    fn hash_join_inner(
+        &self,
+        other: &Series,
+        validate: JoinValidation,
+    ) -> Result<Vec<(IdxSize, IdxSize)>> {
+        HashJoin::hash_join_inner(&self.0, other.as_ref().as_ref(), validate)
     }
-    fn hash_join_left(&self, other: &Series) -> Vec<(u32, Option<u32>)> {
-        HashJoin::hash_join_left(&self.0, other.as_ref().as_ref())
+    fn hash_join_left(
+        &self,
+        other: &Series,
+        validate: JoinValidation,
+    ) -> Result<Vec<(IdxSize, Option<IdxSize>)>> {
+        HashJoin::hash_join_left(&self.0, other.as_ref().as_ref(), validate)
     }
-    fn hash_join_outer(&self, other: &Series) -> Vec<(Option<u32>, Option<u32>)> {
-        HashJoin::hash_join_outer(&self.0, other.as_ref().as_ref())
+    fn hash_join_outer(
+        &self,
+        other: &Series,
+        validate: JoinValidation,
+    ) -> Result<Vec<(Option<IdxSize>, Option<IdxSize>)>> {
+        HashJoin::hash_join_outer(&self.0, other.as_ref().as_ref(), validate)
     }
     fn zip_outer_join_column(
         &self,
         right_column: &Series,
-        opt_join_tuples: &[(Option<u32>, Option<u32>)],
+        opt_join_tuples: &[(Option<IdxSize>, Option<IdxSize>)],
     ) -> Series {
         let categorical_map_out = Some(
             self.0
@@ -106,8 +139,15 @@ impl private::PrivateSeries for SeriesWrap<CategoricalChunked> {
     }
 
     #[cfg(feature = "sort_multiple")]
-    fn argsort_multiple(&self, by: &[Series], reverse: &[bool]) -> Result<UInt32Chunked> {
-        self.0.argsort_multiple(by, reverse)
+    fn argsort_multiple(&self, by: &[Series], reverse: &[bool]) -> Result<IdxCa> {
+        if self.0.uses_lexical_ordering() {
+            self.0
+                .cast(&DataType::Utf8)?
+                .utf8()?
+                .argsort_multiple(by, reverse)
+        } else {
+            self.0.argsort_multiple(by, reverse)
+        }
     }
 }
 
@@ -146,7 +186,7 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
     }
 
     fn categorical(&self) -> Result<&CategoricalChunked> {
-        if matches!(self.0.dtype(), DataType::Categorical) {
+        if matches!(self.dtype(), DataType::Categorical | DataType::Enum(_)) {
             unsafe { Ok(&*(self as *const dyn SeriesTrait as *const CategoricalChunked)) }
         } else {
             Err(PolarsError::SchemaMisMatch(
@@ -169,7 +209,7 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
     }
 
     fn append(&mut self, other: &Series) -> Result<()> {
-        if self.0.dtype() == other.dtype() {
+        if self.dtype() == other.dtype() {
             let other = other.categorical()?;
             self.0.append(other);
             self.0.categorical_map = Some(self.0.merge_categorical_map(other));
@@ -181,7 +221,7 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         }
     }
     fn extend(&mut self, other: &Series) -> Result<()> {
-        if self.0.dtype() == other.dtype() {
+        if self.dtype() == other.dtype() {
             let other = other.categorical()?;
             self.0.extend(other);
             self.0.categorical_map = Some(self.0.merge_categorical_map(other));
@@ -197,7 +237,7 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         ChunkFilter::filter(&self.0, filter).map(|ca| ca.into_series())
     }
 
-    fn take(&self, indices: &UInt32Chunked) -> Result<Series> {
+    fn take(&self, indices: &IdxCa) -> Result<Series> {
         let indices = if indices.chunks.len() > 1 {
             Cow::Owned(indices.rechunk())
         } else {
@@ -218,7 +258,7 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         ChunkTake::take_unchecked(&self.0, iter.into()).into_series()
     }
 
-    unsafe fn take_unchecked(&self, idx: &UInt32Chunked) -> Result<Series> {
+    unsafe fn take_unchecked(&self, idx: &IdxCa) -> Result<Series> {
         let idx = if idx.chunks.len() > 1 {
             Cow::Owned(idx.rechunk())
         } else {
@@ -269,7 +309,7 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         ChunkSort::sort_with(&self.0, options).into_series()
     }
 
-    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+    fn argsort(&self, reverse: bool) -> IdxCa {
         ChunkSort::argsort(&self.0, reverse)
     }
 
@@ -289,7 +329,7 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         ChunkUnique::n_unique(&self.0)
     }
 
-    fn arg_unique(&self) -> Result<UInt32Chunked> {
+    fn arg_unique(&self) -> Result<IdxCa> {
         ChunkUnique::arg_unique(&self.0)
     }
 
@@ -329,10 +369,10 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         CategoricalChunked::full_null(self.name(), 1).into_series()
     }
     fn max_as_series(&self) -> Series {
-        CategoricalChunked::full_null(self.name(), 1).into_series()
+        self.min_max_as_series(true)
     }
     fn min_as_series(&self) -> Series {
-        CategoricalChunked::full_null(self.name(), 1).into_series()
+        self.min_max_as_series(false)
     }
     fn median_as_series(&self) -> Series {
         CategoricalChunked::full_null(self.name(), 1).into_series()
@@ -3,6 +3,7 @@ use super::IntoSeries;
 use super::SeriesTrait;
 use super::SeriesWrap;
 use super::*;
+use crate::chunked_array::ops::estimated_size::SizeEstimator;
 use crate::chunked_array::{
     comparison::*, ops::explode::ExplodeByOffsets, AsSinglePtr, ChunkIdIter,
 };
@@ -29,6 +30,10 @@ impl private::PrivateSeriesNumeric for SeriesWrap<DurationChunked> {
 }
 
 impl private::PrivateSeries for SeriesWrap<DurationChunked> {
+    fn _add_to_size_estimate(&self, estimator: &mut SizeEstimator) {
+        self.0.add_to_size_estimate(estimator)
+    }
+
     fn _field(&self) -> Cow<Field> {
         Cow::Owned(self.0.field())
     }
@@ -60,7 +65,7 @@ impl private::PrivateSeries for SeriesWrap<DurationChunked> {
     }
 
     #[cfg(feature = "asof_join")]
-    fn join_asof(&self, other: &Series) -> Result<Vec<Option<u32>>> {
+    fn join_asof(&self, other: &Series) -> Result<Vec<Option<IdxSize>>> {
         let other = other.to_physical_repr();
         self.0.deref().join_asof(&other)
     }
@@ -146,22 +151,34 @@ impl private::PrivateSeries for SeriesWrap<DurationChunked> {
             .map(|s| s.into_duration(self.0.time_unit()).into_series())
     }
 
-    fn hash_join_inner(&self, other: &Series) -> Vec<(u32, u32)> {
+    fn hash_join_inner(
+        &self,
+        other: &Series,
+        validate: JoinValidation,
+    ) -> Result<Vec<(IdxSize, IdxSize)>> {
         let other = other.to_physical_repr().into_owned();
-        self.0.hash_join_inner(other.as_ref().as_ref())
+        self.0.hash_join_inner(other.as_ref().as_ref(), validate)
     }
-    fn hash_join_left(&self, other: &Series) -> Vec<(u32, Option<u32>)> {
+    fn hash_join_left(
+        &self,
+        other: &Series,
+        validate: JoinValidation,
+    ) -> Result<Vec<(IdxSize, Option<IdxSize>)>> {
         let other = other.to_physical_repr().into_owned();
-        self.0.hash_join_left(other.as_ref().as_ref())
+        self.0.hash_join_left(other.as_ref().as_ref(), validate)
     }
-    fn hash_join_outer(&self, other: &Series) -> Vec<(Option<u32>, Option<u32>)> {
+    fn hash_join_outer(
+        &self,
+        other: &Series,
+        validate: JoinValidation,
+    ) -> Result<Vec<(Option<IdxSize>, Option<IdxSize>)>> {
         let other = other.to_physical_repr().into_owned();
-        self.0.hash_join_outer(other.as_ref().as_ref())
+        self.0.hash_join_outer(other.as_ref().as_ref(), validate)
     }
     fn zip_outer_join_column(
         &self,
         right_column: &Series,
-        opt_join_tuples: &[(Option<u32>, Option<u32>)],
+        opt_join_tuples: &[(Option<IdxSize>, Option<IdxSize>)],
     ) -> Series {
         let right_column = right_column.to_physical_repr().into_owned();
         self.0
@@ -231,7 +248,7 @@ impl private::PrivateSeries for SeriesWrap<DurationChunked> {
         self.0.group_tuples(multithreaded, sorted)
     }
     #[cfg(feature = "sort_multiple")]
-    fn argsort_multiple(&self, by: &[Series], reverse: &[bool]) -> Result<UInt32Chunked> {
+    fn argsort_multiple(&self, by: &[Series], reverse: &[bool]) -> Result<IdxCa> {
         self.0.deref().argsort_multiple(by, reverse)
     }
 }
@@ -327,7 +344,7 @@ impl SeriesTrait for SeriesWrap<DurationChunked> {
             .map(|ca| ca.into_duration(self.0.time_unit()).into_series())
     }
 
-    fn take(&self, indices: &UInt32Chunked) -> Result<Series> {
+    fn take(&self, indices: &IdxCa) -> Result<Series> {
         ChunkTake::take(self.0.deref(), indices.into())
             .map(|ca| ca.into_duration(self.0.time_unit()).into_series())
     }
@@ -350,7 +367,7 @@ impl SeriesTrait for SeriesWrap<DurationChunked> {
             .into_series()
     }
 
-    unsafe fn take_unchecked(&self, idx: &UInt32Chunked) -> Result<Series> {
+    unsafe fn take_unchecked(&self, idx: &IdxCa) -> Result<Series> {
         Ok(ChunkTake::take_unchecked(self.0.deref(), idx.into())
             .into_duration(self.0.time_unit())
             .into_series())
@@ -416,7 +433,7 @@ impl SeriesTrait for SeriesWrap<DurationChunked> {
             .into_series()
     }
 
-    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+    fn argsort(&self, reverse: bool) -> IdxCa {
         self.0.argsort(reverse)
     }
 
@@ -438,7 +455,7 @@ impl SeriesTrait for SeriesWrap<DurationChunked> {
         self.0.n_unique()
     }
 
-    fn arg_unique(&self) -> Result<UInt32Chunked> {
+    fn arg_unique(&self) -> Result<IdxCa> {
         self.0.arg_unique()
     }
 
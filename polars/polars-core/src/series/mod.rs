@@ -15,6 +15,7 @@ mod series_trait;
 #[cfg(feature = "private")]
 pub mod unstable;
 
+use crate::chunked_array::ops::estimated_size::SizeEstimator;
 use crate::chunked_array::ops::rolling_window::RollingOptions;
 #[cfg(feature = "rank")]
 use crate::prelude::unique::rank::rank;
@@ -211,6 +212,14 @@ impl Series {
         self.0.cast(dtype)
     }
 
+    /// Estimate the number of bytes that are held by the buffers of this `Series`. See
+    /// [`ChunkEstimatedSize::estimated_size`] for what is counted.
+    pub fn estimated_size(&self) -> usize {
+        let mut estimator = SizeEstimator::default();
+        self.0._add_to_size_estimate(&mut estimator);
+        estimator.finish()
+    }
+
     /// Compute the sum of all values in this Series.
     /// Returns `None` if the array is empty or only contains null values.
     ///
@@ -338,20 +347,32 @@ impl Series {
         lhs.zip_with_same_type(mask, rhs.as_ref())
     }
 
+    /// Unpack to ChunkedArray of the row index type, see [`IdxSize`].
+    pub fn idx(&self) -> Result<&IdxCa> {
+        #[cfg(not(feature = "bigidx"))]
+        {
+            self.u32()
+        }
+        #[cfg(feature = "bigidx")]
+        {
+            self.u64()
+        }
+    }
+
     /// Cast a datelike Series to their physical representation.
     /// Primitives remain unchanged
     ///
     /// * Date -> Int32
     /// * Datetime-> Int64
     /// * Time -> Int64
-    /// * Categorical -> UInt32
+    /// * Categorical, Enum -> UInt32
     ///
     pub fn to_physical_repr(&self) -> Cow<Series> {
         use DataType::*;
         match self.dtype() {
             Date => Cow::Owned(self.cast(&DataType::Int32).unwrap()),
             Datetime(_, _) | Duration(_) | Time => Cow::Owned(self.cast(&DataType::Int64).unwrap()),
-            Categorical => Cow::Owned(self.cast(&DataType::UInt32).unwrap()),
+            Categorical | Enum(_) => Cow::Owned(self.cast(&DataType::UInt32).unwrap()),
             _ => Cow::Borrowed(self),
         }
     }
@@ -360,11 +381,7 @@ impl Series {
     ///
     /// # Safety
     /// This doesn't check any bounds. Null validity is checked.
-    pub unsafe fn take_unchecked_threaded(
-        &self,
-        idx: &UInt32Chunked,
-        rechunk: bool,
-    ) -> Result<Series> {
+    pub unsafe fn take_unchecked_threaded(&self, idx: &IdxCa, rechunk: bool) -> Result<Series> {
         let n_threads = POOL.current_num_threads();
         let idx = split_ca(idx, n_threads)?;
 
@@ -390,7 +407,7 @@ impl Series {
     /// # Safety
     ///
     /// Out of bounds access doesn't Error but will return a Null value
-    pub fn take_threaded(&self, idx: &UInt32Chunked, rechunk: bool) -> Result<Series> {
+    pub fn take_threaded(&self, idx: &IdxCa, rechunk: bool) -> Result<Series> {
         let n_threads = POOL.current_num_threads();
         let idx = split_ca(idx, n_threads).unwrap();
 
@@ -875,6 +892,7 @@ impl Series {
             DataType::Utf8
             | DataType::List(_)
             | DataType::Categorical
+            | DataType::Enum(_)
             | DataType::Date
             | DataType::Datetime(_, _)
             | DataType::Duration(_)
@@ -886,6 +904,38 @@ impl Series {
         }
     }
 
+    /// Keys on which numeric, temporal, categorical and boolean values are hashed: their
+    /// physical bit representation widened to `u64`.
+    pub(crate) fn bit_keys(&self) -> Result<Vec<Option<u64>>> {
+        let s = match self.dtype() {
+            DataType::Boolean => self.cast(&DataType::UInt32)?,
+            DataType::Utf8 | DataType::List(_) | DataType::Null => {
+                return Err(PolarsError::InvalidOperation(
+                    format!(
+                        "cannot hash values of dtype {:?} on their bits",
+                        self.dtype()
+                    )
+                    .into(),
+                ))
+            }
+            #[cfg(feature = "object")]
+            DataType::Object(_) => {
+                return Err(PolarsError::InvalidOperation(
+                    "cannot hash object values on their bits".into(),
+                ))
+            }
+            _ => self.to_physical_repr().into_owned(),
+        };
+        Ok(if s.bit_repr_is_large() {
+            s.bit_repr_large().into_iter().collect()
+        } else {
+            s.bit_repr_small()
+                .into_iter()
+                .map(|opt_v| opt_v.map(|v| v as u64))
+                .collect()
+        })
+    }
+
     #[cfg(feature = "abs")]
     #[cfg_attr(docsrs, doc(cfg(feature = "abs")))]
     /// convert numerical values to their absolute value
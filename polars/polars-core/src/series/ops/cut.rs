@@ -0,0 +1,302 @@
+//! Binning of numeric values with `cut`, `qcut` and `hist`.
+//!
+//! The breaks are sorted, so the bin of a value is found with a binary search on the breaks.
+use crate::prelude::*;
+
+fn check_breaks(breaks: &[f64]) -> Result<()> {
+    if breaks.windows(2).any(|w| w[0] >= w[1]) || breaks.iter().any(|b| b.is_nan()) {
+        return Err(PolarsError::ValueError(
+            "breaks should be strictly increasing".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn fmt_bound(b: f64) -> String {
+    if b.is_infinite() {
+        if b > 0.0 { "inf" } else { "-inf" }.to_string()
+    } else {
+        format!("{}", b)
+    }
+}
+
+fn default_labels(breaks: &[f64], left_closed: bool) -> Vec<String> {
+    let mut bounds = Vec::with_capacity(breaks.len() + 2);
+    bounds.push(f64::NEG_INFINITY);
+    bounds.extend_from_slice(breaks);
+    bounds.push(f64::INFINITY);
+    bounds
+        .windows(2)
+        .map(|w| {
+            let (lower, upper) = (fmt_bound(w[0]), fmt_bound(w[1]));
+            if left_closed {
+                format!("[{}, {})", lower, upper)
+            } else {
+                format!("({}, {}]", lower, upper)
+            }
+        })
+        .collect()
+}
+
+/// Index of the bin of every value given the sorted `breaks`, `None` for null and `NaN`
+/// values. Bin `i` lies between `breaks[i - 1]` and `breaks[i]`.
+fn bin_indices(ca: &Float64Chunked, breaks: &[f64], left_closed: bool) -> Vec<Option<usize>> {
+    ca.into_iter()
+        .map(|opt_v| {
+            opt_v.filter(|v| !v.is_nan()).map(|v| {
+                if left_closed {
+                    breaks.partition_point(|b| *b <= v)
+                } else {
+                    breaks.partition_point(|b| *b < v)
+                }
+            })
+        })
+        .collect()
+}
+
+impl Series {
+    /// Assign the values to the bins between `breaks`. The output is a `Categorical` with the
+    /// label of the bin of every value.
+    ///
+    /// * `breaks` - the strictly increasing bin edges. Together with `-inf` and `inf` they
+    ///   define `breaks.len() + 1` bins.
+    /// * `labels` - a label for every bin. Defaults to the interval notation of the bins,
+    ///   e.g. `"(1, 5]"`.
+    /// * `left_closed` - the bins include their lower edge instead of their upper edge.
+    ///
+    /// Null and `NaN` values have a null bin.
+    #[cfg_attr(docsrs, doc(cfg(feature = "cut")))]
+    pub fn cut(
+        &self,
+        breaks: &[f64],
+        labels: Option<&[String]>,
+        left_closed: bool,
+    ) -> Result<Series> {
+        check_breaks(breaks)?;
+        let labels = match labels {
+            Some(labels) => {
+                if labels.len() != breaks.len() + 1 {
+                    return Err(PolarsError::ShapeMisMatch(
+                        format!(
+                            "expected {} labels for {} breaks, got {}",
+                            breaks.len() + 1,
+                            breaks.len(),
+                            labels.len()
+                        )
+                        .into(),
+                    ));
+                }
+                labels.to_vec()
+            }
+            None => default_labels(breaks, left_closed),
+        };
+        let s = self.cast(&DataType::Float64)?;
+        let ca = s.f64()?;
+        let mut out: Utf8Chunked = bin_indices(ca, breaks, left_closed)
+            .into_iter()
+            .map(|opt_bin| opt_bin.map(|bin| labels[bin].as_str()))
+            .collect();
+        out.rename(self.name());
+        out.cast(&DataType::Categorical)
+    }
+
+    /// Assign the values to bins with (about) equal counts. The bin edges are the given
+    /// `quantiles` of the values, see [`cut`](Series::cut) for the other arguments.
+    /// Duplicate edges, that occur if many values are equal, are merged.
+    #[cfg_attr(docsrs, doc(cfg(feature = "cut")))]
+    pub fn qcut(
+        &self,
+        quantiles: &[f64],
+        labels: Option<&[String]>,
+        left_closed: bool,
+    ) -> Result<Series> {
+        let s = self.cast(&DataType::Float64)?;
+        let mut breaks = quantiles
+            .iter()
+            .map(|q| {
+                let q = s.quantile_as_series(*q, QuantileInterpolOptions::Linear)?;
+                Ok(q.f64()?.get(0))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .filter(|b| !b.is_nan())
+            .collect::<Vec<_>>();
+        breaks.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        breaks.dedup();
+        self.cut(&breaks, labels, left_closed)
+    }
+
+    /// Count the values in bins. Returns a `DataFrame` with the `"lower_bound"`, the
+    /// `"upper_bound"` and the `"count"` of every bin.
+    ///
+    /// * `bins` - the strictly increasing bin edges. The bins include their upper edge and
+    ///   values outside of the edges are not counted.
+    /// * `bin_count` - if no `bins` are given, create this number of bins of equal width
+    ///   between the minimum and the maximum. The first bin also includes the minimum.
+    #[cfg_attr(docsrs, doc(cfg(feature = "cut")))]
+    pub fn hist(&self, bins: Option<&[f64]>, bin_count: Option<usize>) -> Result<DataFrame> {
+        let s = self.cast(&DataType::Float64)?;
+        let ca = s.f64()?;
+        let (edges, include_lowest) = match (bins, bin_count) {
+            (Some(bins), _) => {
+                check_breaks(bins)?;
+                (bins.to_vec(), false)
+            }
+            (None, Some(bin_count)) if bin_count > 0 => {
+                let (min, max) = match (ca.min(), ca.max()) {
+                    (Some(min), Some(max)) => (min, max),
+                    _ => (0.0, 1.0),
+                };
+                // a single value still gets a bin of non-zero width
+                let (min, max) = if min == max {
+                    (min - 0.5, max + 0.5)
+                } else {
+                    (min, max)
+                };
+                let width = (max - min) / bin_count as f64;
+                let mut edges = (0..bin_count)
+                    .map(|i| min + i as f64 * width)
+                    .collect::<Vec<_>>();
+                edges.push(max);
+                (edges, true)
+            }
+            _ => {
+                return Err(PolarsError::ValueError(
+                    "hist needs either bins or a positive bin_count".into(),
+                ))
+            }
+        };
+
+        let n_bins = edges.len().saturating_sub(1);
+        let mut counts = vec![0u32; n_bins];
+        let bins = bin_indices(ca, &edges, false);
+        for (v, idx) in ca.into_iter().zip(bins) {
+            let (v, idx) = match (v, idx) {
+                (Some(v), Some(idx)) => (v, idx),
+                _ => continue,
+            };
+            if (1..=n_bins).contains(&idx) {
+                counts[idx - 1] += 1;
+            } else if include_lowest && idx == 0 && v == edges[0] && n_bins > 0 {
+                counts[0] += 1;
+            }
+        }
+
+        let lower = Float64Chunked::from_slice("lower_bound", &edges[..n_bins]);
+        let upper = Float64Chunked::from_slice("upper_bound", &edges[edges.len() - n_bins..]);
+        let counts = UInt32Chunked::from_vec("count", counts);
+        DataFrame::new(vec![
+            lower.into_series(),
+            upper.into_series(),
+            counts.into_series(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_cut() -> Result<()> {
+        let s = Series::new("a", &[Some(1), Some(5), None, Some(6), Some(10)]);
+        let out = s.cut(&[5.0], None, false)?;
+        assert_eq!(out.dtype(), &DataType::Categorical);
+        let out = out.cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[
+                Some("(-inf, 5]"),
+                Some("(-inf, 5]"),
+                None,
+                Some("(5, inf]"),
+                Some("(5, inf]")
+            ]
+        );
+
+        let labels = ["low".to_string(), "high".to_string()];
+        let out = s.cut(&[5.0], Some(&labels), true)?.cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("low"), Some("high"), None, Some("high"), Some("high")]
+        );
+        assert!(s.cut(&[5.0, 1.0], None, false).is_err());
+
+        // unsorted values, values on the breaks and values outside of the breaks
+        let s = Series::new(
+            "a",
+            &[
+                4.0,
+                f64::INFINITY,
+                1.0,
+                2.0,
+                f64::NAN,
+                f64::NEG_INFINITY,
+                3.0,
+                1.5,
+            ],
+        );
+        let labels = ["a".to_string(), "b".to_string(), "c".to_string()];
+        let out = s
+            .cut(&[1.0, 3.0], Some(&labels), false)?
+            .cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[
+                Some("c"),
+                Some("c"),
+                Some("a"),
+                Some("b"),
+                None,
+                Some("a"),
+                Some("b"),
+                Some("b")
+            ]
+        );
+        let out = s
+            .cut(&[1.0, 3.0], Some(&labels), true)?
+            .cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[
+                Some("c"),
+                Some("c"),
+                Some("b"),
+                Some("b"),
+                None,
+                Some("a"),
+                Some("c"),
+                Some("b")
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_qcut_hist() -> Result<()> {
+        let s = Series::new("a", &[1.0, 2.0, 3.0, 4.0, 5.0]);
+        let out = s.qcut(&[0.5], None, false)?.cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[
+                Some("(-inf, 3]"),
+                Some("(-inf, 3]"),
+                Some("(-inf, 3]"),
+                Some("(3, inf]"),
+                Some("(3, inf]")
+            ]
+        );
+
+        let df = s.hist(None, Some(2))?;
+        assert_eq!(
+            Vec::from(df.column("upper_bound")?.f64()?),
+            &[Some(3.0), Some(5.0)]
+        );
+        assert_eq!(Vec::from(df.column("count")?.u32()?), &[Some(3), Some(2)]);
+
+        let df = s.hist(Some(&[2.0, 4.0]), None)?;
+        assert_eq!(Vec::from(df.column("count")?.u32()?), &[Some(2)]);
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use crate::prelude::*;
+use num::{One, Zero};
+
+macro_rules! impl_float_fn {
+    ($($(#[$doc:meta])* $name:ident => $method:ident),+ $(,)?) => {
+        $(
+            $(#[$doc])*
+            #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+            pub fn $name(&self) -> Result<Series> {
+                self.apply_float(stringify!($name), f32::$method, f64::$method)
+            }
+        )+
+    };
+}
+
+impl Series {
+    /// Apply a floating point function. Integers are cast to `Float64` first.
+    fn apply_float<F32, F64>(&self, name: &str, f32_op: F32, f64_op: F64) -> Result<Series>
+    where
+        F32: Fn(f32) -> f32 + Copy,
+        F64: Fn(f64) -> f64 + Copy,
+    {
+        use DataType::*;
+        match self.dtype() {
+            Float32 => Ok(self.f32().unwrap().apply(f32_op).into_series()),
+            Float64 => Ok(self.f64().unwrap().apply(f64_op).into_series()),
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 => {
+                let s = self.cast(&Float64)?;
+                Ok(s.f64().unwrap().apply(f64_op).into_series())
+            }
+            dt => Err(PolarsError::InvalidOperation(
+                format!("{} not supported for series of type {:?}", name, dt).into(),
+            )),
+        }
+    }
+
+    impl_float_fn!(
+        /// Compute the square root of every value.
+        sqrt => sqrt,
+        /// Compute the cube root of every value.
+        cbrt => cbrt,
+        /// Compute `e^x` of every value.
+        exp => exp,
+        /// Compute `ln(1 + x)` of every value, which is more accurate than `log` for values
+        /// close to zero.
+        log1p => ln_1p,
+        /// Compute the sine of every value in radians.
+        sin => sin,
+        /// Compute the cosine of every value in radians.
+        cos => cos,
+        /// Compute the tangent of every value in radians.
+        tan => tan,
+        /// Compute the inverse sine of every value, in radians.
+        arcsin => asin,
+        /// Compute the inverse cosine of every value, in radians.
+        arccos => acos,
+        /// Compute the inverse tangent of every value, in radians.
+        arctan => atan,
+        /// Compute the hyperbolic sine of every value.
+        sinh => sinh,
+        /// Compute the hyperbolic cosine of every value.
+        cosh => cosh,
+        /// Compute the hyperbolic tangent of every value.
+        tanh => tanh,
+        /// Compute the inverse hyperbolic sine of every value.
+        arcsinh => asinh,
+        /// Compute the inverse hyperbolic cosine of every value.
+        arccosh => acosh,
+        /// Compute the inverse hyperbolic tangent of every value.
+        arctanh => atanh,
+    );
+
+    /// Compute the logarithm of every value to the given `base`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn log(&self, base: f64) -> Result<Series> {
+        self.apply_float("log", move |v| v.log(base as f32), move |v| v.log(base))
+    }
+
+    /// Get the sign of every value: `-1` for negative values, `1` for positive values and `0`
+    /// for zero. Integers keep their data type. `NaN` stays `NaN`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn sign(&self) -> Result<Series> {
+        use DataType::*;
+        match self.dtype() {
+            #[cfg(feature = "dtype-u8")]
+            UInt8 => Ok(sign_int(self.u8().unwrap())),
+            #[cfg(feature = "dtype-u16")]
+            UInt16 => Ok(sign_int(self.u16().unwrap())),
+            UInt32 => Ok(sign_int(self.u32().unwrap())),
+            UInt64 => Ok(sign_int(self.u64().unwrap())),
+            #[cfg(feature = "dtype-i8")]
+            Int8 => Ok(sign_int(self.i8().unwrap())),
+            #[cfg(feature = "dtype-i16")]
+            Int16 => Ok(sign_int(self.i16().unwrap())),
+            Int32 => Ok(sign_int(self.i32().unwrap())),
+            Int64 => Ok(sign_int(self.i64().unwrap())),
+            _ => self.apply_float(
+                "sign",
+                |v| if v == 0.0 { 0.0 } else { v.signum() },
+                |v| if v == 0.0 { 0.0 } else { v.signum() },
+            ),
+        }
+    }
+}
+
+fn sign_int<T>(ca: &ChunkedArray<T>) -> Series
+where
+    T: PolarsIntegerType,
+    ChunkedArray<T>: IntoSeries,
+{
+    let zero = T::Native::zero();
+    let one = T::Native::one();
+    ca.apply(|v| {
+        if v > zero {
+            one
+        } else if v < zero {
+            // only reached for signed integers
+            zero - one
+        } else {
+            zero
+        }
+    })
+    .into_series()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_math_functions() -> Result<()> {
+        let s = Series::new("a", &[Some(1i32), None, Some(4)]);
+        let out = s.sqrt()?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(Vec::from(out.f64()?), &[Some(1.0), None, Some(2.0)]);
+
+        let s = Series::new("a", &[1.0f32, 100.0]);
+        let out = s.log(10.0)?;
+        assert_eq!(out.dtype(), &DataType::Float32);
+        let out = out.f32()?;
+        assert!((out.get(1).unwrap() - 2.0).abs() < 1e-6);
+
+        let s = Series::new("a", &[-3.0, 0.0, 2.5]);
+        assert_eq!(
+            Vec::from(s.sign()?.f64()?),
+            &[Some(-1.0), Some(0.0), Some(1.0)]
+        );
+        let s = Series::new("a", &[Some(-3i64), None, Some(0), Some(7)]);
+        let out = s.sign()?;
+        assert_eq!(out.dtype(), &DataType::Int64);
+        assert_eq!(Vec::from(out.i64()?), &[Some(-1), None, Some(0), Some(1)]);
+        let s = Series::new("a", &[0u32, 5]);
+        assert_eq!(Vec::from(s.sign()?.u32()?), &[Some(0), Some(1)]);
+        assert!(Series::new("a", &["a"]).sin().is_err());
+        Ok(())
+    }
+}
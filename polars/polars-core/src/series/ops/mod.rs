@@ -1,16 +1,24 @@
+#[cfg(feature = "cut")]
+mod cut;
 #[cfg(feature = "diff")]
 pub mod diff;
 #[cfg(feature = "ewma")]
 mod ewm;
 mod extend;
+#[cfg(feature = "math")]
+mod math;
 #[cfg(feature = "moment")]
 pub mod moment;
 mod null;
 #[cfg(feature = "pct_change")]
 pub mod pct_change;
+#[cfg(feature = "remap")]
+mod remap;
 #[cfg(feature = "round_series")]
 mod round;
 mod to_list;
+#[cfg(feature = "top_k")]
+mod top_k;
 
 #[derive(Copy, Clone)]
 pub enum NullBehavior {
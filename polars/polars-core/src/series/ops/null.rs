@@ -24,6 +24,8 @@ impl Series {
             match dtype {
                 #[cfg(feature = "dtype-categorical")]
                 DataType::Categorical => CategoricalChunked::full_null(name, size).into_series(),
+                #[cfg(feature = "dtype-categorical")]
+                DataType::Enum(_) => UInt32Chunked::full_null(name, size).cast(dtype).unwrap(),
                 #[cfg(feature = "dtype-date")]
                 DataType::Date => Int32Chunked::full_null(name, size)
                     .into_date()
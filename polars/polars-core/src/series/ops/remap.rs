@@ -0,0 +1,363 @@
+//! Remap values with a lookup table from old values to new values.
+//!
+//! The lookup table is built once and maps every old value to its index in the new values.
+//! The rows are then looked up in parallel and the output is gathered with a single `take`.
+//! Categoricals are looked up once per category in their `RevMapping` instead of once per row.
+#[cfg(feature = "dtype-categorical")]
+use crate::chunked_array::categorical::RevMapping;
+use crate::prelude::*;
+use crate::utils::get_supertype;
+use crate::POOL;
+use rayon::prelude::*;
+use std::hash::Hash;
+
+/// Build the lookup table of the `old` keys. If a key occurs more than once, its first
+/// occurrence wins.
+fn lookup_table<K: Hash + Eq + Copy>(old: &[K]) -> PlHashMap<K, IdxSize> {
+    let mut table = PlHashMap::with_capacity(old.len());
+    for (idx, key) in old.iter().enumerate() {
+        table.entry(*key).or_insert(idx as IdxSize);
+    }
+    table
+}
+
+/// Look up the indexes of the `keys` in parallel. Keys that are not found get the index
+/// returned by `not_found` for their row.
+fn remap_idx<K, F>(keys: &[K], table: &PlHashMap<K, IdxSize>, not_found: F) -> IdxCa
+where
+    K: Hash + Eq + Sync,
+    F: Fn(usize) -> Option<IdxSize> + Sync,
+{
+    let idx = POOL.install(|| {
+        keys.par_iter()
+            .enumerate()
+            .map(|(row, key)| table.get(key).copied().or_else(|| not_found(row)))
+            .collect::<Vec<_>>()
+    });
+    idx.into_iter().collect()
+}
+
+impl Series {
+    /// Create a `Series` from `AnyValue`s. The dtype is the dtype of the first non-null value,
+    /// all other values should have that dtype as well. If all values are null, the dtype is
+    /// `Boolean`. Small integers are widened to 32 bits if their dtype feature is not active.
+    #[cfg_attr(docsrs, doc(cfg(feature = "remap")))]
+    pub fn from_any_values(name: &str, values: &[AnyValue]) -> Result<Series> {
+        let first = values.iter().find(|av| !matches!(av, AnyValue::Null));
+
+        macro_rules! collect {
+            ($variant:ident, $ca:ty, $v:ident => $map:expr) => {{
+                let mut ca = values
+                    .iter()
+                    .map(|av| match av {
+                        AnyValue::$variant($v, ..) => Ok(Some($map)),
+                        AnyValue::Null => Ok(None),
+                        av => Err(PolarsError::SchemaMisMatch(
+                            format!("expected values of one dtype, got {:?} and {:?}", first, av)
+                                .into(),
+                        )),
+                    })
+                    .collect::<Result<$ca>>()?;
+                ca.rename(name);
+                ca.into_series()
+            }};
+        }
+
+        let s = match first {
+            None => BooleanChunked::full_null(name, values.len()).into_series(),
+            Some(AnyValue::Boolean(_)) => collect!(Boolean, BooleanChunked, v => *v),
+            Some(AnyValue::Utf8(_)) => collect!(Utf8, Utf8Chunked, v => *v),
+            #[cfg(feature = "dtype-u8")]
+            Some(AnyValue::UInt8(_)) => collect!(UInt8, UInt8Chunked, v => *v),
+            #[cfg(not(feature = "dtype-u8"))]
+            Some(AnyValue::UInt8(_)) => collect!(UInt8, UInt32Chunked, v => *v as u32),
+            #[cfg(feature = "dtype-u16")]
+            Some(AnyValue::UInt16(_)) => collect!(UInt16, UInt16Chunked, v => *v),
+            #[cfg(not(feature = "dtype-u16"))]
+            Some(AnyValue::UInt16(_)) => collect!(UInt16, UInt32Chunked, v => *v as u32),
+            Some(AnyValue::UInt32(_)) => collect!(UInt32, UInt32Chunked, v => *v),
+            Some(AnyValue::UInt64(_)) => collect!(UInt64, UInt64Chunked, v => *v),
+            #[cfg(feature = "dtype-i8")]
+            Some(AnyValue::Int8(_)) => collect!(Int8, Int8Chunked, v => *v),
+            #[cfg(not(feature = "dtype-i8"))]
+            Some(AnyValue::Int8(_)) => collect!(Int8, Int32Chunked, v => *v as i32),
+            #[cfg(feature = "dtype-i16")]
+            Some(AnyValue::Int16(_)) => collect!(Int16, Int16Chunked, v => *v),
+            #[cfg(not(feature = "dtype-i16"))]
+            Some(AnyValue::Int16(_)) => collect!(Int16, Int32Chunked, v => *v as i32),
+            Some(AnyValue::Int32(_)) => collect!(Int32, Int32Chunked, v => *v),
+            Some(AnyValue::Int64(_)) => collect!(Int64, Int64Chunked, v => *v),
+            Some(AnyValue::Float32(_)) => collect!(Float32, Float32Chunked, v => *v),
+            Some(AnyValue::Float64(_)) => collect!(Float64, Float64Chunked, v => *v),
+            #[cfg(feature = "dtype-date")]
+            Some(AnyValue::Date(_)) => collect!(Date, Int32Chunked, v => *v).into_date(),
+            #[cfg(feature = "dtype-datetime")]
+            Some(AnyValue::Datetime(_, tu, tz)) => {
+                collect!(Datetime, Int64Chunked, v => *v).into_datetime(*tu, (*tz).clone())
+            }
+            Some(av) => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("cannot create a series from values like {:?}", av).into(),
+                ))
+            }
+        };
+        Ok(s)
+    }
+
+    /// Replace every value that equals `old[i]` with `new[i]`.
+    ///
+    /// * `old` - the values to remap. They are cast to the dtype of `self`.
+    /// * `new` - the new values, the output has their dtype.
+    /// * `default` - the output for values that are not in `old`. Either a single value or a
+    ///   value for every row. If `None`, those values become null.
+    ///
+    /// Null is a value like any other, so a null in `old` remaps the null values.
+    #[cfg_attr(docsrs, doc(cfg(feature = "remap")))]
+    pub fn remap(&self, old: &Series, new: &Series, default: Option<&Series>) -> Result<Series> {
+        if old.len() != new.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot remap {} old values to {} new values",
+                    old.len(),
+                    new.len()
+                )
+                .into(),
+            ));
+        }
+        let mut values = new.clone();
+        let default_offset = new.len() as IdxSize;
+        let broadcast_default = match default {
+            Some(default) => {
+                if default.len() != 1 && default.len() != self.len() {
+                    return Err(PolarsError::ShapeMisMatch(
+                        format!(
+                            "default of length {} does not match series of length {}",
+                            default.len(),
+                            self.len()
+                        )
+                        .into(),
+                    ));
+                }
+                values.append(&default.cast(new.dtype())?)?;
+                Some(default.len() == 1)
+            }
+            None => None,
+        };
+        let not_found = |row: usize| {
+            broadcast_default.map(|broadcast| {
+                if broadcast {
+                    default_offset
+                } else {
+                    default_offset + row as IdxSize
+                }
+            })
+        };
+
+        let idx = match self.dtype() {
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical | DataType::Enum(_) => {
+                let old = old.cast(&DataType::Utf8)?;
+                let old = old.utf8()?.into_iter().collect::<Vec<_>>();
+                let table = lookup_table(&old);
+                let ca = self.categorical()?;
+                let (categories, global) = match &**ca.get_categorical_map().unwrap() {
+                    RevMapping::Local(arr) | RevMapping::Enum(arr, _) => (arr, None),
+                    RevMapping::Global(map, arr, _) => (arr, Some(map)),
+                };
+                // remap every category once
+                let categories = categories
+                    .values_iter()
+                    .map(|cat| table.get(&Some(cat)).copied())
+                    .collect::<Vec<_>>();
+                let null_idx = table.get(&None).copied();
+
+                let codes = ca.into_iter().collect::<Vec<_>>();
+                let idx = POOL.install(|| {
+                    codes
+                        .par_iter()
+                        .enumerate()
+                        .map(|(row, opt_code)| {
+                            let found = match opt_code {
+                                Some(code) => {
+                                    let local = global.map_or(*code, |map| map[code]);
+                                    categories[local as usize]
+                                }
+                                None => null_idx,
+                            };
+                            found.or_else(|| not_found(row))
+                        })
+                        .collect::<Vec<_>>()
+                });
+                idx.into_iter().collect()
+            }
+            DataType::Utf8 => {
+                let old = old.cast(&DataType::Utf8)?;
+                let old = old.utf8()?.into_iter().collect::<Vec<_>>();
+                let keys = self.utf8()?.into_iter().collect::<Vec<_>>();
+                remap_idx(&keys, &lookup_table(&old), not_found)
+            }
+            DataType::List(_) | DataType::Null => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("remap not supported for series of type {:?}", self.dtype()).into(),
+                ))
+            }
+            #[cfg(feature = "object")]
+            DataType::Object(_) => {
+                return Err(PolarsError::InvalidOperation(
+                    "remap not supported for series of type object".into(),
+                ))
+            }
+            dt => {
+                let old = old.cast(dt)?.bit_keys()?;
+                let keys = self.bit_keys()?;
+                remap_idx(&keys, &lookup_table(&old), not_found)
+            }
+        };
+
+        let mut out = values.take(&idx)?;
+        out.rename(self.name());
+        Ok(out)
+    }
+
+    /// Replace every value that equals `old[i]` with `new[i]` and keep the values that are not
+    /// in `old`. The output has the supertype of `self` and `new`; a `Categorical` or `Enum`
+    /// keeps its type if the new values are strings.
+    #[cfg_attr(docsrs, doc(cfg(feature = "remap")))]
+    pub fn replace(&self, old: &Series, new: &Series) -> Result<Series> {
+        let dtype = match self.dtype() {
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical | DataType::Enum(_) => {
+                let dtype = get_supertype(&DataType::Utf8, new.dtype())?;
+                if dtype == DataType::Utf8 {
+                    return self.replace_categories(old, new);
+                }
+                dtype
+            }
+            dt => get_supertype(dt, new.dtype())?,
+        };
+        let default = self.cast(&dtype)?;
+        self.remap(old, &new.cast(&dtype)?, Some(&default))
+    }
+
+    /// Replace the categories that equal `old[i]` with the string `new[i]`. Every category is
+    /// replaced once; the rows only translate their code to the code of the new category.
+    #[cfg(feature = "dtype-categorical")]
+    fn replace_categories(&self, old: &Series, new: &Series) -> Result<Series> {
+        let old = old.cast(&DataType::Utf8)?;
+        let table = lookup_table(&old.utf8()?.into_iter().collect::<Vec<_>>());
+        let new = new.cast(&DataType::Utf8)?;
+        let new = new.utf8()?;
+
+        let ca = self.categorical()?;
+        let (categories, global) = match &**ca.get_categorical_map().unwrap() {
+            RevMapping::Local(arr) | RevMapping::Enum(arr, _) => (arr, None),
+            RevMapping::Global(map, arr, _) => (arr, Some(map)),
+        };
+        // the new value of every category, followed by the new value of the null rows
+        let replaced: Utf8Chunked = categories
+            .values_iter()
+            .map(Some)
+            .chain(std::iter::once(None))
+            .map(|opt_v| match table.get(&opt_v) {
+                Some(idx) => new.get(*idx as usize),
+                None => opt_v,
+            })
+            .collect();
+        // the cast builds the new `RevMapping`, merging categories that get the same value
+        let replaced = replaced.cast(self.dtype())?;
+
+        let null_idx = categories.len() as IdxSize;
+        let idx: IdxCa = ca
+            .into_iter()
+            .map(|opt_code| {
+                Some(match opt_code {
+                    Some(code) => global.map_or(code, |map| map[&code]) as IdxSize,
+                    None => null_idx,
+                })
+            })
+            .collect();
+        let mut out = replaced.take(&idx)?.categorical()?.clone();
+        out.set_ordering(ca.ordering());
+        out.rename(self.name());
+        Ok(out.into_series())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_remap() -> Result<()> {
+        let s = Series::new("a", &[Some(1), Some(2), None, Some(3), Some(1)]);
+        let old = Series::new("", &[1, 3]);
+        let new = Series::new("", &["one", "three"]);
+        let out = s.remap(&old, &new, None)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("one"), None, None, Some("three"), Some("one")]
+        );
+
+        let default = Series::new("", &["other"]);
+        let out = s.remap(&old, &new, Some(&default))?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[
+                Some("one"),
+                Some("other"),
+                Some("other"),
+                Some("three"),
+                Some("one")
+            ]
+        );
+
+        let out = s.replace(&Series::new("", &[2]), &Series::new("", &[20]))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), Some(20), None, Some(3), Some(1)]
+        );
+        assert!(s.remap(&old, &Series::new("", &["one"]), None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_remap_categorical() -> Result<()> {
+        let s = Series::new("a", &["x", "y", "x", "z"]).cast(&DataType::Categorical)?;
+        let out = s.replace(&Series::new("", &["x"]), &Series::new("", &["w"]))?;
+        assert_eq!(out.dtype(), &DataType::Categorical);
+        let out = out.cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("w"), Some("y"), Some("w"), Some("z")]
+        );
+
+        // categories that get the same value are merged, and nulls can be replaced
+        let s = Series::new("a", &[Some("x"), None, Some("y"), Some("z")])
+            .cast(&DataType::Categorical)?;
+        let old = Series::new("", &[Some("x"), None]);
+        let new = Series::new("", &["y", "n"]);
+        let out = s.replace(&old, &new)?;
+        assert_eq!(out.categorical()?.get_categorical_map().unwrap().len(), 3);
+        let out = out.cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("y"), Some("n"), Some("y"), Some("z")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_any_values() -> Result<()> {
+        let s = Series::from_any_values("a", &[AnyValue::Null, AnyValue::Int64(2)])?;
+        assert_eq!(Vec::from(s.i64()?), &[None, Some(2)]);
+        assert!(Series::from_any_values("a", &[AnyValue::Int64(2), AnyValue::Utf8("a")]).is_err());
+        #[cfg(feature = "dtype-i8")]
+        {
+            let s = Series::from_any_values("a", &[AnyValue::Int8(1)])?;
+            assert_eq!(s.dtype(), &DataType::Int8);
+        }
+        Ok(())
+    }
+}
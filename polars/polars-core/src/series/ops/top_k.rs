@@ -0,0 +1,140 @@
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+/// Select the `k` largest values (or smallest if `reverse`) in sorted order.
+/// Only the selected values are sorted, the others are partitioned away in linear time.
+///
+/// The values must be totally ordered by `partial_cmp`, so floats must not hold NaN.
+fn top_k_values<T: PartialOrd>(mut values: Vec<T>, k: usize, reverse: bool) -> Vec<T> {
+    let k = std::cmp::min(k, values.len());
+    if k == 0 {
+        return vec![];
+    }
+    let cmp = |a: &T, b: &T| {
+        let ord = if reverse {
+            a.partial_cmp(b)
+        } else {
+            b.partial_cmp(a)
+        };
+        ord.unwrap_or(Ordering::Equal)
+    };
+    if k < values.len() {
+        values.select_nth_unstable_by(k - 1, cmp);
+        values.truncate(k);
+    }
+    values.sort_unstable_by(cmp);
+    values
+}
+
+fn top_k_numeric<T>(ca: &ChunkedArray<T>, k: usize, reverse: bool) -> Series
+where
+    T: PolarsNumericType,
+    ChunkedArray<T>: IntoSeries,
+{
+    // NaN is the only value that is not ordered, it is split out and taken as the largest value
+    #[allow(clippy::eq_op)]
+    let (nan, values): (Vec<_>, Vec<_>) = ca.into_iter().flatten().partition(|v| v != v);
+    let out = if reverse {
+        let mut out = top_k_values(values, k, true);
+        let n_nan = k.saturating_sub(out.len());
+        out.extend(nan.into_iter().take(n_nan));
+        out
+    } else {
+        let mut out = nan.into_iter().take(k).collect::<Vec<_>>();
+        out.extend(top_k_values(values, k - out.len(), false));
+        out
+    };
+    ChunkedArray::<T>::from_vec(ca.name(), out).into_series()
+}
+
+impl Series {
+    /// Get the `k` largest values, sorted from large to small. If `reverse`, get the `k`
+    /// smallest values, sorted from small to large. Null values are ignored.
+    ///
+    /// This uses a selection algorithm, so only the `k` selected values are sorted.
+    #[cfg_attr(docsrs, doc(cfg(feature = "top_k")))]
+    pub fn top_k(&self, k: usize, reverse: bool) -> Result<Series> {
+        #[cfg(feature = "dtype-categorical")]
+        if let Ok(ca) = self.categorical() {
+            if ca.uses_lexical_ordering() {
+                let out = self.filter(&self.is_not_null())?.sort(!reverse);
+                return Ok(out.slice(0, k));
+            }
+        }
+        let physical = self.to_physical_repr();
+        use DataType::*;
+        let out = match physical.dtype() {
+            Int32 => top_k_numeric(physical.i32().unwrap(), k, reverse),
+            Int64 => top_k_numeric(physical.i64().unwrap(), k, reverse),
+            UInt32 => top_k_numeric(physical.u32().unwrap(), k, reverse),
+            UInt64 => top_k_numeric(physical.u64().unwrap(), k, reverse),
+            Float32 => top_k_numeric(physical.f32().unwrap(), k, reverse),
+            Float64 => top_k_numeric(physical.f64().unwrap(), k, reverse),
+            Int8 | Int16 => return physical.cast(&Int32)?.top_k(k, reverse)?.cast(self.dtype()),
+            UInt8 | UInt16 => {
+                return physical
+                    .cast(&UInt32)?
+                    .top_k(k, reverse)?
+                    .cast(self.dtype())
+            }
+            Utf8 => {
+                let ca = physical.utf8().unwrap();
+                let values = ca.into_iter().flatten().collect::<Vec<_>>();
+                Utf8Chunked::from_slice(self.name(), &top_k_values(values, k, reverse))
+                    .into_series()
+            }
+            dt => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("top_k not supported for series of type {:?}", dt).into(),
+                ))
+            }
+        };
+        match self.dtype() {
+            // the physical ordering of categoricals is the order of their codes
+            #[cfg(feature = "dtype-categorical")]
+            Categorical | Enum(_) => {
+                let mut out = out.u32().unwrap().clone();
+                let ca = self.categorical().unwrap();
+                out.categorical_map = ca.categorical_map.clone();
+                let mut out = out.cast(&Categorical)?.categorical()?.clone();
+                out.set_ordering(ca.ordering());
+                Ok(out.into_series())
+            }
+            dt if dt.is_logical() => out.cast(dt),
+            _ => Ok(out),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_top_k() -> Result<()> {
+        let s = Series::new("a", &[Some(3), None, Some(7), Some(1), Some(5), Some(7)]);
+        let out = s.top_k(3, false)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(7), Some(7), Some(5)]);
+        let out = s.top_k(2, true)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(3)]);
+        assert_eq!(s.top_k(10, false)?.len(), 5);
+
+        let s = Series::new("a", &[1.0, f64::NAN, 3.0, 2.0]);
+        let out = s.top_k(2, false)?;
+        let out = out.f64()?;
+        assert!(out.get(0).unwrap().is_nan());
+        assert_eq!(out.get(1), Some(3.0));
+        let out = s.top_k(4, true)?;
+        let out = out.f64()?;
+        assert_eq!(
+            out.slice(0, 3).into_iter().collect::<Vec<_>>(),
+            &[Some(1.0), Some(2.0), Some(3.0)]
+        );
+        assert!(out.get(3).unwrap().is_nan());
+
+        let s = Series::new("a", &["b", "c", "a"]);
+        let out = s.top_k(1, false)?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("c")]);
+        Ok(())
+    }
+}
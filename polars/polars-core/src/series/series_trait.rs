@@ -45,6 +45,7 @@ pub(crate) mod private {
     use crate::frame::groupby::GroupsProxy;
 
     use crate::chunked_array::ops::compare_inner::{PartialEqInner, PartialOrdInner};
+    use crate::chunked_array::ops::estimated_size::SizeEstimator;
     use ahash::RandomState;
 
     pub trait PrivateSeriesNumeric {
@@ -79,6 +80,9 @@ pub(crate) mod private {
             unimplemented!()
         }
 
+        /// Add the buffers of this Series to a size estimate.
+        fn _add_to_size_estimate(&self, estimator: &mut SizeEstimator);
+
         fn explode_by_offsets(&self, _offsets: &[i64]) -> Series {
             invalid_operation_panic!(self)
         }
@@ -146,7 +150,7 @@ pub(crate) mod private {
         }
 
         #[cfg(feature = "asof_join")]
-        fn join_asof(&self, _other: &Series) -> Result<Vec<Option<u32>>> {
+        fn join_asof(&self, _other: &Series) -> Result<Vec<Option<IdxSize>>> {
             invalid_operation!(self)
         }
 
@@ -211,19 +215,31 @@ pub(crate) mod private {
             None
         }
 
-        fn hash_join_inner(&self, _other: &Series) -> Vec<(u32, u32)> {
+        fn hash_join_inner(
+            &self,
+            _other: &Series,
+            _validate: JoinValidation,
+        ) -> Result<Vec<(IdxSize, IdxSize)>> {
             invalid_operation_panic!(self)
         }
-        fn hash_join_left(&self, _other: &Series) -> Vec<(u32, Option<u32>)> {
+        fn hash_join_left(
+            &self,
+            _other: &Series,
+            _validate: JoinValidation,
+        ) -> Result<Vec<(IdxSize, Option<IdxSize>)>> {
             invalid_operation_panic!(self)
         }
-        fn hash_join_outer(&self, _other: &Series) -> Vec<(Option<u32>, Option<u32>)> {
+        fn hash_join_outer(
+            &self,
+            _other: &Series,
+            _validate: JoinValidation,
+        ) -> Result<Vec<(Option<IdxSize>, Option<IdxSize>)>> {
             invalid_operation_panic!(self)
         }
         fn zip_outer_join_column(
             &self,
             _right_column: &Series,
-            _opt_join_tuples: &[(Option<u32>, Option<u32>)],
+            _opt_join_tuples: &[(Option<IdxSize>, Option<IdxSize>)],
         ) -> Series {
             invalid_operation_panic!(self)
         }
@@ -250,7 +266,7 @@ pub(crate) mod private {
             invalid_operation_panic!(self)
         }
         #[cfg(feature = "sort_multiple")]
-        fn argsort_multiple(&self, _by: &[Series], _reverse: &[bool]) -> Result<UInt32Chunked> {
+        fn argsort_multiple(&self, _by: &[Series], _reverse: &[bool]) -> Result<IdxCa> {
             Err(PolarsError::InvalidOperation(
                 "argsort_multiple is not implemented for this Series".into(),
             ))
@@ -523,7 +539,7 @@ pub trait SeriesTrait:
     ///
     /// # Safety
     /// This doesn't check any bounds.
-    unsafe fn take_unchecked(&self, _idx: &UInt32Chunked) -> Result<Series> {
+    unsafe fn take_unchecked(&self, _idx: &IdxCa) -> Result<Series> {
         invalid_operation_panic!(self)
     }
 
@@ -545,7 +561,7 @@ pub trait SeriesTrait:
     }
 
     /// Take by index. This operation is clone.
-    fn take(&self, _indices: &UInt32Chunked) -> Result<Series> {
+    fn take(&self, _indices: &IdxCa) -> Result<Series> {
         invalid_operation_panic!(self)
     }
 
@@ -635,7 +651,7 @@ pub trait SeriesTrait:
     }
 
     /// Retrieve the indexes needed for a sort.
-    fn argsort(&self, _reverse: bool) -> UInt32Chunked {
+    fn argsort(&self, _reverse: bool) -> IdxCa {
         invalid_operation_panic!(self)
     }
 
@@ -659,7 +675,7 @@ pub trait SeriesTrait:
     }
 
     /// Get first indexes of unique values.
-    fn arg_unique(&self) -> Result<UInt32Chunked> {
+    fn arg_unique(&self) -> Result<IdxCa> {
         invalid_operation_panic!(self)
     }
 
@@ -674,7 +690,7 @@ pub trait SeriesTrait:
     }
 
     /// Get indexes that evaluate true
-    fn arg_true(&self) -> Result<UInt32Chunked> {
+    fn arg_true(&self) -> Result<IdxCa> {
         Err(PolarsError::InvalidOperation(
             "arg_true can only be called for boolean dtype".into(),
         ))
@@ -935,6 +951,36 @@ pub trait SeriesTrait:
         }
     }
 
+    #[cfg(feature = "temporal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+    /// Returns the ISO year, i.e. the year of the ISO week of the date.
+    fn iso_year(&self) -> Result<Int32Chunked> {
+        match self.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => self.date().map(|ca| ca.iso_year()),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => self.datetime().map(|ca| ca.iso_year()),
+            _ => Err(PolarsError::InvalidOperation(
+                format!("operation not supported on dtype {:?}", self.dtype()).into(),
+            )),
+        }
+    }
+
+    #[cfg(feature = "temporal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+    /// Returns the quarter of the year, ranging from 1 to 4.
+    fn quarter(&self) -> Result<UInt32Chunked> {
+        match self.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => self.date().map(|ca| ca.quarter()),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => self.datetime().map(|ca| ca.quarter()),
+            _ => Err(PolarsError::InvalidOperation(
+                format!("operation not supported on dtype {:?}", self.dtype()).into(),
+            )),
+        }
+    }
+
     #[cfg(feature = "temporal")]
     #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
     /// Returns the day of year starting from 1.
@@ -15,3 +15,36 @@ fn test_initial_empty_sort() -> Result<()> {
     series.f64()?.sort(false);
     Ok(())
 }
+
+#[test]
+fn test_with_thread_pool() -> Result<()> {
+    use crate::config::Config;
+    use crate::{with_n_threads, POOL};
+
+    let cfg = Config {
+        fmt_max_rows: 3,
+        ..Config::from_env()
+    };
+    let (n_threads, max_rows) = cfg.scoped(|_| {
+        with_n_threads(2, || {
+            // nested parallel operations stay in the installed pool
+            let (a, _) = POOL.join(|| POOL.current_num_threads(), || ());
+            (a, Config::current().fmt_max_rows)
+        })
+    })?;
+    assert_eq!(n_threads, 2);
+    assert_eq!(max_rows, 3);
+
+    let df = df!["a" => [1, 1, 2], "b" => [1, 2, 3]]?;
+    let out = with_n_threads(1, || df.groupby(["a"])?.select(["b"]).sum())??;
+    assert_eq!(out.height(), 2);
+
+    // a pool that is not installed doesn't change the pool of polars
+    let global = POOL.current_num_threads();
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(global + 1)
+        .build()
+        .unwrap();
+    assert_eq!(pool.install(|| POOL.current_num_threads()), global);
+    Ok(())
+}
@@ -323,7 +323,7 @@ pub type IdBuildHasher = BuildHasherDefault<IdHasher>;
 /// accidental quadratic behavior. So do not use an Identity function!
 pub(crate) struct IdxHash {
     // idx in row of Series, DataFrame
-    pub(crate) idx: u32,
+    pub(crate) idx: IdxSize,
     // precomputed hash of T
     hash: u64,
 }
@@ -336,7 +336,7 @@ impl Hash for IdxHash {
 
 impl IdxHash {
     #[inline]
-    pub(crate) fn new(idx: u32, hash: u64) -> Self {
+    pub(crate) fn new(idx: IdxSize, hash: u64) -> Self {
         IdxHash { idx, hash }
     }
 }
@@ -381,9 +381,13 @@ pub(crate) fn this_partition(h: u64, thread_no: u64, n_partitions: u64) -> bool
     (h.wrapping_add(thread_no)) & n_partitions.wrapping_sub(1) == 0
 }
 
+/// Build the hash tables of an outer join. If the keys must be `unique`, `None` is returned at
+/// the first key that is inserted twice.
+#[allow(clippy::type_complexity)]
 pub(crate) fn prepare_hashed_relation_threaded<T, I>(
     iters: Vec<I>,
-) -> Vec<HashMap<T, (bool, Vec<u32>), RandomState>>
+    unique: bool,
+) -> Option<Vec<HashMap<T, (bool, Vec<IdxSize>), RandomState>>>
 where
     I: Iterator<Item = T> + Send + TrustedLen,
     T: Send + Hash + Eq + Sync + Copy,
@@ -392,6 +396,7 @@ where
     assert!(n_partitions.is_power_of_two());
 
     let (hashes_and_keys, build_hasher) = create_hash_and_keys_threaded_vectorized(iters, None);
+    let token = CancellationToken::current();
 
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
@@ -401,42 +406,43 @@ where
             let build_hasher = build_hasher.clone();
             let hashes_and_keys = &hashes_and_keys;
             let partition_no = partition_no as u64;
-            let mut hash_tbl: HashMap<T, (bool, Vec<u32>), RandomState> =
+            let mut hash_tbl: HashMap<T, (bool, Vec<IdxSize>), RandomState> =
                 HashMap::with_hasher(build_hasher);
 
             let n_threads = n_partitions as u64;
             let mut offset = 0;
             for hashes_and_keys in hashes_and_keys {
                 let len = hashes_and_keys.len();
-                hashes_and_keys
-                    .iter()
-                    .enumerate()
-                    .for_each(|(idx, (h, k))| {
-                        let idx = idx as u32;
-                        // partition hashes by thread no.
-                        // So only a part of the hashes go to this hashmap
-                        if this_partition(*h, partition_no, n_threads) {
-                            let idx = idx + offset;
-                            let entry = hash_tbl
-                                .raw_entry_mut()
-                                // uses the key to check equality to find and entry
-                                .from_key_hashed_nocheck(*h, k);
-
-                            match entry {
-                                RawEntryMut::Vacant(entry) => {
-                                    entry.insert_hashed_nocheck(*h, *k, (false, vec![idx]));
-                                }
-                                RawEntryMut::Occupied(mut entry) => {
-                                    let (_k, v) = entry.get_key_value_mut();
-                                    v.1.push(idx);
-                                }
+                for (idx, (h, k)) in hashes_and_keys.iter().enumerate() {
+                    if token.is_cancelled_at(idx) {
+                        return Some(hash_tbl);
+                    }
+                    let idx = idx as IdxSize;
+                    // partition hashes by thread no.
+                    // So only a part of the hashes go to this hashmap
+                    if this_partition(*h, partition_no, n_threads) {
+                        let idx = idx + offset;
+                        let entry = hash_tbl
+                            .raw_entry_mut()
+                            // uses the key to check equality to find and entry
+                            .from_key_hashed_nocheck(*h, k);
+
+                        match entry {
+                            RawEntryMut::Vacant(entry) => {
+                                entry.insert_hashed_nocheck(*h, *k, (false, vec![idx]));
+                            }
+                            RawEntryMut::Occupied(_) if unique => return None,
+                            RawEntryMut::Occupied(mut entry) => {
+                                let (_k, v) = entry.get_key_value_mut();
+                                v.1.push(idx);
                             }
                         }
-                    });
+                    }
+                }
 
-                offset += len as u32;
+                offset += len as IdxSize;
             }
-            hash_tbl
+            Some(hash_tbl)
         })
     })
     .collect()
@@ -0,0 +1,259 @@
+//! Derive macros that convert between Rust structs and polars `DataFrame`s.
+//!
+//! `#[derive(IntoDataFrame)]` implements `IntoDataFrame`, which builds a column per field from a
+//! `Vec` of structs. `#[derive(FromDataFrame)]` implements `FromDataFrame`, which is used by
+//! `DataFrame::iter_as` to read the rows of a `DataFrame` as structs.
+//!
+//! The type of every field must implement `ColumnValue`, unless the field is annotated with
+//! `#[polars(flatten)]`. The fields of a flattened struct are stored in columns that are named
+//! `{field}.{nested field}`. A column can be given a different name than its field with
+//! `#[polars(rename = "name")]`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use polars::prelude::*;
+//!
+//! #[derive(IntoDataFrame, FromDataFrame)]
+//! struct Point {
+//!     x: f64,
+//!     y: f64,
+//! }
+//!
+//! #[derive(IntoDataFrame, FromDataFrame)]
+//! struct Measurement {
+//!     #[polars(rename = "sensor_id")]
+//!     sensor: u32,
+//!     label: Option<String>,
+//!     values: Vec<f64>,
+//!     #[polars(flatten)]
+//!     location: Point,
+//! }
+//!
+//! fn example(rows: Vec<Measurement>) -> Result<Vec<Measurement>> {
+//!     let df = Measurement::into_df(rows)?;
+//!     // columns: sensor_id, label, values, location.x, location.y
+//!     Ok(df.iter_as::<Measurement>()?.collect())
+//! }
+//! ```
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Lit, Meta, NestedMeta};
+
+#[proc_macro_derive(IntoDataFrame, attributes(polars))]
+pub fn derive_into_dataframe(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_into_dataframe(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(FromDataFrame, attributes(polars))]
+pub fn derive_from_dataframe(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_dataframe(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+struct Field {
+    ident: syn::Ident,
+    ty: syn::Type,
+    /// name of the column, or the prefix of the columns if the field is flattened
+    name: String,
+    flatten: bool,
+}
+
+/// The path of the crate that exports the runtime traits. Users either depend on `polars` or
+/// on `polars-core`.
+fn polars_crate() -> TokenStream2 {
+    let to_path = |name: String| {
+        let ident = syn::Ident::new(&name, Span::call_site());
+        quote!(::#ident)
+    };
+    match crate_name("polars") {
+        Ok(FoundCrate::Name(name)) => to_path(name),
+        // the integration tests of polars
+        Ok(FoundCrate::Itself) => quote!(::polars),
+        Err(_) => match crate_name("polars-core") {
+            Ok(FoundCrate::Name(name)) => to_path(name),
+            Ok(FoundCrate::Itself) => quote!(crate),
+            Err(_) => quote!(::polars),
+        },
+    }
+}
+
+fn parse_fields(input: &DeriveInput) -> syn::Result<Vec<Field>> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "only structs with named fields can be converted to a DataFrame",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "only structs can be converted to a DataFrame",
+            ))
+        }
+    };
+
+    fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.clone().unwrap();
+            let mut name = ident.to_string();
+            let mut flatten = false;
+
+            for attr in field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path.is_ident("polars"))
+            {
+                let list = match attr.parse_meta()? {
+                    Meta::List(list) => list,
+                    meta => return Err(syn::Error::new_spanned(meta, "expected #[polars(...)]")),
+                };
+                for nested in list.nested {
+                    match nested {
+                        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("flatten") => {
+                            flatten = true
+                        }
+                        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("rename") => {
+                            match nv.lit {
+                                Lit::Str(lit) => name = lit.value(),
+                                lit => {
+                                    return Err(syn::Error::new_spanned(
+                                        lit,
+                                        "expected a string literal",
+                                    ))
+                                }
+                            }
+                        }
+                        nested => return Err(syn::Error::new_spanned(
+                            nested,
+                            "unknown polars attribute, expected `flatten` or `rename = \"...\"`",
+                        )),
+                    }
+                }
+            }
+
+            Ok(Field {
+                ident,
+                ty: field.ty.clone(),
+                name,
+                flatten,
+            })
+        })
+        .collect()
+}
+
+fn expand_into_dataframe(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let krate = polars_crate();
+    let fields = parse_fields(&input)?;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let buffers = (0..fields.len())
+        .map(|i| format_ident!("__field_{}", i))
+        .collect::<Vec<_>>();
+    let field_idents = fields.iter().map(|f| &f.ident);
+    let columns = fields.iter().zip(&buffers).map(|(field, buffer)| {
+        let ty = &field.ty;
+        let name = &field.name;
+        if field.flatten {
+            quote! {
+                columns.extend(<#ty as #krate::frame::row::IntoDataFrame>::into_columns(
+                    #buffer,
+                    &::std::format!("{}{}.", prefix, #name),
+                ));
+            }
+        } else {
+            quote! {
+                columns.push(<#ty as #krate::frame::row::ColumnValue>::into_series(
+                    &::std::format!("{}{}", prefix, #name),
+                    #buffer,
+                ));
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #krate::frame::row::IntoDataFrame for #ident #ty_generics #where_clause {
+            #[allow(unused_variables, unused_mut)]
+            fn into_columns(
+                rows: ::std::vec::Vec<Self>,
+                prefix: &str,
+            ) -> ::std::vec::Vec<#krate::series::Series> {
+                #(
+                    let mut #buffers = ::std::vec::Vec::with_capacity(rows.len());
+                )*
+                for row in rows {
+                    #(
+                        #buffers.push(row.#field_idents);
+                    )*
+                }
+                let mut columns = ::std::vec::Vec::new();
+                #(#columns)*
+                columns
+            }
+        }
+    })
+}
+
+fn expand_from_dataframe(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let krate = polars_crate();
+    let fields = parse_fields(&input)?;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let iters = (0..fields.len())
+        .map(|i| format_ident!("__field_{}", i))
+        .collect::<Vec<_>>();
+    let field_idents = fields.iter().map(|f| &f.ident);
+    let columns = fields.iter().zip(&iters).map(|(field, iter)| {
+        let ty = &field.ty;
+        let name = &field.name;
+        if field.flatten {
+            quote! {
+                let mut #iter = <#ty as #krate::frame::row::FromDataFrame>::from_columns(
+                    df,
+                    &::std::format!("{}{}.", prefix, #name),
+                )?;
+            }
+        } else {
+            quote! {
+                let mut #iter = <#ty as #krate::frame::row::ColumnValue>::from_series(
+                    df.column(&::std::format!("{}{}", prefix, #name))?,
+                )?;
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #krate::frame::row::FromDataFrame for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn from_columns(
+                df: &#krate::frame::DataFrame,
+                prefix: &str,
+            ) -> #krate::error::Result<::std::boxed::Box<dyn ::std::iter::Iterator<Item = Self>>> {
+                #(#columns)*
+                let iter = ::std::iter::from_fn(move || {
+                    ::std::option::Option::Some(Self {
+                        #(
+                            #field_idents: #iters.next()?,
+                        )*
+                    })
+                });
+                // a struct without fields doesn't stop the iterator
+                ::std::result::Result::Ok(::std::boxed::Box::new(iter.take(df.height())))
+            }
+        }
+    })
+}
@@ -90,7 +90,7 @@ where
             None,
         );
 
-        finish_reader(avro_reader, rechunk, None, None, None, &schema, None)
+        finish_reader(avro_reader, rechunk, None, None, None, &schema, None, None)
     }
 }
 
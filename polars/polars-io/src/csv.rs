@@ -231,6 +231,7 @@ where
     #[cfg(feature = "temporal")]
     parse_dates: bool,
     row_count: Option<RowCount>,
+    cancel_token: Option<CancellationToken>,
 }
 
 impl<'a, R> CsvReader<'a, R>
@@ -249,6 +250,13 @@ where
         self
     }
 
+    /// Stop reading with [`PolarsError::Cancelled`] once `token` is cancelled. The token is
+    /// checked after every chunk of `chunk_size` rows.
+    pub fn with_cancel_token(mut self, token: Option<CancellationToken>) -> Self {
+        self.cancel_token = token;
+        self
+    }
+
     /// Sets the chunk size used by the parser. This influences performance
     pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
         self.chunk_size = chunk_size;
@@ -458,6 +466,7 @@ where
             #[cfg(feature = "temporal")]
             parse_dates: false,
             row_count: None,
+            cancel_token: None,
         }
     }
 
@@ -479,7 +488,7 @@ where
                     use DataType::*;
                     match fld.data_type() {
                         // For categorical we first read as utf8 and later cast to categorical
-                        Categorical => {
+                        Categorical | Enum(_) => {
                             to_cast_local.push(fld);
                             Some(Field::new(fld.name(), DataType::Utf8))
                         }
@@ -535,6 +544,7 @@ where
                 &to_cast,
                 self.skip_rows_after_header,
                 self.row_count,
+                self.cancel_token,
             )?;
             csv_reader.as_df()?
         } else {
@@ -565,6 +575,7 @@ where
                 &[],
                 self.skip_rows_after_header,
                 self.row_count,
+                self.cancel_token,
             )?;
             csv_reader.as_df()?
         };
@@ -7,10 +7,11 @@ use crate::predicates::PhysicalIoExpr;
 use crate::utils::update_row_counts;
 use crate::RowCount;
 use polars_arrow::array::*;
+use polars_core::config::Config;
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::{prelude::*, POOL};
 use rayon::prelude::*;
-use rayon::ThreadPoolBuilder;
+use rayon::{ThreadPool, ThreadPoolBuilder};
 use std::borrow::Cow;
 use std::fmt;
 use std::sync::atomic::Ordering;
@@ -79,6 +80,7 @@ pub(crate) struct CoreReader<'a> {
     aggregate: Option<&'a [ScanAggregation]>,
     to_cast: &'a [&'a Field],
     row_count: Option<RowCount>,
+    cancel_token: Option<CancellationToken>,
 }
 
 impl<'a> fmt::Debug for CoreReader<'a> {
@@ -98,6 +100,18 @@ pub(crate) struct RunningSize {
     last: AtomicUsize,
 }
 
+/// Run `op` on `pool` if given, otherwise on the thread pool of polars.
+fn install<OP, R>(pool: Option<&ThreadPool>, op: OP) -> R
+where
+    OP: FnOnce() -> R + Send,
+    R: Send,
+{
+    match pool {
+        Some(pool) => pool.install(op),
+        None => POOL.install(op),
+    }
+}
+
 fn compute_size_hint(max: usize, sum: usize, count: usize, last: usize) -> usize {
     let avg = (sum as f32 / count as f32) as usize;
     let size = std::cmp::max(last, avg) as f32;
@@ -167,6 +181,7 @@ impl<'a> CoreReader<'a> {
         to_cast: &'a [&'a Field],
         skip_rows_after_header: usize,
         row_count: Option<RowCount>,
+        cancel_token: Option<CancellationToken>,
     ) -> Result<CoreReader<'a>> {
         #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
         let mut reader_bytes = reader_bytes;
@@ -273,9 +288,17 @@ impl<'a> CoreReader<'a> {
             aggregate,
             to_cast,
             row_count,
+            cancel_token,
         })
     }
 
+    fn check_cancelled(&self) -> Result<()> {
+        match &self.cancel_token {
+            Some(token) => token.check(),
+            None => Ok(()),
+        }
+    }
+
     fn find_starting_point<'b>(&self, mut bytes: &'b [u8]) -> Result<&'b [u8]> {
         // Skip all leading white space and the occasional utf8-bom
         bytes = skip_whitespace(skip_bom(bytes));
@@ -309,7 +332,7 @@ impl<'a> CoreReader<'a> {
         bytes: &[u8],
         predicate: Option<&Arc<dyn PhysicalIoExpr>>,
     ) -> Result<DataFrame> {
-        let logging = std::env::var("POLARS_VERBOSE").is_ok();
+        let logging = Config::current().verbose;
         // Make the variable mutable so that we can reassign the sliced file to this variable.
         let mut bytes = self.find_starting_point(bytes)?;
 
@@ -405,18 +428,17 @@ impl<'a> CoreReader<'a> {
 
         // If the number of threads given by the user is lower than our global thread pool we create
         // new one.
-        let owned_pool;
-        let pool = if POOL.current_num_threads() != n_threads {
-            owned_pool = Some(
+        let owned_pool = if POOL.current_num_threads() != n_threads {
+            Some(
                 ThreadPoolBuilder::new()
                     .num_threads(n_threads)
                     .build()
                     .unwrap(),
-            );
-            owned_pool.as_ref().unwrap()
+            )
         } else {
-            &POOL
+            None
         };
+        let pool = owned_pool.as_ref();
 
         // all the buffers returned from the threads
         // Structure:
@@ -450,7 +472,7 @@ impl<'a> CoreReader<'a> {
                 return Ok(df);
             }
 
-            let dfs = pool.install(|| {
+            let dfs = install(pool, || {
                 file_chunks
                     .into_par_iter()
                     .map(|(bytes_offset_thread, stop_at_nbytes)| {
@@ -467,6 +489,7 @@ impl<'a> CoreReader<'a> {
                             if read >= stop_at_nbytes || read == last_read {
                                 break;
                             }
+                            self.check_cancelled()?;
 
                             let mut buffers = init_buffers(
                                 projection,
@@ -501,7 +524,7 @@ impl<'a> CoreReader<'a> {
                                     .map(|buf| buf.into_series())
                                     .collect::<Result<_>>()?,
                             );
-                            let current_row_count = local_df.height() as u32;
+                            let current_row_count = local_df.height() as IdxSize;
                             if let Some(rc) = &self.row_count {
                                 local_df.with_row_count_mut(&rc.name, Some(rc.offset));
                             };
@@ -569,8 +592,15 @@ impl<'a> CoreReader<'a> {
                 .iter()
                 .map(|_| RunningSize::new(init_str_bytes))
                 .collect();
+            // chunk size doesn't really matter anymore, less calls if we increase the size.
+            // A token is checked between the calls, so then we parse in chunks.
+            let lines_per_call = if self.cancel_token.is_some() {
+                chunk_size
+            } else {
+                usize::MAX
+            };
 
-            let mut dfs = pool.install(|| {
+            let mut dfs = install(pool, || {
                 file_chunks
                     .into_par_iter()
                     .map(|(bytes_offset_thread, stop_at_nbytes)| {
@@ -595,6 +625,7 @@ impl<'a> CoreReader<'a> {
                             if read >= stop_at_nbytes || read == last_read {
                                 break;
                             }
+                            self.check_cancelled()?;
                             let local_bytes = &bytes[read..stop_at_nbytes];
 
                             last_read = read;
@@ -608,9 +639,7 @@ impl<'a> CoreReader<'a> {
                                 projection,
                                 &mut buffers,
                                 ignore_parser_errors,
-                                // chunk size doesn't really matter anymore,
-                                // less calls if we increase the size
-                                usize::MAX,
+                                lines_per_call,
                                 self.schema.len(),
                             )?;
                         }
@@ -626,7 +655,7 @@ impl<'a> CoreReader<'a> {
                         if let Some(rc) = &self.row_count {
                             df.with_row_count_mut(&rc.name, Some(rc.offset));
                         }
-                        let n_read = df.height() as u32;
+                        let n_read = df.height() as IdxSize;
                         Ok((df, n_read))
                     })
                     .collect::<Result<Vec<_>>>()
@@ -32,7 +32,11 @@
 //! let df_read = IpcReader::new(buf).finish().unwrap();
 //! assert!(df.frame_equal(&df_read));
 //! ```
+#[cfg(feature = "lazy")]
+use super::read_batches;
 use super::{finish_reader, ArrowReader, ArrowResult};
+#[cfg(feature = "lazy")]
+use crate::predicates::arrow_schema_to_empty_df;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
 use ahash::AHashMap;
@@ -68,6 +72,7 @@ pub struct IpcReader<R> {
     projection: Option<Vec<usize>>,
     columns: Option<Vec<String>>,
     row_count: Option<RowCount>,
+    cancel_token: Option<CancellationToken>,
 }
 
 impl<R: Read + Seek> IpcReader<R> {
@@ -100,6 +105,13 @@ impl<R: Read + Seek> IpcReader<R> {
         self
     }
 
+    /// Stop reading with [`PolarsError::Cancelled`] once `token` is cancelled. The token is
+    /// checked before every record batch.
+    pub fn with_cancel_token(mut self, token: Option<CancellationToken>) -> Self {
+        self.cancel_token = token;
+        self
+    }
+
     /// Set the reader's column projection. This counts from 0, meaning that
     /// `vec![0, 4]` would select the 1st and 5th column.
     pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
@@ -108,14 +120,10 @@ impl<R: Read + Seek> IpcReader<R> {
     }
 
     #[cfg(feature = "lazy")]
-    // todo! hoist to lazy crate
-    pub fn finish_with_scan_ops(
-        mut self,
-        predicate: Option<Arc<dyn PhysicalIoExpr>>,
-        aggregate: Option<&[ScanAggregation]>,
+    fn scan_reader(
+        &mut self,
         projection: Option<&[usize]>,
-    ) -> Result<DataFrame> {
-        let rechunk = self.rechunk;
+    ) -> Result<(read::FileReader<&mut R>, ArrowSchema)> {
         let metadata = read::read_file_metadata(&mut self.reader)?;
         let projection = projection.map(|x| {
             let mut x = x.to_vec();
@@ -130,17 +138,67 @@ impl<R: Read + Seek> IpcReader<R> {
         };
 
         let reader = read::FileReader::new(&mut self.reader, metadata, projection);
+        Ok((reader, schema))
+    }
+
+    #[cfg(feature = "lazy")]
+    // todo! hoist to lazy crate
+    pub fn finish_with_scan_ops(
+        mut self,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        aggregate: Option<&[ScanAggregation]>,
+        projection: Option<&[usize]>,
+    ) -> Result<DataFrame> {
+        let rechunk = self.rechunk;
+        let n_rows = self.n_rows;
+        let row_count = self.row_count.take();
+        let cancel_token = self.cancel_token.take();
+        let (reader, schema) = self.scan_reader(projection)?;
 
         finish_reader(
             reader,
             rechunk,
-            self.n_rows,
+            n_rows,
             predicate,
             aggregate,
             &schema,
-            self.row_count,
+            row_count,
+            cancel_token,
         )
     }
+
+    /// Pass the record batches to `on_batch` one by one, so that the file doesn't have to fit
+    /// in memory. An empty file is passed as a single empty batch.
+    #[cfg(feature = "lazy")]
+    pub fn read_batches_with_scan_ops(
+        mut self,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        projection: Option<&[usize]>,
+        on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+    ) -> Result<()> {
+        let n_rows = self.n_rows;
+        let row_count = self.row_count.take();
+        let cancel_token = self.cancel_token.take();
+        let (reader, schema) = self.scan_reader(projection)?;
+
+        let mut is_empty = true;
+        read_batches(
+            reader,
+            n_rows,
+            predicate,
+            &schema,
+            row_count,
+            cancel_token,
+            &mut |df| {
+                is_empty = false;
+                on_batch(df)
+            },
+        )?;
+        if is_empty {
+            on_batch(arrow_schema_to_empty_df(&schema))?;
+        }
+        Ok(())
+    }
 }
 
 impl<R> ArrowReader for read::FileReader<R>
@@ -164,6 +222,7 @@ where
             columns: None,
             projection: None,
             row_count: None,
+            cancel_token: None,
         }
     }
 
@@ -227,6 +286,7 @@ where
             None,
             &schema,
             self.row_count,
+            self.cancel_token,
         )
     }
 }
@@ -89,28 +89,30 @@ pub trait ArrowReader {
     fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>>;
 }
 
+/// Read the record batches of `reader` one by one and pass them to `on_batch` after the
+/// row count and the predicate are applied.
 #[cfg(any(
     feature = "ipc",
     feature = "parquet",
     feature = "json",
     feature = "avro"
 ))]
-pub(crate) fn finish_reader<R: ArrowReader>(
+pub(crate) fn read_batches<R: ArrowReader>(
     mut reader: R,
-    rechunk: bool,
     n_rows: Option<usize>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
-    aggregate: Option<&[ScanAggregation]>,
     arrow_schema: &ArrowSchema,
     row_count: Option<RowCount>,
-) -> Result<DataFrame> {
-    use polars_core::utils::accumulate_dataframes_vertical;
-
+    cancel_token: Option<CancellationToken>,
+    on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+) -> Result<()> {
     let mut num_rows = 0;
-    let mut parsed_dfs = Vec::with_capacity(1024);
 
     while let Some(batch) = reader.next_record_batch()? {
-        let current_num_rows = num_rows as u32;
+        if let Some(token) = &cancel_token {
+            token.check()?;
+        }
+        let current_num_rows = num_rows as IdxSize;
         num_rows += batch.len();
         let mut df = DataFrame::try_from((batch, arrow_schema.fields.as_slice()))?;
 
@@ -124,9 +126,7 @@ pub(crate) fn finish_reader<R: ArrowReader>(
             df = df.filter(mask)?;
         }
 
-        apply_aggregations(&mut df, aggregate)?;
-
-        parsed_dfs.push(df);
+        on_batch(df)?;
 
         if let Some(n) = n_rows {
             if num_rows >= n {
@@ -134,6 +134,42 @@ pub(crate) fn finish_reader<R: ArrowReader>(
             }
         }
     }
+    Ok(())
+}
+
+#[cfg(any(
+    feature = "ipc",
+    feature = "parquet",
+    feature = "json",
+    feature = "avro"
+))]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn finish_reader<R: ArrowReader>(
+    reader: R,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    aggregate: Option<&[ScanAggregation]>,
+    arrow_schema: &ArrowSchema,
+    row_count: Option<RowCount>,
+    cancel_token: Option<CancellationToken>,
+) -> Result<DataFrame> {
+    use polars_core::utils::accumulate_dataframes_vertical;
+
+    let mut parsed_dfs = Vec::with_capacity(1024);
+    read_batches(
+        reader,
+        n_rows,
+        predicate,
+        arrow_schema,
+        row_count,
+        cancel_token,
+        &mut |mut df| {
+            apply_aggregations(&mut df, aggregate)?;
+            parsed_dfs.push(df);
+            Ok(())
+        },
+    )?;
     let mut df = accumulate_dataframes_vertical(parsed_dfs)?;
 
     // Aggregations must be applied a final time to aggregate the partitions
@@ -1,5 +1,7 @@
+use polars_core::prelude::IdxSize;
+
 #[derive(Clone, Debug)]
 pub struct RowCount {
     pub name: String,
-    pub offset: u32,
+    pub offset: IdxSize,
 }
@@ -102,4 +102,26 @@ mod test {
         assert_eq!(df_read.shape(), (3, 2));
         df_read.frame_equal(&expected);
     }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_read_parquet_batched() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3])?;
+        df.vstack_mut(&df!("a" => [4, 5])?)?;
+        ParquetWriter::new(&mut buf).finish(&df)?;
+        buf.set_position(0);
+
+        // a batch per row group, the last one cut off at the row limit
+        let mut batches = vec![];
+        ParquetReader::new(buf)
+            .with_n_rows(Some(4))
+            .read_batches_with_scan_ops(None, None, &mut |df| {
+                batches.push(df);
+                Ok(())
+            })?;
+        let heights = batches.iter().map(|df| df.height()).collect::<Vec<_>>();
+        assert_eq!(heights, &[3, 1]);
+        Ok(())
+    }
 }
@@ -10,7 +10,7 @@ use polars_core::prelude::*;
 /// - max value
 /// - min value
 /// - null_count
-#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Debug)]
 pub struct ColumnStats(Box<dyn Statistics>);
 
 impl ColumnStats {
@@ -84,6 +84,7 @@ impl ColumnStats {
 }
 
 /// A collection of column stats with a known schema.
+#[derive(Debug)]
 pub struct BatchStats {
     schema: Schema,
     stats: Vec<ColumnStats>,
@@ -1,6 +1,9 @@
 use crate::aggregations::ScanAggregation;
 use crate::mmap::MmapBytesReader;
+use crate::parquet::predicates::{collect_statistics, BatchStats};
 use crate::parquet::read_impl::read_parquet;
+#[cfg(feature = "lazy")]
+use crate::parquet::read_impl::read_parquet_batched;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
 use crate::RowCount;
@@ -19,6 +22,7 @@ pub struct ParquetReader<R: Read + Seek> {
     projection: Option<Vec<usize>>,
     parallel: bool,
     row_count: Option<RowCount>,
+    cancel_token: Option<CancellationToken>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -45,6 +49,7 @@ impl<R: MmapBytesReader> ParquetReader<R> {
             aggregate,
             self.parallel,
             self.row_count,
+            self.cancel_token,
         )
         .map(|mut df| {
             if rechunk {
@@ -54,6 +59,32 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         })
     }
 
+    /// Pass the row groups to `on_batch` one by one, so that the file doesn't have to fit in
+    /// memory. A file of which no row group is read is passed as a single empty batch.
+    #[cfg(feature = "lazy")]
+    pub fn read_batches_with_scan_ops(
+        mut self,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        projection: Option<&[usize]>,
+        on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+    ) -> Result<()> {
+        let metadata = read::read_metadata(&mut self.reader)?;
+        let schema = read::schema::infer_schema(&metadata)?;
+
+        read_parquet_batched(
+            self.reader,
+            self.n_rows.unwrap_or(usize::MAX),
+            projection,
+            &schema,
+            Some(metadata),
+            predicate,
+            self.parallel,
+            self.row_count,
+            self.cancel_token,
+            on_batch,
+        )
+    }
+
     /// Read the parquet file in parallel (default). The single threaded reader consumes less memory.
     pub fn read_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
@@ -86,12 +117,48 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Stop reading with [`PolarsError::Cancelled`] once `token` is cancelled. The token is
+    /// checked before every row group and column.
+    pub fn with_cancel_token(mut self, token: Option<CancellationToken>) -> Self {
+        self.cancel_token = token;
+        self
+    }
+
     pub fn schema(mut self) -> Result<Schema> {
         let metadata = read::read_metadata(&mut self.reader)?;
 
         let schema = read::infer_schema(&metadata)?;
         Ok(schema.into())
     }
+
+    /// Get the schema, and the number of rows and the column statistics of every row group in
+    /// the file. This only reads the metadata of the file.
+    #[allow(clippy::type_complexity)]
+    pub fn schema_and_statistics(mut self) -> Result<(Schema, Vec<(usize, Option<BatchStats>)>)> {
+        let metadata = read::read_metadata(&mut self.reader)?;
+        let schema = read::infer_schema(&metadata)?;
+
+        let row_groups = metadata
+            .row_groups
+            .iter()
+            .map(|md| {
+                let stats = collect_statistics(md.columns(), &schema)?;
+                Ok((md.num_rows() as usize, stats))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok((schema.into(), row_groups))
+    }
+
+    /// Get the total uncompressed size in bytes of the column data of all row groups.
+    /// This only reads the metadata of the file.
+    pub fn uncompressed_size(mut self) -> Result<usize> {
+        let metadata = read::read_metadata(&mut self.reader)?;
+        Ok(metadata
+            .row_groups
+            .iter()
+            .map(|md| md.total_byte_size() as usize)
+            .sum())
+    }
 }
 
 impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
@@ -104,6 +171,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             projection: None,
             parallel: true,
             row_count: None,
+            cancel_token: None,
         }
     }
 
@@ -136,6 +204,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             None,
             self.parallel,
             self.row_count,
+            self.cancel_token,
         )
         .map(|mut df| {
             if self.rechunk {
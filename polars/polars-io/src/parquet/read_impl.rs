@@ -17,40 +17,37 @@ use std::io::Cursor;
 use std::ops::Deref;
 use std::sync::Arc;
 
+/// Read the row groups of `bytes` one by one and pass them to `on_batch` after the row count
+/// and the predicate are applied. Row groups that the statistics rule out are skipped.
 #[allow(clippy::too_many_arguments)]
-pub fn read_parquet<R: MmapBytesReader>(
-    reader: R,
+fn read_row_groups(
+    bytes: &[u8],
     limit: usize,
-    projection: Option<&[usize]>,
+    projection: &[usize],
     schema: &ArrowSchema,
-    metadata: Option<FileMetaData>,
-    predicate: Option<Arc<dyn PhysicalIoExpr>>,
-    aggregate: Option<&[ScanAggregation]>,
+    file_metadata: &FileMetaData,
+    predicate: Option<&dyn PhysicalIoExpr>,
     parallel: bool,
     row_count: Option<RowCount>,
-) -> Result<DataFrame> {
-    let reader = ReaderBytes::from(&reader);
-    let bytes = reader.deref();
+    cancel_token: Option<CancellationToken>,
+    on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+) -> Result<()> {
     let mut reader = Cursor::new(bytes);
-
-    let file_metadata = metadata
-        .map(Ok)
-        .unwrap_or_else(|| read::read_metadata(&mut reader))?;
     let row_group_len = file_metadata.row_groups.len();
-
-    let projection = projection
-        .map(Cow::Borrowed)
-        .unwrap_or_else(|| Cow::Owned((0usize..schema.fields.len()).collect::<Vec<_>>()));
-
-    let mut dfs = Vec::with_capacity(row_group_len);
-
     let mut remaining_rows = limit;
+    let mut rows_read = 0;
+
+    let check_cancelled = || match &cancel_token {
+        Some(token) => token.check(),
+        None => Ok(()),
+    };
 
     let mut previous_row_count = 0;
     for rg in 0..row_group_len {
+        check_cancelled()?;
         let md = &file_metadata.row_groups[rg];
-        let current_row_count = md.num_rows() as u32;
-        if let Some(pred) = &predicate {
+        let current_row_count = md.num_rows() as IdxSize;
+        if let Some(pred) = predicate {
             if let Some(pred) = pred.as_stats_evaluator() {
                 if let Some(stats) = collect_statistics(md.columns(), schema)? {
                     let should_read = pred.should_read(&stats);
@@ -77,6 +74,7 @@ pub fn read_parquet<R: MmapBytesReader>(
                 projection
                     .par_iter()
                     .map(|column_i| {
+                        check_cancelled()?;
                         let mut reader = Cursor::new(bytes);
                         let field = &schema.fields[*column_i];
                         let columns = read::read_columns(&mut reader, md.columns(), &field.name)?;
@@ -90,7 +88,7 @@ pub fn read_parquet<R: MmapBytesReader>(
                             Some(arr) => arr?,
                             None => Arc::from(new_empty_array(field.data_type.clone())),
                         };
-                        Series::try_from((field.name.as_str(), arr))
+                        Series::try_from((field, arr))
                     })
                     .collect::<Result<Vec<_>>>()
             })?
@@ -107,7 +105,7 @@ pub fn read_parquet<R: MmapBytesReader>(
                         Some(arr) => arr?,
                         None => Arc::from(new_empty_array(field.data_type.clone())),
                     };
-                    Series::try_from((field.name.as_str(), arr))
+                    Series::try_from((field, arr))
                 })
                 .collect::<Result<Vec<_>>>()?
         };
@@ -119,23 +117,128 @@ pub fn read_parquet<R: MmapBytesReader>(
             df.with_row_count_mut(&rc.name, Some(previous_row_count + rc.offset));
         }
 
-        apply_predicate(&mut df, predicate.as_deref())?;
-        apply_aggregations(&mut df, aggregate)?;
+        apply_predicate(&mut df, predicate)?;
 
         previous_row_count += current_row_count;
-        dfs.push(df)
+        rows_read += df.height();
+        on_batch(df)?;
+        // the batches are in order, so the rows after the limit are never used
+        if rows_read >= limit {
+            break;
+        }
     }
+    Ok(())
+}
+
+/// The projected schema as an empty `DataFrame`.
+fn empty_df(schema: &ArrowSchema, projection: &Cow<[usize]>) -> DataFrame {
+    let schema = if let Cow::Borrowed(_) = projection {
+        Cow::Owned(apply_projection(schema, projection))
+    } else {
+        Cow::Borrowed(schema)
+    };
+    arrow_schema_to_empty_df(&schema)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_parquet<R: MmapBytesReader>(
+    reader: R,
+    limit: usize,
+    projection: Option<&[usize]>,
+    schema: &ArrowSchema,
+    metadata: Option<FileMetaData>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    aggregate: Option<&[ScanAggregation]>,
+    parallel: bool,
+    row_count: Option<RowCount>,
+    cancel_token: Option<CancellationToken>,
+) -> Result<DataFrame> {
+    let reader = ReaderBytes::from(&reader);
+    let bytes = reader.deref();
+
+    let file_metadata = metadata
+        .map(Ok)
+        .unwrap_or_else(|| read::read_metadata(&mut Cursor::new(bytes)))?;
+
+    let projection = projection
+        .map(Cow::Borrowed)
+        .unwrap_or_else(|| Cow::Owned((0usize..schema.fields.len()).collect::<Vec<_>>()));
+
+    let mut dfs = Vec::with_capacity(file_metadata.row_groups.len());
+    read_row_groups(
+        bytes,
+        limit,
+        &projection,
+        schema,
+        &file_metadata,
+        predicate.as_deref(),
+        parallel,
+        row_count,
+        cancel_token,
+        &mut |mut df| {
+            apply_aggregations(&mut df, aggregate)?;
+            dfs.push(df);
+            Ok(())
+        },
+    )?;
 
     if dfs.is_empty() {
-        let schema = if let Cow::Borrowed(_) = projection {
-            Cow::Owned(apply_projection(schema, &projection))
-        } else {
-            Cow::Borrowed(schema)
-        };
-        Ok(arrow_schema_to_empty_df(&schema))
+        Ok(empty_df(schema, &projection))
     } else {
         let mut df = accumulate_dataframes_vertical(dfs.into_iter())?;
         apply_aggregations(&mut df, aggregate)?;
         Ok(df.slice(0, limit))
     }
 }
+
+/// Pass the row groups to `on_batch` one by one, so that the file doesn't have to fit in
+/// memory. A file of which no row group is read is passed as a single empty batch.
+#[cfg(feature = "lazy")]
+#[allow(clippy::too_many_arguments)]
+pub fn read_parquet_batched<R: MmapBytesReader>(
+    reader: R,
+    limit: usize,
+    projection: Option<&[usize]>,
+    schema: &ArrowSchema,
+    metadata: Option<FileMetaData>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    parallel: bool,
+    row_count: Option<RowCount>,
+    cancel_token: Option<CancellationToken>,
+    on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+) -> Result<()> {
+    let reader = ReaderBytes::from(&reader);
+    let bytes = reader.deref();
+
+    let file_metadata = metadata
+        .map(Ok)
+        .unwrap_or_else(|| read::read_metadata(&mut Cursor::new(bytes)))?;
+
+    let projection = projection
+        .map(Cow::Borrowed)
+        .unwrap_or_else(|| Cow::Owned((0usize..schema.fields.len()).collect::<Vec<_>>()));
+
+    let mut remaining = limit;
+    let mut is_empty = true;
+    read_row_groups(
+        bytes,
+        limit,
+        &projection,
+        schema,
+        &file_metadata,
+        predicate.as_deref(),
+        parallel,
+        row_count,
+        cancel_token,
+        &mut |df| {
+            is_empty = false;
+            let df = df.slice(0, remaining);
+            remaining -= df.height();
+            on_batch(df)
+        },
+    )?;
+    if is_empty {
+        on_batch(empty_df(schema, &projection))?;
+    }
+    Ok(())
+}
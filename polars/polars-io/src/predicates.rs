@@ -19,7 +19,7 @@ pub trait StatsEvaluator {
     fn should_read(&self, stats: &crate::parquet::predicates::BatchStats) -> Result<bool>;
 }
 
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "ipc", feature = "parquet"))]
 pub(crate) fn arrow_schema_to_empty_df(schema: &ArrowSchema) -> DataFrame {
     let columns = schema
         .fields
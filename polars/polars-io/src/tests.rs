@@ -17,3 +17,20 @@ fn test_filter() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_csv_cancelled() -> Result<()> {
+    let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods1.csv";
+    let token = CancellationToken::new();
+    let df = CsvReader::from_path(path)?
+        .with_cancel_token(Some(token.clone()))
+        .finish()?;
+    assert!(df.height() > 0);
+
+    token.cancel();
+    let out = CsvReader::from_path(path)?
+        .with_cancel_token(Some(token))
+        .finish();
+    assert!(matches!(out, Err(PolarsError::Cancelled)));
+    Ok(())
+}
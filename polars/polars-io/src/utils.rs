@@ -2,6 +2,7 @@
 use crate::ArrowSchema;
 use dirs::home_dir;
 use polars_core::frame::DataFrame;
+use polars_core::prelude::IdxSize;
 use std::path::{Path, PathBuf};
 
 // used by python polars
@@ -28,7 +29,7 @@ pub(crate) fn apply_projection(schema: &ArrowSchema, projection: &[usize]) -> Ar
 
 /// Because of threading every row starts from `0` or from `offset`.
 /// We must correct that so that they are monotonically increasing.
-pub(crate) fn update_row_counts(dfs: &mut [(DataFrame, u32)]) {
+pub(crate) fn update_row_counts(dfs: &mut [(DataFrame, IdxSize)]) {
     if !dfs.is_empty() {
         let mut previous = dfs[0].1;
         for (df, n_read) in &mut dfs[1..] {
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use polars_core::prelude::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Categorical`] and [`DataType::Enum`].
+pub struct CategoricalNameSpace(pub(crate) Expr);
+
+impl CategoricalNameSpace {
+    /// Set the ordering used by `sort`, `min`/`max` and comparisons. This has no effect on
+    /// an `Enum`, which is always ordered by its categories.
+    pub fn set_ordering(self, ordering: CategoricalOrdering) -> Expr {
+        let function = move |s: Series| {
+            let mut ca = s.categorical()?.clone();
+            ca.set_ordering(ordering);
+            Ok(ca.into_series())
+        };
+        self.0
+            .map(function, GetOutput::same_type())
+            .with_fmt("cat.set_ordering")
+    }
+}
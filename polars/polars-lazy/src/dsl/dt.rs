@@ -1,4 +1,11 @@
 use super::*;
+use polars_time::{Duration, PolarsRound, PolarsTruncate};
+
+const MS_IN_DAY: i64 = 86_400_000;
+
+fn temporal_err(dtype: &DataType) -> PolarsError {
+    PolarsError::InvalidOperation(format!("operation not supported on dtype {:?}", dtype).into())
+}
 
 /// Specialized expressions for [`Series`] with dates/datetimes.
 pub struct DateLikeNameSpace(pub(crate) Expr);
@@ -11,4 +18,191 @@ impl DateLikeNameSpace {
             .map(function, GetOutput::from_type(DataType::Utf8))
             .with_fmt("strftime")
     }
+
+    /// Truncate the dates/datetimes to the start of their window of size `every`,
+    /// shifted by `offset`. Both are durations such as `"1mo"`, `"1w"` or `"2h15m"`,
+    /// see [`Duration::parse`]. An invalid duration is an error when the query runs.
+    pub fn truncate(self, every: &str, offset: &str) -> Expr {
+        let every = every.to_string();
+        let offset = offset.to_string();
+        let function = move |s: Series| {
+            let every = Duration::parse(&every)?;
+            let offset = Duration::parse(&offset)?;
+            match s.dtype() {
+                DataType::Datetime(_, _) => Ok(s.datetime()?.truncate(every, offset).into_series()),
+                #[cfg(feature = "dtype-date")]
+                DataType::Date => Ok(s.date()?.truncate(every, offset).into_series()),
+                dt => Err(temporal_err(dt)),
+            }
+        };
+        self.0
+            .map(function, GetOutput::same_type())
+            .with_fmt("dt.truncate")
+    }
+
+    /// Round the dates/datetimes to the closest window boundary of size `every`.
+    /// Values that are exactly halfway are rounded up. An invalid duration is an error when
+    /// the query runs.
+    pub fn round(self, every: &str) -> Expr {
+        let every = every.to_string();
+        let function = move |s: Series| {
+            let every = Duration::parse(&every)?;
+            let offset = Duration::parse("0ns")?;
+            match s.dtype() {
+                DataType::Datetime(_, _) => Ok(s.datetime()?.round(every, offset).into_series()),
+                #[cfg(feature = "dtype-date")]
+                DataType::Date => Ok(s.date()?.round(every, offset).into_series()),
+                dt => Err(temporal_err(dt)),
+            }
+        };
+        self.0
+            .map(function, GetOutput::same_type())
+            .with_fmt("dt.round")
+    }
+
+    /// Offset the dates/datetimes by a duration such as `"1mo2d"` or `"-3h"`.
+    ///
+    /// Calendar durations are calendar aware, e.g. adding `"1mo"` to January 31st gives the
+    /// last day of February. An invalid duration is an error when the query runs.
+    pub fn offset_by(self, by: &str) -> Expr {
+        let by = by.to_string();
+        let function = move |s: Series| {
+            let by = Duration::parse(&by)?;
+            match s.dtype() {
+                DataType::Datetime(tu, tz) => {
+                    let add = match tu {
+                        TimeUnit::Nanoseconds => Duration::add_ns,
+                        TimeUnit::Microseconds => Duration::add_us,
+                        TimeUnit::Milliseconds => Duration::add_ms,
+                    };
+                    Ok(s.datetime()?
+                        .apply(|t| add(&by, t))
+                        .into_datetime(*tu, tz.clone())
+                        .into_series())
+                }
+                #[cfg(feature = "dtype-date")]
+                DataType::Date => Ok(s
+                    .date()?
+                    .apply(|t| by.add_ms(t as i64 * MS_IN_DAY).div_euclid(MS_IN_DAY) as i32)
+                    .into_date()
+                    .into_series()),
+                dt => Err(temporal_err(dt)),
+            }
+        };
+        self.0
+            .map(function, GetOutput::same_type())
+            .with_fmt("dt.offset_by")
+    }
+
+    /// Get the time passed since the Unix epoch in the given time unit.
+    pub fn epoch(self, tu: TimeUnit) -> Expr {
+        let function = move |s: Series| match s.dtype() {
+            DataType::Datetime(_, _) | DataType::Date => s
+                .cast(&DataType::Datetime(tu, None))?
+                .cast(&DataType::Int64),
+            dt => Err(temporal_err(dt)),
+        };
+        self.0
+            .map(function, GetOutput::from_type(DataType::Int64))
+            .with_fmt("dt.epoch")
+    }
+
+    /// Convert the values to a different time unit, e.g. milliseconds to nanoseconds.
+    pub fn cast_time_unit(self, tu: TimeUnit) -> Expr {
+        let function = move |s: Series| match s.dtype() {
+            DataType::Datetime(_, tz) => s.cast(&DataType::Datetime(tu, tz.clone())),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(_) => s.cast(&DataType::Duration(tu)),
+            dt => Err(temporal_err(dt)),
+        };
+        self.0
+            .map(function, GetOutput::map_dtype(move |dt| with_unit(dt, tu)))
+            .with_fmt("dt.cast_time_unit")
+    }
+
+    /// Set the time unit without converting the underlying values, i.e. the integers are
+    /// reinterpreted in the new time unit.
+    pub fn with_time_unit(self, tu: TimeUnit) -> Expr {
+        let function = move |s: Series| match s.dtype() {
+            DataType::Datetime(_, _) => {
+                let mut ca = s.datetime()?.clone();
+                ca.set_time_unit(tu);
+                Ok(ca.into_series())
+            }
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(_) => {
+                let mut ca = s.duration()?.clone();
+                ca.set_time_unit(tu);
+                Ok(ca.into_series())
+            }
+            dt => Err(temporal_err(dt)),
+        };
+        self.0
+            .map(function, GetOutput::map_dtype(move |dt| with_unit(dt, tu)))
+            .with_fmt("dt.with_time_unit")
+    }
+
+    /// Get the quarter of the year, ranging from 1 to 4.
+    pub fn quarter(self) -> Expr {
+        let function = move |s: Series| s.quarter().map(|ca| ca.into_series());
+        self.0
+            .map(function, GetOutput::from_type(DataType::UInt32))
+            .with_fmt("dt.quarter")
+    }
+
+    /// Get the ISO year, i.e. the year of the ISO week. It can differ from the calendar year
+    /// in the first and last days of a year.
+    pub fn iso_year(self) -> Expr {
+        let function = move |s: Series| s.iso_year().map(|ca| ca.into_series());
+        self.0
+            .map(function, GetOutput::from_type(DataType::Int32))
+            .with_fmt("dt.iso_year")
+    }
+
+    /// Combine the dates with a [`DataType::Time`] expression to a datetime in time unit `tu`.
+    /// The time of datetimes is replaced.
+    pub fn combine(self, time: Expr, tu: TimeUnit) -> Expr {
+        let function = move |s: &mut [Series]| {
+            let tz = match s[0].dtype() {
+                DataType::Datetime(_, tz) => tz.clone(),
+                DataType::Date => None,
+                dt => return Err(temporal_err(dt)),
+            };
+            if s[1].dtype() != &DataType::Time {
+                return Err(temporal_err(s[1].dtype()));
+            }
+            // midnight of every date in the requested time unit
+            let date = s[0]
+                .cast(&DataType::Date)?
+                .cast(&DataType::Datetime(tu, None))?
+                .cast(&DataType::Int64)?;
+            // times are nanoseconds since midnight
+            let time = s[1].cast(&DataType::Int64)?;
+            let time = match tu {
+                TimeUnit::Nanoseconds => time,
+                TimeUnit::Microseconds => &time / 1_000,
+                TimeUnit::Milliseconds => &time / 1_000_000,
+            };
+            let out = &date + &time;
+            Ok(out.i64()?.clone().into_datetime(tu, tz).into_series())
+        };
+        self.0
+            .map_many(
+                function,
+                &[time],
+                GetOutput::map_dtype(move |dt| match dt {
+                    DataType::Datetime(_, tz) => DataType::Datetime(tu, tz.clone()),
+                    _ => DataType::Datetime(tu, None),
+                }),
+            )
+            .with_fmt("dt.combine")
+    }
+}
+
+fn with_unit(dtype: &DataType, tu: TimeUnit) -> DataType {
+    match dtype {
+        DataType::Datetime(_, tz) => DataType::Datetime(tu, tz.clone()),
+        DataType::Duration(_) => DataType::Duration(tu),
+        dt => dt.clone(),
+    }
 }
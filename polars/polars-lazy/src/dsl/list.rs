@@ -1,4 +1,7 @@
+use crate::physical_plan::planner::DefaultPlanner;
+use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
+use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
 
 /// Specialized expressions for [`Series`] of [`DataType::List`].
@@ -140,4 +143,160 @@ impl ListNameSpace {
             )
             .with_fmt("arr.join")
     }
+    fn set_operation(self, other: Expr, op: SetOperation, fmt_str: &'static str) -> Expr {
+        let function = move |s: &mut [Series]| {
+            Ok(s[0]
+                .list()?
+                .lst_set_operation(s[1].list()?, op)?
+                .into_series())
+        };
+        self.0
+            .map_many(function, &[other], GetOutput::same_type())
+            .with_fmt(fmt_str)
+    }
+
+    /// Get the items that are in either the sublist or the sublist of `other`, every item once.
+    pub fn union(self, other: Expr) -> Expr {
+        self.set_operation(other, SetOperation::Union, "arr.union")
+    }
+
+    /// Get the items that are in both the sublist and the sublist of `other`.
+    pub fn intersection(self, other: Expr) -> Expr {
+        self.set_operation(other, SetOperation::Intersection, "arr.intersection")
+    }
+
+    /// Get the items of the sublist that are not in the sublist of `other`.
+    pub fn difference(self, other: Expr) -> Expr {
+        self.set_operation(other, SetOperation::Difference, "arr.difference")
+    }
+
+    /// Get the items that are in exactly one of the sublist and the sublist of `other`.
+    pub fn symmetric_difference(self, other: Expr) -> Expr {
+        self.set_operation(
+            other,
+            SetOperation::SymmetricDifference,
+            "arr.symmetric_difference",
+        )
+    }
+
+    /// Check if the sublists contain `value`.
+    pub fn contains(self, value: Expr) -> Expr {
+        let function = |s: &mut [Series]| Ok(s[0].list()?.lst_contains(&s[1])?.into_series());
+        self.0
+            .map_many(function, &[value], GetOutput::from_type(DataType::Boolean))
+            .with_fmt("arr.contains")
+    }
+
+    /// Count the items of every sublist that are equal to `value`.
+    pub fn count_match(self, value: Expr) -> Expr {
+        let function = |s: &mut [Series]| Ok(s[0].list()?.lst_count_match(&s[1])?.into_series());
+        self.0
+            .map_many(function, &[value], GetOutput::from_type(DataType::UInt32))
+            .with_fmt("arr.count_match")
+    }
+
+    /// Run an expression on the items of every sublist. The items are referred to with
+    /// `col("")`, e.g. `col("a").arr().eval(col("").filter(col("").gt(lit(0))))`.
+    ///
+    /// The items of all sublists are evaluated at once by the physical expression engine with
+    /// a group per sublist, so this runs in parallel and doesn't need a UDF. The result is
+    /// always a list; aggregations produce sublists of a single item. Null sublists stay null.
+    pub fn eval(self, expr: Expr) -> Expr {
+        let expr2 = expr.clone();
+        Expr::Function {
+            input: vec![self.0],
+            function: NoEq::new(Arc::new(ListEval { expr })),
+            output_type: GetOutput::map_field(move |f| {
+                let inner = match f.data_type() {
+                    DataType::List(inner) => *inner.clone(),
+                    dt => dt.clone(),
+                };
+                Field::new(
+                    f.name(),
+                    DataType::List(Box::new(eval_dtype(&expr2, inner))),
+                )
+            }),
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::ApplyFlat,
+                input_wildcard_expansion: false,
+                auto_explode: false,
+                fmt_str: "arr.eval",
+                cast_to_float: false,
+            },
+        }
+    }
+}
+
+/// The data type of `expr` evaluated on items of type `inner`.
+fn eval_dtype(expr: &Expr, inner: DataType) -> DataType {
+    let schema = Schema::new(vec![Field::new("", inner.clone())]);
+    expr.to_field(&schema, Context::Default)
+        .map(|f| f.data_type().clone())
+        .unwrap_or(inner)
+}
+
+/// The function of [`ListNameSpace::eval`].
+struct ListEval {
+    expr: Expr,
+}
+
+impl ListEval {
+    fn eval(&self, s: &Series, state: &ExecutionState) -> Result<Series> {
+        let ca = s.list()?.rechunk();
+        let inner_dtype = ca.inner_dtype();
+        let arr = match ca.downcast_iter().next() {
+            Some(arr) if !ca.is_empty() => arr,
+            _ => {
+                let dtype = DataType::List(Box::new(eval_dtype(&self.expr, inner_dtype)));
+                return Ok(Series::full_null(s.name(), 0, &dtype));
+            }
+        };
+        let offsets = arr.offsets().as_slice();
+        let first = offsets[0];
+        let values = arr.values().slice(
+            first as usize,
+            (offsets[offsets.len() - 1] - first) as usize,
+        );
+        let values = Series::try_from(("", Arc::from(values)))?.cast(&inner_dtype)?;
+
+        // every sublist is a group that is a slice of the values, null sublists are empty
+        let groups = offsets
+            .windows(2)
+            .map(|w| [(w[0] - first) as IdxSize, (w[1] - w[0]) as IdxSize])
+            .collect::<Vec<_>>();
+        let groups = GroupsProxy::Slice(groups);
+
+        let mut arena = Arena::with_capacity(10);
+        let node = to_aexpr(self.expr.clone(), &mut arena);
+        let phys_expr = DefaultPlanner::default().create_physical_expr(
+            node,
+            Context::Aggregation,
+            &mut arena,
+        )?;
+        let df = DataFrame::new_no_checks(vec![values]);
+        let mut ac = phys_expr.evaluate_on_groups(&df, &groups, state)?;
+        let mut out = ac.aggregated();
+
+        // aggregations return a single value per group
+        if !matches!(out.dtype(), DataType::List(_)) {
+            let groups = (0..out.len() as IdxSize).map(|i| [i, 1]).collect();
+            out = out.agg_list(&GroupsProxy::Slice(groups)).unwrap();
+        }
+        if ca.null_count() > 0 {
+            let nulls = Series::full_null("", out.len(), out.dtype());
+            out = out.zip_with(&ca.is_not_null(), &nulls)?;
+        }
+        out.rename(s.name());
+        Ok(out)
+    }
+}
+
+impl SeriesUdf for ListEval {
+    fn call_udf(&self, s: &mut [Series]) -> Result<Series> {
+        self.eval(&s[0], &ExecutionState::new())
+    }
+
+    fn call_udf_with_state(&self, s: &mut [Series], state: &ExecutionState) -> Result<Series> {
+        self.eval(&s[0], &state.split())
+    }
 }
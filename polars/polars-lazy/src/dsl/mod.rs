@@ -1,4 +1,6 @@
 //! Domain specific language for the Lazy api.
+#[cfg(feature = "dtype-categorical")]
+mod cat;
 #[cfg(feature = "temporal")]
 mod dt;
 #[cfg(feature = "list")]
@@ -8,6 +10,7 @@ mod options;
 pub mod string;
 
 use crate::logical_plan::Context;
+use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use crate::utils::{has_expr, has_root_literal_expr};
 use polars_arrow::prelude::QuantileInterpolOptions;
@@ -31,10 +34,20 @@ use polars_arrow::array::default_arrays::FromData;
 #[cfg(feature = "diff")]
 use polars_core::series::ops::NullBehavior;
 use polars_core::utils::{get_supertype, NoNull};
+#[cfg(feature = "remap")]
+use std::collections::HashMap;
 
 /// A wrapper trait for any closure `Fn(Vec<Series>) -> Result<Series>`
 pub trait SeriesUdf: Send + Sync {
     fn call_udf(&self, s: &mut [Series]) -> Result<Series>;
+
+    /// Called by the physical expressions with the state of the running query. Functions
+    /// that evaluate expressions themselves override this, so that they stop when the query
+    /// is cancelled and use the settings of the query.
+    #[doc(hidden)]
+    fn call_udf_with_state(&self, s: &mut [Series], _state: &ExecutionState) -> Result<Series> {
+        self.call_udf(s)
+    }
 }
 
 impl<F> SeriesUdf for F
@@ -388,6 +401,26 @@ pub fn binary_expr(l: Expr, op: Operator, r: Expr) -> Expr {
     }
 }
 
+/// The first index of every unique value and the number of occurrences of the value, in order
+/// of first appearance or, if `sort`, by their count from high to low.
+fn value_counts_impl(s: &Series, sort: bool) -> (IdxCa, IdxCa) {
+    let mut counts = match s.group_tuples(true, true) {
+        GroupsProxy::Idx(groups) => groups
+            .iter()
+            .map(|(first, all)| (first, all.len() as IdxSize))
+            .collect::<Vec<_>>(),
+        GroupsProxy::Slice(groups) => groups.iter().map(|&[first, len]| (first, len)).collect(),
+    };
+    if sort {
+        counts.sort_by(|a, b| b.1.cmp(&a.1));
+    }
+    let (first, counts): (Vec<_>, Vec<_>) = counts.into_iter().unzip();
+    (
+        IdxCa::from_vec("", first),
+        IdxCa::from_vec("counts", counts),
+    )
+}
+
 /// Intermediate state of `when(..).then(..).otherwise(..)` expr.
 #[derive(Clone)]
 pub struct When {
@@ -740,11 +773,150 @@ impl Expr {
         );
         self.apply(
             |s: Series| s.arg_unique().map(|ca| ca.into_series()),
-            GetOutput::from_type(DataType::UInt32),
+            GetOutput::from_type(IDX_DTYPE),
         )
         .with_fmt("arg_unique")
     }
 
+    /// Count the occurrences of the unique values. Returns an expression with the unique values
+    /// and an expression with their counts of type `IdxSize`, named `"counts"`. If `sort`, the
+    /// values are sorted by their count from high to low, otherwise they are in order of first
+    /// appearance.
+    ///
+    /// There is no struct type to hold both in one column, so each expression groups the input.
+    /// In a groupby aggregation the values and counts are computed per group.
+    pub fn value_counts(self, sort: bool) -> [Expr; 2] {
+        let values = self
+            .clone()
+            .apply(
+                move |s: Series| {
+                    let (first, _) = value_counts_impl(&s, sort);
+                    s.take(&first)
+                },
+                GetOutput::same_type(),
+            )
+            .with_fmt("value_counts");
+        let counts = self
+            .apply(
+                move |s: Series| {
+                    let (_, counts) = value_counts_impl(&s, sort);
+                    Ok(counts.into_series())
+                },
+                GetOutput::from_type(IDX_DTYPE),
+            )
+            .with_fmt("value_counts")
+            .alias("counts");
+        [values, counts]
+    }
+
+    /// Count the occurrences of the unique values, in order of first appearance.
+    pub fn unique_counts(self) -> Self {
+        self.apply(
+            |s: Series| {
+                let mut counts = s.group_tuples(true, true).group_count();
+                counts.rename(s.name());
+                Ok(counts.into_series())
+            },
+            GetOutput::from_type(IDX_DTYPE),
+        )
+        .with_fmt("unique_counts")
+    }
+
+    /// Get the `k` largest values, sorted from large to small. If `reverse`, get the `k`
+    /// smallest values, sorted from small to large. Null values are ignored.
+    ///
+    /// Unlike `sort` followed by `head`, only the `k` selected values are sorted. In a groupby
+    /// aggregation the values are selected per group.
+    #[cfg(feature = "top_k")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "top_k")))]
+    pub fn top_k(self, k: usize, reverse: bool) -> Self {
+        self.apply(move |s: Series| s.top_k(k, reverse), GetOutput::same_type())
+            .with_fmt("top_k")
+    }
+
+    /// Assign the values to the bins between `breaks`, returning the `Categorical` label of
+    /// the bin of every value. See [`Series::cut`] for the arguments.
+    ///
+    /// Like [`qcut`](Expr::qcut), in a groupby aggregation the values are binned per group.
+    #[cfg(feature = "cut")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cut")))]
+    pub fn cut(self, breaks: Vec<f64>, labels: Option<Vec<String>>, left_closed: bool) -> Self {
+        self.apply(
+            move |s: Series| s.cut(&breaks, labels.as_deref(), left_closed),
+            GetOutput::from_type(DataType::Categorical),
+        )
+        .with_fmt("cut")
+    }
+
+    /// Assign the values to bins with (about) equal counts, using the given `quantiles` of the
+    /// values as bin edges. In a groupby aggregation the quantiles are computed per group.
+    #[cfg(feature = "cut")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cut")))]
+    pub fn qcut(self, quantiles: Vec<f64>) -> Self {
+        self.apply(
+            move |s: Series| s.qcut(&quantiles, None, false),
+            GetOutput::from_type(DataType::Categorical),
+        )
+        .with_fmt("qcut")
+    }
+
+    /// Remap the values with a `mapping` from old values to new values. The output has the
+    /// dtype of the new values. Values that are not in the `mapping` get the `default`, or null
+    /// if there is no `default`.
+    #[cfg(feature = "remap")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "remap")))]
+    pub fn map_dict(
+        self,
+        mapping: HashMap<AnyValue<'static>, AnyValue<'static>>,
+        default: Option<Expr>,
+    ) -> Self {
+        let (old, new): (Vec<_>, Vec<_>) = mapping.into_iter().unzip();
+        // errors are raised once the expression is executed
+        let remap = Series::from_any_values("", &old)
+            .and_then(|old| Ok((old, Series::from_any_values("", &new)?)))
+            .map_err(|e| e.to_string());
+        let output_type = match &remap {
+            Ok((_, new)) => GetOutput::from_type(new.dtype().clone()),
+            Err(_) => GetOutput::same_type(),
+        };
+        let remap = move |s: &Series, default: Option<&Series>| match &remap {
+            Ok((old, new)) => s.remap(old, new, default),
+            Err(e) => Err(PolarsError::ComputeError(e.clone().into())),
+        };
+
+        match default {
+            Some(default) => self
+                .map_many(
+                    move |s: &mut [Series]| remap(&s[0], Some(&s[1])),
+                    &[default],
+                    output_type,
+                )
+                .with_fmt("map_dict"),
+            None => self
+                .map(move |s: Series| remap(&s, None), output_type)
+                .with_fmt("map_dict"),
+        }
+    }
+
+    /// Replace the values that equal `old[i]` with `new[i]` and keep all other values. The
+    /// output has the supertype of the input and the new values.
+    #[cfg(feature = "remap")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "remap")))]
+    pub fn replace(self, old: Series, new: Series) -> Self {
+        let new_dtype = new.dtype().clone();
+        self.map(
+            move |s: Series| s.replace(&old, &new),
+            GetOutput::map_dtype(move |dt| match (dt, &new_dtype) {
+                (dt @ (DataType::Categorical | DataType::Enum(_)), DataType::Utf8) => dt.clone(),
+                (DataType::Categorical | DataType::Enum(_), _) => {
+                    get_supertype(&DataType::Utf8, &new_dtype).unwrap_or(DataType::Utf8)
+                }
+                _ => get_supertype(dt, &new_dtype).unwrap_or_else(|_| dt.clone()),
+            }),
+        )
+        .with_fmt("replace")
+    }
+
     /// Get the index value that has the minumum value
     pub fn arg_min(self) -> Self {
         let options = FunctionOptions {
@@ -752,11 +924,17 @@ impl Expr {
             input_wildcard_expansion: false,
             auto_explode: true,
             fmt_str: "arg_min",
+            cast_to_float: false,
         };
 
         self.function_with_options(
-            move |s: Series| Ok(Series::new(s.name(), &[s.arg_min().map(|idx| idx as u32)])),
-            GetOutput::from_type(DataType::UInt32),
+            move |s: Series| {
+                Ok(Series::new(
+                    s.name(),
+                    &[s.arg_min().map(|idx| idx as IdxSize)],
+                ))
+            },
+            GetOutput::from_type(IDX_DTYPE),
             options,
         )
     }
@@ -768,11 +946,17 @@ impl Expr {
             input_wildcard_expansion: false,
             auto_explode: true,
             fmt_str: "arg_max",
+            cast_to_float: false,
         };
 
         self.function_with_options(
-            move |s: Series| Ok(Series::new(s.name(), &[s.arg_max().map(|idx| idx as u32)])),
-            GetOutput::from_type(DataType::UInt32),
+            move |s: Series| {
+                Ok(Series::new(
+                    s.name(),
+                    &[s.arg_max().map(|idx| idx as IdxSize)],
+                ))
+            },
+            GetOutput::from_type(IDX_DTYPE),
             options,
         )
     }
@@ -788,11 +972,12 @@ impl Expr {
             input_wildcard_expansion: false,
             auto_explode: false,
             fmt_str: "arg_sort",
+            cast_to_float: false,
         };
 
         self.function_with_options(
             move |s: Series| Ok(s.argsort(reverse).into_series()),
-            GetOutput::from_type(DataType::UInt32),
+            GetOutput::from_type(IDX_DTYPE),
             options,
         )
     }
@@ -872,6 +1057,7 @@ impl Expr {
                 input_wildcard_expansion: false,
                 auto_explode: false,
                 fmt_str: "map",
+                cast_to_float: false,
             },
         }
     }
@@ -895,6 +1081,7 @@ impl Expr {
                 input_wildcard_expansion: false,
                 auto_explode: false,
                 fmt_str: "",
+                cast_to_float: false,
             },
         }
     }
@@ -921,6 +1108,7 @@ impl Expr {
                 input_wildcard_expansion: false,
                 auto_explode: false,
                 fmt_str: "",
+                cast_to_float: false,
             },
         }
     }
@@ -969,6 +1157,7 @@ impl Expr {
                 input_wildcard_expansion: false,
                 auto_explode: false,
                 fmt_str: "",
+                cast_to_float: false,
             },
         }
     }
@@ -992,6 +1181,7 @@ impl Expr {
                 input_wildcard_expansion: false,
                 auto_explode: true,
                 fmt_str: "",
+                cast_to_float: false,
             },
         }
     }
@@ -1212,6 +1402,159 @@ impl Expr {
             .with_fmt("abs")
     }
 
+    /// Apply a floating point function. Integer inputs are cast to `Float64` by the type
+    /// coercion optimization, `Float32` inputs stay `Float32`.
+    #[cfg(feature = "math")]
+    fn float_function<F>(self, function: F, fmt_str: &'static str) -> Self
+    where
+        F: Fn(Series) -> Result<Series> + 'static + Send + Sync,
+    {
+        let options = FunctionOptions {
+            collect_groups: ApplyOptions::ApplyFlat,
+            input_wildcard_expansion: false,
+            auto_explode: false,
+            fmt_str,
+            cast_to_float: true,
+        };
+        self.function_with_options(
+            function,
+            GetOutput::map_dtype(|dt| match dt {
+                DataType::Float32 => DataType::Float32,
+                _ => DataType::Float64,
+            }),
+            options,
+        )
+    }
+
+    /// Compute the square root of the values.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn sqrt(self) -> Self {
+        self.float_function(|s| s.sqrt(), "sqrt")
+    }
+
+    /// Compute the cube root of the values.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn cbrt(self) -> Self {
+        self.float_function(|s| s.cbrt(), "cbrt")
+    }
+
+    /// Compute `e^x` of the values.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn exp(self) -> Self {
+        self.float_function(|s| s.exp(), "exp")
+    }
+
+    /// Compute the logarithm of the values to the given `base`.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn log(self, base: f64) -> Self {
+        self.float_function(move |s| s.log(base), "log")
+    }
+
+    /// Compute `ln(1 + x)` of the values, which is more accurate than `log` for values
+    /// close to zero.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn log1p(self) -> Self {
+        self.float_function(|s| s.log1p(), "log1p")
+    }
+
+    /// Compute the sine of the values in radians.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn sin(self) -> Self {
+        self.float_function(|s| s.sin(), "sin")
+    }
+
+    /// Compute the cosine of the values in radians.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn cos(self) -> Self {
+        self.float_function(|s| s.cos(), "cos")
+    }
+
+    /// Compute the tangent of the values in radians.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn tan(self) -> Self {
+        self.float_function(|s| s.tan(), "tan")
+    }
+
+    /// Compute the inverse sine of the values, in radians.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn arcsin(self) -> Self {
+        self.float_function(|s| s.arcsin(), "arcsin")
+    }
+
+    /// Compute the inverse cosine of the values, in radians.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn arccos(self) -> Self {
+        self.float_function(|s| s.arccos(), "arccos")
+    }
+
+    /// Compute the inverse tangent of the values, in radians.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn arctan(self) -> Self {
+        self.float_function(|s| s.arctan(), "arctan")
+    }
+
+    /// Compute the hyperbolic sine of the values.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn sinh(self) -> Self {
+        self.float_function(|s| s.sinh(), "sinh")
+    }
+
+    /// Compute the hyperbolic cosine of the values.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn cosh(self) -> Self {
+        self.float_function(|s| s.cosh(), "cosh")
+    }
+
+    /// Compute the hyperbolic tangent of the values.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn tanh(self) -> Self {
+        self.float_function(|s| s.tanh(), "tanh")
+    }
+
+    /// Compute the inverse hyperbolic sine of the values.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn arcsinh(self) -> Self {
+        self.float_function(|s| s.arcsinh(), "arcsinh")
+    }
+
+    /// Compute the inverse hyperbolic cosine of the values.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn arccosh(self) -> Self {
+        self.float_function(|s| s.arccosh(), "arccosh")
+    }
+
+    /// Compute the inverse hyperbolic tangent of the values.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn arctanh(self) -> Self {
+        self.float_function(|s| s.arctanh(), "arctanh")
+    }
+
+    /// Get the sign of the values: `-1`, `0` or `1`. Integers keep their data type.
+    /// `NaN` stays `NaN`.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn sign(self) -> Self {
+        self.map(move |s: Series| s.sign(), GetOutput::same_type())
+            .with_fmt("sign")
+    }
+
     /// Apply window function over a subgroup.
     /// This is similar to a groupby + aggregation + self join.
     /// Or similar to [window functions in Postgres](https://www.postgresql.org/docs/9.1/tutorial-window.html).
@@ -1401,7 +1744,7 @@ impl Expr {
     #[cfg(feature = "temporal")]
     pub fn year(self) -> Expr {
         let function = move |s: Series| s.year().map(|ca| ca.into_series());
-        self.map(function, GetOutput::from_type(DataType::UInt32))
+        self.map(function, GetOutput::from_type(DataType::Int32))
             .with_fmt("year")
     }
 
@@ -1807,7 +2150,7 @@ impl Expr {
             move |s| Ok(s.rank(options)),
             GetOutput::map_field(move |fld| match options.method {
                 RankMethod::Average => Field::new(fld.name(), DataType::Float32),
-                _ => Field::new(fld.name(), DataType::UInt32),
+                _ => Field::new(fld.name(), IDX_DTYPE),
             }),
         )
         .with_fmt("rank")
@@ -1960,18 +2303,18 @@ impl Expr {
         self.apply(
             move |s| {
                 if reverse {
-                    let ca: NoNull<UInt32Chunked> = (0u32..s.len() as u32).rev().collect();
+                    let ca: NoNull<IdxCa> = (0..s.len() as IdxSize).rev().collect();
                     let mut ca = ca.into_inner();
                     ca.rename(s.name());
                     Ok(ca.into_series())
                 } else {
-                    let ca: NoNull<UInt32Chunked> = (0u32..s.len() as u32).collect();
+                    let ca: NoNull<IdxCa> = (0..s.len() as IdxSize).collect();
                     let mut ca = ca.into_inner();
                     ca.rename(s.name());
                     Ok(ca.into_series())
                 }
             },
-            GetOutput::from_type(DataType::UInt32),
+            GetOutput::from_type(IDX_DTYPE),
         )
         .with_fmt("cumcount")
     }
@@ -2074,6 +2417,10 @@ impl Expr {
     pub fn arr(self) -> list::ListNameSpace {
         list::ListNameSpace(self)
     }
+    #[cfg(feature = "dtype-categorical")]
+    pub fn cat(self) -> cat::CategoricalNameSpace {
+        cat::CategoricalNameSpace(self)
+    }
 }
 
 // Arithmetic ops
@@ -2142,6 +2489,7 @@ where
             input_wildcard_expansion: false,
             auto_explode: false,
             fmt_str: "",
+            cast_to_float: false,
         },
     }
 }
@@ -2169,6 +2517,7 @@ where
             input_wildcard_expansion: false,
             auto_explode: true,
             fmt_str: "",
+            cast_to_float: false,
         },
     }
 }
@@ -2198,6 +2547,7 @@ where
             input_wildcard_expansion: false,
             auto_explode: true,
             fmt_str: "",
+            cast_to_float: false,
         },
     }
 }
@@ -5,6 +5,44 @@ use polars_arrow::array::ValueSize;
 pub struct StringNameSpace(pub(crate) Expr);
 
 impl StringNameSpace {
+    /// Check if strings start with a prefix.
+    pub fn starts_with(self, prefix: &str) -> Expr {
+        let prefix = prefix.to_string();
+        let function = move |s: Series| Ok(s.utf8()?.starts_with(&prefix).into_series());
+        self.0
+            .map(function, GetOutput::from_type(DataType::Boolean))
+            .with_fmt("str.starts_with")
+    }
+
+    /// Check if strings end with a suffix.
+    pub fn ends_with(self, suffix: &str) -> Expr {
+        let suffix = suffix.to_string();
+        let function = move |s: Series| Ok(s.utf8()?.ends_with(&suffix).into_series());
+        self.0
+            .map(function, GetOutput::from_type(DataType::Boolean))
+            .with_fmt("str.ends_with")
+    }
+
+    /// Count the non-overlapping matches of a regex pattern.
+    pub fn count_matches(self, pat: &str) -> Expr {
+        let pat = pat.to_string();
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            ca.count_matches(&pat).map(|ca| ca.into_series())
+        };
+        self.0
+            .map(function, GetOutput::from_type(DataType::UInt32))
+            .with_fmt("str.count_matches")
+    }
+
+    /// Get the number of characters of the strings.
+    pub fn n_chars(self) -> Expr {
+        let function = move |s: Series| Ok(s.utf8()?.n_chars().into_series());
+        self.0
+            .map(function, GetOutput::from_type(DataType::UInt32))
+            .with_fmt("str.n_chars")
+    }
+
     pub fn extract(self, pat: &str, group_index: usize) -> Expr {
         let pat = pat.to_string();
         let function = move |s: Series| {
@@ -16,6 +54,73 @@ impl StringNameSpace {
             .with_fmt("str.extract")
     }
 
+    /// Extract all non-overlapping matches of a regex pattern in a list.
+    pub fn extract_all(self, pat: &str) -> Expr {
+        let pat = pat.to_string();
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            ca.extract_all(&pat).map(|ca| ca.into_series())
+        };
+        self.0
+            .map(
+                function,
+                GetOutput::from_type(DataType::List(Box::new(DataType::Utf8))),
+            )
+            .with_fmt("str.extract_all")
+    }
+
+    /// Remove leading and trailing characters. If `chars` is `None`, whitespace is removed.
+    pub fn strip(self, chars: Option<&str>) -> Expr {
+        let chars = chars.map(|chars| chars.to_string());
+        let function = move |s: Series| Ok(s.utf8()?.strip(chars.as_deref()).into_series());
+        self.0
+            .map(function, GetOutput::same_type())
+            .with_fmt("str.strip")
+    }
+
+    /// Remove leading characters. If `chars` is `None`, whitespace is removed.
+    pub fn lstrip(self, chars: Option<&str>) -> Expr {
+        let chars = chars.map(|chars| chars.to_string());
+        let function = move |s: Series| Ok(s.utf8()?.lstrip(chars.as_deref()).into_series());
+        self.0
+            .map(function, GetOutput::same_type())
+            .with_fmt("str.lstrip")
+    }
+
+    /// Remove trailing characters. If `chars` is `None`, whitespace is removed.
+    pub fn rstrip(self, chars: Option<&str>) -> Expr {
+        let chars = chars.map(|chars| chars.to_string());
+        let function = move |s: Series| Ok(s.utf8()?.rstrip(chars.as_deref()).into_series());
+        self.0
+            .map(function, GetOutput::same_type())
+            .with_fmt("str.rstrip")
+    }
+
+    /// Pad the strings with zeros on the left to `width` characters.
+    /// A leading sign is kept in front of the zeros.
+    pub fn zfill(self, width: usize) -> Expr {
+        let function = move |s: Series| Ok(s.utf8()?.zfill(width).into_series());
+        self.0
+            .map(function, GetOutput::same_type())
+            .with_fmt("str.zfill")
+    }
+
+    /// Pad the strings on the right with `fill` to `width` characters.
+    pub fn ljust(self, width: usize, fill: char) -> Expr {
+        let function = move |s: Series| Ok(s.utf8()?.ljust(width, fill).into_series());
+        self.0
+            .map(function, GetOutput::same_type())
+            .with_fmt("str.ljust")
+    }
+
+    /// Pad the strings on the left with `fill` to `width` characters.
+    pub fn rjust(self, width: usize, fill: char) -> Expr {
+        let function = move |s: Series| Ok(s.utf8()?.rjust(width, fill).into_series());
+        self.0
+            .map(function, GetOutput::same_type())
+            .with_fmt("str.rjust")
+    }
+
     #[cfg(feature = "temporal")]
     pub fn strptime(self, options: StrpTimeOptions) -> Expr {
         let out_type = options.date_dtype.clone();
@@ -80,6 +185,7 @@ impl StringNameSpace {
                 input_wildcard_expansion: false,
                 auto_explode: true,
                 fmt_str: "str.concat",
+                cast_to_float: false,
             },
         }
     }
@@ -109,6 +215,32 @@ impl StringNameSpace {
             .with_fmt("str.split")
     }
 
+    /// Split the string by a substring in a list of exactly `n + 1` parts.
+    /// Missing parts are null. Use `arr().get(i)` to select a part.
+    pub fn split_exact(self, by: &str, n: usize) -> Expr {
+        let by = by.to_string();
+        let function = move |s: Series| Ok(s.utf8()?.split_exact(&by, n).into_series());
+        self.0
+            .map(
+                function,
+                GetOutput::from_type(DataType::List(Box::new(DataType::Utf8))),
+            )
+            .with_fmt("str.split_exact")
+    }
+
+    /// Split the string by a substring in a list of at most `n` parts.
+    /// The last part contains the remainder of the string.
+    pub fn splitn(self, by: &str, n: usize) -> Expr {
+        let by = by.to_string();
+        let function = move |s: Series| Ok(s.utf8()?.splitn(&by, n).into_series());
+        self.0
+            .map(
+                function,
+                GetOutput::from_type(DataType::List(Box::new(DataType::Utf8))),
+            )
+            .with_fmt("str.splitn")
+    }
+
     /// Split the string by a substring.
     pub fn split_inclusive(self, by: &str) -> Expr {
         let by = by.to_string();
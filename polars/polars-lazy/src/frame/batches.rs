@@ -0,0 +1,77 @@
+use super::LazyFrame;
+use crate::prelude::*;
+use polars_core::prelude::*;
+use std::sync::mpsc::{sync_channel, Receiver};
+
+/// Iterator over the output of a query in batches, created by
+/// [`LazyFrame::iter_batches`].
+///
+/// The query runs on a background thread that hands over one batch at a time. Dropping the
+/// iterator cancels the query.
+pub struct BatchIter {
+    pub(super) query: Option<LazyFrame>,
+    pub(super) batch_size: usize,
+    pub(super) token: CancellationToken,
+    pub(super) receiver: Option<Receiver<Result<DataFrame>>>,
+    /// The batch of the query that is being sliced and the offset of the next slice.
+    pub(super) pending: Option<(DataFrame, usize)>,
+}
+
+impl BatchIter {
+    fn start(&mut self, lf: LazyFrame) {
+        // a single slot, so that the query waits for the consumer
+        let (sender, receiver) = sync_channel(1);
+        let token = self.token.clone();
+        std::thread::spawn(move || {
+            let out = lf.execute_impl(token.clone(), None, |physical_plan, state| {
+                physical_plan.execute_batched(state, &mut |df| {
+                    token.check()?;
+                    // the receiver is gone if the iterator was dropped
+                    sender.send(Ok(df)).map_err(|_| PolarsError::Cancelled)
+                })
+            });
+            if let Err(e) = out {
+                let _ = sender.send(Err(e));
+            }
+        });
+        self.receiver = Some(receiver);
+    }
+}
+
+impl Iterator for BatchIter {
+    type Item = Result<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(lf) = self.query.take() {
+            self.start(lf)
+        }
+        loop {
+            if let Some((df, offset)) = &mut self.pending {
+                if *offset < df.height() {
+                    let batch = df.slice(*offset as i64, self.batch_size);
+                    *offset += batch.height();
+                    return Some(Ok(batch));
+                }
+                self.pending = None;
+            }
+            // the channel is closed once the query is done
+            match self.receiver.as_ref()?.recv() {
+                Ok(Ok(df)) => self.pending = Some((df, 0)),
+                Ok(Err(e)) => {
+                    self.receiver = None;
+                    return Some(Err(e));
+                }
+                Err(_) => {
+                    self.receiver = None;
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+impl Drop for BatchIter {
+    fn drop(&mut self) {
+        self.token.cancel()
+    }
+}
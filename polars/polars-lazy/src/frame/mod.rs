@@ -1,11 +1,14 @@
 //! Lazy variant of a [DataFrame](polars_core::frame::DataFrame).
+mod batches;
 #[cfg(feature = "csv-file")]
 mod csv;
 #[cfg(feature = "ipc")]
 mod ipc;
 #[cfg(feature = "parquet")]
 mod parquet;
+mod pivot;
 
+pub use batches::BatchIter;
 #[cfg(feature = "csv-file")]
 pub use csv::*;
 #[cfg(feature = "ipc")]
@@ -19,12 +22,15 @@ use polars_core::datatypes::PlHashMap;
 use polars_core::frame::hash_join::JoinType;
 use polars_core::prelude::*;
 #[cfg(feature = "dtype-categorical")]
-use polars_core::toggle_string_cache;
-use std::sync::Arc;
+use polars_core::StringCacheHolder;
+use rayon::ThreadPool;
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
 
 use crate::logical_plan::optimizer::aggregate_pushdown::AggregatePushdown;
 #[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
 use crate::logical_plan::optimizer::aggregate_scan_projections::AggScanProjection;
+use crate::logical_plan::optimizer::join_reorder::JoinReorder;
 use crate::logical_plan::optimizer::simplify_expr::SimplifyExprRule;
 use crate::logical_plan::optimizer::stack_opt::{OptimizationRule, StackOptimizer};
 use crate::logical_plan::optimizer::{
@@ -40,7 +46,7 @@ use crate::prelude::{
 };
 
 use crate::logical_plan::FETCH_ROWS;
-use crate::utils::{combine_predicates_expr, expr_to_root_column_names};
+use crate::utils::{combine_predicates_expr, estimated_source_size, expr_to_root_column_names};
 use polars_arrow::prelude::QuantileInterpolOptions;
 use polars_io::RowCount;
 
@@ -52,6 +58,13 @@ pub struct JoinOptions {
     pub suffix: Cow<'static, str>,
     pub asof_by_left: Vec<String>,
     pub asof_by_right: Vec<String>,
+    /// Comparisons between `left_on` and `right_on` of a `join_where`.
+    /// Empty for equi joins.
+    pub operators: Vec<JoinOperator>,
+    /// Expected relation between the join keys.
+    pub validate: JoinValidation,
+    /// Null keys match each other.
+    pub join_nulls: bool,
 }
 
 impl Default for JoinOptions {
@@ -63,6 +76,10 @@ impl Default for JoinOptions {
             suffix: "_right".into(),
             asof_by_left: vec![],
             asof_by_right: vec![],
+            operators: vec![],
+            validate: JoinValidation::ManyToMany,
+            join_nulls: true,
+            error: None,
         }
     }
 }
@@ -97,7 +114,7 @@ impl From<LogicalPlan> for LazyFrame {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 /// State of the allowed optimizations
 pub struct OptState {
     pub projection_pushdown: bool,
@@ -109,6 +126,12 @@ pub struct OptState {
     pub aggregate_pushdown: bool,
     pub global_string_cache: bool,
     pub slice_pushdown: bool,
+    /// Reorder chains of inner joins based on their estimated cardinalities
+    pub join_reorder: bool,
+    /// Number of bytes operations may buffer in memory before they spill to disk
+    pub memory_limit: Option<usize>,
+    /// User defined rules that run in the optimization loop next to the built-in rules.
+    pub user_rules: Vec<OptimizationRuleFactory>,
 }
 
 impl Default for OptState {
@@ -120,9 +143,12 @@ impl Default for OptState {
             simplify_expr: true,
             global_string_cache: true,
             slice_pushdown: true,
+            join_reorder: false,
+            memory_limit: None,
             // will be toggled by a scan operation such as csv scan or parquet scan
             agg_scan_projection: false,
             aggregate_pushdown: false,
+            user_rules: vec![],
         }
     }
 }
@@ -142,7 +168,7 @@ impl LazyFrame {
     }
 
     fn get_opt_state(&self) -> OptState {
-        self.opt_state
+        self.opt_state.clone()
     }
 
     fn from_logical_plan(logical_plan: LogicalPlan, opt_state: OptState) -> Self {
@@ -190,7 +216,7 @@ impl LazyFrame {
         self
     }
 
-    /// Toggle global string cache.
+    /// Hold the global string cache while the query runs.
     pub fn with_string_cache(mut self, toggle: bool) -> Self {
         self.opt_state.global_string_cache = toggle;
         self
@@ -202,6 +228,84 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle cost based reordering of inner joins.
+    ///
+    /// Chains of inner joins are reordered such that the estimated intermediate results are
+    /// as small as possible. The column order of the result is not changed, but the row order
+    /// may differ.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.join_reorder = toggle;
+        self
+    }
+
+    /// Set the number of bytes groupbys and sorts may buffer in memory before they spill to disk.
+    ///
+    /// Groupbys that don't maintain order and don't use `apply`, and sorts pull their input in
+    /// batches. Once the batches exceed the memory limit, a groupby hash partitions them on the
+    /// keys as they come in and a sort sorts them in runs. Partitions and runs that don't fit in
+    /// the memory limit are written to temporary files in `POLARS_TEMP_DIR` or the temporary
+    /// directory of the system.
+    ///
+    /// Only IPC and parquet scans produce their output in batches, one per record batch or
+    /// row group, and a sort only consumes batches if it sorts by columns. Any other input is
+    /// materialized in memory first; then the limit only bounds the memory the operation needs
+    /// on top of its input.
+    #[cfg(feature = "spill")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "spill")))]
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.opt_state.memory_limit = Some(bytes);
+        self
+    }
+
+    /// Register a custom [`OptimizationRule`].
+    ///
+    /// The rule is cloned for every optimization pass and runs inside the optimization loop
+    /// together with the built-in rules until the plan reaches a fixed point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// /// Replace every `Reverse` of a `Reverse` by its input.
+    /// #[derive(Clone)]
+    /// struct DoubleReverse;
+    ///
+    /// impl OptimizationRule for DoubleReverse {
+    ///     fn optimize_expr(
+    ///         &self,
+    ///         expr_arena: &mut Arena<AExpr>,
+    ///         expr_node: Node,
+    ///         _lp_arena: &Arena<ALogicalPlan>,
+    ///         _lp_node: Node,
+    ///     ) -> Option<AExpr> {
+    ///         match expr_arena.get(expr_node) {
+    ///             AExpr::Reverse(input) => match expr_arena.get(*input) {
+    ///                 AExpr::Reverse(inner) => Some(expr_arena.get(*inner).clone()),
+    ///                 _ => None,
+    ///             },
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///     df.lazy()
+    ///         .with_optimization_rule(DoubleReverse)
+    ///         .select([col("a").reverse().reverse()])
+    /// }
+    /// ```
+    pub fn with_optimization_rule<R>(mut self, rule: R) -> Self
+    where
+        R: OptimizationRule + Clone + Send + Sync + 'static,
+    {
+        self.opt_state.user_rules.push(Arc::new(move || {
+            Box::new(rule.clone()) as Box<dyn OptimizationRule>
+        }));
+        self
+    }
+
     /// Describe the logical plan.
     pub fn describe_plan(&self) -> String {
         self.logical_plan.describe()
@@ -216,6 +320,16 @@ impl LazyFrame {
         Ok(logical_plan.describe())
     }
 
+    /// Estimate the number of bytes of the data sources of the query, based on the metadata
+    /// of the scanned files and the size of in-memory DataFrames. Projections and filters are
+    /// not taken into account. Returns `None` if the size of a source is unknown.
+    pub fn estimated_size(&self) -> Option<usize> {
+        let mut expr_arena = Arena::with_capacity(64);
+        let mut lp_arena = Arena::with_capacity(32);
+        let root = to_alp(self.logical_plan.clone(), &mut expr_arena, &mut lp_arena);
+        estimated_source_size(root, &lp_arena)
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// # Example
@@ -398,7 +512,7 @@ impl LazyFrame {
     }
 
     pub fn optimize(
-        self,
+        mut self,
         lp_arena: &mut Arena<ALogicalPlan>,
         expr_arena: &mut Arena<AExpr>,
     ) -> Result<Node> {
@@ -408,12 +522,15 @@ impl LazyFrame {
         let type_coercion = self.opt_state.type_coercion;
         let simplify_expr = self.opt_state.simplify_expr;
         let slice_pushdown = self.opt_state.slice_pushdown;
+        let join_reorder = self.opt_state.join_reorder;
 
         #[cfg(any(feature = "parquet", feature = "csv-file"))]
         let agg_scan_projection = self.opt_state.agg_scan_projection;
         let aggregate_pushdown = self.opt_state.aggregate_pushdown;
+        let user_rules = std::mem::take(&mut self.opt_state.user_rules);
 
         let logical_plan = self.get_plan_builder().build();
+        logical_plan.check_errors()?;
 
         // gradually fill the rules passed to the optimizer
         let opt = StackOptimizer {};
@@ -423,6 +540,8 @@ impl LazyFrame {
             rules.push(Box::new(SimplifyExprRule {}));
             rules.push(Box::new(SimplifyBooleanRule {}));
         }
+        // user defined rules run in both passes of the optimization loop
+        rules.extend(user_rules.iter().map(|create_rule| create_rule()));
 
         // during debug we check if the optimizations have not modified the final schema
         #[cfg(debug_assertions)]
@@ -463,6 +582,11 @@ impl LazyFrame {
             lp_arena.replace(lp_top, alp);
         }
 
+        // run after predicate pushdown so that the predicates are available to the estimates
+        if join_reorder {
+            JoinReorder {}.optimize(lp_top, lp_arena, expr_arena);
+        }
+
         if type_coercion {
             rules.push(Box::new(TypeCoercionRule {}))
         }
@@ -527,30 +651,162 @@ impl LazyFrame {
     /// }
     /// ```
     pub fn collect(self) -> Result<DataFrame> {
+        self.collect_cancellable(CancellationToken::new())
+    }
+
+    /// Execute the query like [`collect`](LazyFrame::collect), but stop with
+    /// [`PolarsError::Cancelled`] once `token` is cancelled.
+    ///
+    /// Cancellation is checked between the steps of the physical plan and inside long
+    /// running loops, so the query returns shortly after [`CancellationToken::cancel`] is called.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     let token = CancellationToken::new();
+    ///     // hand a clone to the thread that may cancel the query
+    ///     let handle = token.clone();
+    ///     std::thread::spawn(move || handle.cancel());
+    ///
+    ///     df.lazy()
+    ///       .groupby([col("foo")])
+    ///       .agg([col("bar").sum()])
+    ///       .collect_cancellable(token)
+    /// }
+    /// ```
+    pub fn collect_cancellable(self, token: CancellationToken) -> Result<DataFrame> {
+        self.collect_impl(token, None)
+    }
+
+    /// Execute the query like [`collect`](LazyFrame::collect), but run it on `pool` instead of
+    /// the global thread pool. This limits the threads a query may occupy, without affecting
+    /// other queries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// use rayon::ThreadPoolBuilder;
+    ///
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     let pool = ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+    ///     df.lazy()
+    ///       .groupby([col("foo")])
+    ///       .agg([col("bar").sum()])
+    ///       .collect_with_pool(&pool)
+    /// }
+    /// ```
+    pub fn collect_with_pool(self, pool: &ThreadPool) -> Result<DataFrame> {
+        self.collect_impl(CancellationToken::new(), Some(pool))
+    }
+
+    fn collect_impl(
+        self,
+        token: CancellationToken,
+        pool: Option<&ThreadPool>,
+    ) -> Result<DataFrame> {
+        self.execute_impl(token, pool, |physical_plan, state| {
+            physical_plan.execute(state)
+        })
+    }
+
+    /// Optimize the query, create the physical plan and run `execute` on it with the settings
+    /// of the query in the execution state.
+    fn execute_impl<T, F>(
+        self,
+        token: CancellationToken,
+        pool: Option<&ThreadPool>,
+        execute: F,
+    ) -> Result<T>
+    where
+        F: FnOnce(&mut dyn Executor, &ExecutionState) -> Result<T> + Send,
+        T: Send,
+    {
         #[cfg(feature = "dtype-categorical")]
         let use_string_cache = self.opt_state.global_string_cache;
+        let memory_limit = self.opt_state.memory_limit;
         let mut expr_arena = Arena::with_capacity(256);
         let mut lp_arena = Arena::with_capacity(128);
         let lp_top = self.optimize(&mut lp_arena, &mut expr_arena)?;
 
-        // if string cache was already set, we skip this and global settings are respected
+        // the cache lives until the result is produced, other holders keep it alive longer
         #[cfg(feature = "dtype-categorical")]
-        if use_string_cache {
-            toggle_string_cache(use_string_cache);
-        }
+        let _string_cache_holder = if use_string_cache {
+            Some(StringCacheHolder::hold())
+        } else {
+            None
+        };
         let planner = DefaultPlanner::default();
         let mut physical_plan =
             planner.create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
 
-        let state = ExecutionState::new();
-        let out = physical_plan.execute(&state);
-        #[cfg(feature = "dtype-categorical")]
-        if use_string_cache {
-            toggle_string_cache(!use_string_cache);
+        let mut state = ExecutionState::new();
+        state.memory_limit = memory_limit;
+        state.cancel_token = token;
+        // executors that read the settings directly see the settings of the state
+        let run = || {
+            state
+                .config
+                .clone()
+                .scoped_arc(|_| execute(physical_plan.as_mut(), &state))
+        };
+        // the parallel operations run in the thread pool they are called from
+        match pool {
+            Some(pool) => polars_core::with_thread_pool(pool, run),
+            None => run(),
         }
+    }
+
+    /// Execute the query like [`collect`](LazyFrame::collect), but return
+    /// [`PolarsError::Cancelled`] if it doesn't finish within `timeout`.
+    pub fn collect_with_timeout(self, timeout: Duration) -> Result<DataFrame> {
+        let token = CancellationToken::new();
+        let timer_token = token.clone();
+        let (finished_tx, finished_rx) = mpsc::channel::<()>();
+        std::thread::spawn(move || {
+            // the sender is dropped when the query finishes, which wakes us up early
+            if let Err(mpsc::RecvTimeoutError::Timeout) = finished_rx.recv_timeout(timeout) {
+                timer_token.cancel()
+            }
+        });
+        let out = self.collect_cancellable(token);
+        drop(finished_tx);
         out
     }
 
+    /// Execute the query and iterate over its result in batches of at most `batch_size` rows.
+    ///
+    /// The query starts on a background thread when the first batch is requested. If the
+    /// output of the plan is an IPC or parquet scan, its record batches and row groups are
+    /// passed on as they are read and the scan waits until the previous batch is consumed;
+    /// other plans produce their output at once, which is then sliced zero-copy. Dropping the
+    /// iterator cancels the query.
+    pub fn iter_batches(self, batch_size: usize) -> BatchIter {
+        BatchIter {
+            query: Some(self),
+            batch_size: std::cmp::max(batch_size, 1),
+            token: CancellationToken::new(),
+            receiver: None,
+            pending: None,
+        }
+    }
+
+    /// Export the query as an [`ArrowArrayStream`](polars_core::ffi::ArrowArrayStream) of
+    /// batches of at most `batch_size` rows. The query starts when the consumer asks for the
+    /// first batch and is cancelled when the stream is released.
+    /// See [`iter_batches`](LazyFrame::iter_batches).
+    #[cfg(feature = "ffi")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
+    pub fn to_ffi_stream(self, batch_size: usize) -> Result<polars_core::ffi::ArrowArrayStream> {
+        let schema = self.schema();
+        polars_core::ffi::ArrowArrayStream::from_batches(&schema, self.iter_batches(batch_size))
+    }
+
     /// Filter by some predicate expression.
     ///
     /// # Example
@@ -740,6 +996,39 @@ impl LazyFrame {
         self.join(other, vec![], vec![], JoinType::Cross)
     }
 
+    /// Join on comparisons between the columns of both frames.
+    ///
+    /// Every predicate is a comparison (`eq`, `lt`, `lt_eq`, `gt` or `gt_eq`) of which the left
+    /// side refers to this frame and the right side to `other`, or a conjunction of those.
+    /// The join runs on the sorted keys of the first inequality, within the groups of the
+    /// equality predicates, and keeps the join columns of both frames.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// // match events to sessions where start <= ts < end
+    /// fn example(events: LazyFrame, sessions: LazyFrame) -> LazyFrame {
+    ///     events.join_where(
+    ///         sessions,
+    ///         [
+    ///             col("user").eq(col("user_id")),
+    ///             col("ts").gt_eq(col("start")).and(col("ts").lt(col("end"))),
+    ///         ],
+    ///     )
+    /// }
+    /// ```
+    #[cfg(feature = "range_join")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "range_join")))]
+    pub fn join_where<E: AsRef<[Expr]>>(self, other: LazyFrame, predicates: E) -> LazyFrame {
+        self.join_builder()
+            .with(other)
+            .predicates(predicates)
+            .finish()
+    }
+
     /// Generic join function that can join on multiple columns.
     ///
     /// # Example
@@ -914,7 +1203,7 @@ impl LazyFrame {
     }
 
     /// Slice the DataFrame.
-    pub fn slice(self, offset: i64, len: u32) -> LazyFrame {
+    pub fn slice(self, offset: i64, len: IdxSize) -> LazyFrame {
         let opt_state = self.get_opt_state();
         let lp = self.get_plan_builder().slice(offset, len).build();
         Self::from_logical_plan(lp, opt_state)
@@ -931,24 +1220,31 @@ impl LazyFrame {
     }
 
     /// Get the last `n` rows
-    pub fn tail(self, n: u32) -> LazyFrame {
+    pub fn tail(self, n: IdxSize) -> LazyFrame {
         let neg_tail = -(n as i64);
         self.slice(neg_tail, n)
     }
 
     /// Melt the DataFrame from wide to long format
     pub fn melt(self, id_vars: Vec<String>, value_vars: Vec<String>) -> LazyFrame {
+        self.melt2(MeltArgs {
+            id_vars,
+            value_vars,
+            ..Default::default()
+        })
+    }
+
+    /// Similar to [`melt`](LazyFrame::melt), but the names of the `variable` and `value`
+    /// columns can be set with [`MeltArgs`].
+    pub fn melt2(self, args: MeltArgs) -> LazyFrame {
         let opt_state = self.get_opt_state();
-        let lp = self
-            .get_plan_builder()
-            .melt(Arc::new(id_vars), Arc::new(value_vars))
-            .build();
+        let lp = self.get_plan_builder().melt(Arc::new(args)).build();
         Self::from_logical_plan(lp, opt_state)
     }
 
     /// Limit the DataFrame to the first `n` rows. Note if you don't want the rows to be scanned,
     /// use [fetch](LazyFrame::fetch).
-    pub fn limit(self, n: u32) -> LazyFrame {
+    pub fn limit(self, n: IdxSize) -> LazyFrame {
         self.slice(0, n)
     }
 
@@ -987,7 +1283,7 @@ impl LazyFrame {
     /// # Warning
     /// This can have a negative effect on query performance.
     /// This may for instance block predicate pushdown optimization.
-    pub fn with_row_count(mut self, name: &str, offset: Option<u32>) -> LazyFrame {
+    pub fn with_row_count(mut self, name: &str, offset: Option<IdxSize>) -> LazyFrame {
         match &mut self.logical_plan {
             // Do the row count at scan
             #[cfg(feature = "csv-file")]
@@ -1018,7 +1314,7 @@ impl LazyFrame {
                 let schema = self.schema();
 
                 let mut fields = schema.fields().clone();
-                fields.insert(0, Field::new(name, DataType::UInt32));
+                fields.insert(0, Field::new(name, IDX_DTYPE));
                 let new_schema = Schema::new(fields);
 
                 let name = name.to_owned();
@@ -1143,6 +1439,11 @@ pub struct JoinBuilder {
     suffix: Option<String>,
     asof_by_left: Vec<String>,
     asof_by_right: Vec<String>,
+    operators: Vec<JoinOperator>,
+    validate: JoinValidation,
+    join_nulls: bool,
+    /// An invalid argument, returned when the query is collected.
+    error: Option<PolarsError>,
 }
 impl JoinBuilder {
     pub fn new(lf: LazyFrame) -> Self {
@@ -1157,6 +1458,9 @@ impl JoinBuilder {
             suffix: None,
             asof_by_left: vec![],
             asof_by_right: vec![],
+            operators: vec![],
+            validate: JoinValidation::ManyToMany,
+            join_nulls: true,
         }
     }
 
@@ -1202,6 +1506,20 @@ impl JoinBuilder {
         self
     }
 
+    /// Check the relation between the join keys. A violation fails the query.
+    /// Defaults to [`JoinValidation::ManyToMany`], which doesn't check anything.
+    pub fn validate(mut self, validate: JoinValidation) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Let null keys match each other.
+    /// Defaults to `true`.
+    pub fn join_nulls(mut self, join_nulls: bool) -> Self {
+        self.join_nulls = join_nulls;
+        self
+    }
+
     /// Set the `by` subgrouper of an asof join.
     pub fn asof_by(mut self, left_by: Vec<String>, right_by: Vec<String>) -> Self {
         self.asof_by_left = left_by;
@@ -1209,9 +1527,64 @@ impl JoinBuilder {
         self
     }
 
+    /// Join on comparisons between the columns of both tables, see [`LazyFrame::join_where`].
+    /// This replaces the `left_on` and `right_on` columns and the join type.
+    ///
+    /// A predicate that is not a (conjunction of) `eq`, `lt`, `lt_eq`, `gt` or `gt_eq` is
+    /// an error, which is returned when the query is collected.
+    #[cfg(feature = "range_join")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "range_join")))]
+    pub fn predicates<E: AsRef<[Expr]>>(mut self, predicates: E) -> Self {
+        fn split(e: &Expr, builder: &mut JoinBuilder) -> Result<()> {
+            match e {
+                Expr::BinaryExpr {
+                    left,
+                    op: Operator::And,
+                    right,
+                } => {
+                    split(left, builder)?;
+                    split(right, builder)
+                }
+                Expr::BinaryExpr { left, op, right } => {
+                    let op = match op {
+                        Operator::Eq => JoinOperator::Eq,
+                        Operator::Lt => JoinOperator::Lt,
+                        Operator::LtEq => JoinOperator::LtEq,
+                        Operator::Gt => JoinOperator::Gt,
+                        Operator::GtEq => JoinOperator::GtEq,
+                        op => {
+                            return Err(PolarsError::InvalidOperation(
+                                format!("operator {:?} is not supported in join_where", op).into(),
+                            ))
+                        }
+                    };
+                    builder.left_on.push(left.as_ref().clone());
+                    builder.right_on.push(right.as_ref().clone());
+                    builder.operators.push(op);
+                    Ok(())
+                }
+                e => Err(PolarsError::InvalidOperation(
+                    format!("expected a comparison in join_where, got {:?}", e).into(),
+                )),
+            }
+        }
+
+        self.left_on.clear();
+        self.right_on.clear();
+        self.operators.clear();
+        for e in predicates.as_ref() {
+            if let Err(e) = split(e, &mut self) {
+                self.error = Some(e);
+                break;
+            }
+        }
+        self.how = JoinType::Inner;
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
-        let opt_state = self.lf.opt_state;
+        let opt_state = self.lf.get_opt_state();
 
         let suffix = match self.suffix {
             None => Cow::Borrowed("_right"),
@@ -1232,9 +1605,20 @@ impl JoinBuilder {
                     suffix,
                     asof_by_left: self.asof_by_left,
                     asof_by_right: self.asof_by_right,
+                    operators: self.operators,
+                    validate: self.validate,
+                    join_nulls: self.join_nulls,
                 },
             )
             .build();
+        // the join is still built, so the schema of the frame is known
+        let lp = match self.error {
+            Some(err) => LogicalPlan::Error {
+                input: Box::new(lp),
+                err: err.into(),
+            },
+            None => lp,
+        };
         LazyFrame::from_logical_plan(lp, opt_state)
     }
 }
@@ -0,0 +1,174 @@
+use crate::prelude::*;
+use polars_core::prelude::*;
+
+/// Replace the `col("*")` in `agg` with the value column filtered on a pivot value. A `None`
+/// pivot value selects the rows where `columns` is null.
+fn pivot_agg(agg: &Expr, value: &str, columns: &str, pivot_value: Option<&str>) -> Expr {
+    let predicate = match pivot_value {
+        Some(pivot_value) => col(columns)
+            .cast(DataType::Utf8)
+            .eq(lit(pivot_value.to_string())),
+        None => col(columns).is_null(),
+    };
+    let filtered = col(value).filter(predicate);
+    let mut agg = agg.clone();
+    agg.mutate().apply(|e| {
+        if let Expr::Wildcard = e {
+            *e = filtered.clone();
+        }
+        true
+    });
+    agg
+}
+
+/// The aggregations that create a column for every combination of value column and pivot
+/// value. A null pivot value gets the name `null`. Names that are already taken, for instance
+/// by an index column, are prefixed with the name of `columns` until they are unique.
+fn pivot_aggs(
+    index: &[String],
+    columns: &str,
+    values: &[String],
+    agg: &Expr,
+    pivot_values: &[Option<String>],
+) -> Vec<Expr> {
+    let mut taken = index.iter().cloned().collect::<PlHashSet<_>>();
+    let mut aggs = Vec::with_capacity(values.len() * pivot_values.len());
+    for value in values {
+        for pivot_value in pivot_values {
+            let pivot_value = pivot_value.as_deref();
+            let mut name = match (values.len(), pivot_value) {
+                (1, _) => pivot_value.unwrap_or("null").to_string(),
+                (_, _) => format!("{}_{}_{}", value, columns, pivot_value.unwrap_or("null")),
+            };
+            while !taken.insert(name.clone()) {
+                name = format!("{}_{}", columns, name);
+            }
+            aggs.push(pivot_agg(agg, value, columns, pivot_value).alias(&name));
+        }
+    }
+    aggs
+}
+
+fn index_keys(index: &[String]) -> Vec<Expr> {
+    index.iter().map(|name| col(name.as_str())).collect()
+}
+
+impl LazyFrame {
+    /// Pivot the values of `columns` to new columns and aggregate the `values` for every
+    /// combination of `index` and pivot value.
+    ///
+    /// `agg` is an aggregation on `col("*")`, which is replaced by every value column, for
+    /// instance `col("*").sum()` or `col("*").last()`.
+    ///
+    /// The distinct values of `columns`, including null, are computed when the query runs, so
+    /// the new columns are not in the schema of the plan: only the `index` columns can be
+    /// referred to in the rest of the query. Use [`pivot_values`](LazyFrame::pivot_values) to
+    /// pass the pivot values explicitly, so that the new columns are known upfront.
+    pub fn pivot(
+        self,
+        index: impl IntoVec<String>,
+        columns: &str,
+        values: impl IntoVec<String>,
+        agg: Expr,
+    ) -> LazyFrame {
+        let index = index.into_vec();
+        let values = values.into_vec();
+        let columns = columns.to_string();
+
+        let input_schema = self.schema();
+        let mut needed = index.clone();
+        needed.push(columns.clone());
+        needed.extend(values.iter().cloned());
+        if let Some(err) = needed
+            .iter()
+            .find_map(|name| input_schema.field_with_name(name).err())
+        {
+            let opt_state = self.get_opt_state();
+            let lp = LogicalPlan::Error {
+                input: Box::new(self.logical_plan),
+                err: err.into(),
+            };
+            return Self::from_logical_plan(lp, opt_state);
+        }
+        let schema = Schema::new(
+            index
+                .iter()
+                .map(|name| input_schema.field_with_name(name).unwrap().clone())
+                .collect(),
+        );
+
+        let opt = AllowedOptimizations {
+            projection_pushdown: false,
+            predicate_pushdown: false,
+            slice_pushdown: false,
+            ..Default::default()
+        };
+        let needed = needed
+            .iter()
+            .map(|name| col(name.as_str()))
+            .collect::<Vec<_>>();
+        self.select(needed).map(
+            move |df: DataFrame| {
+                let mut seen = PlHashSet::new();
+                let pivot_values = df
+                    .column(&columns)?
+                    .cast(&DataType::Utf8)?
+                    .utf8()?
+                    .into_iter()
+                    .filter(|v| seen.insert(*v))
+                    .map(|v| v.map(|v| v.to_string()))
+                    .collect::<Vec<_>>();
+                df.lazy()
+                    .groupby_stable(index_keys(&index))
+                    .agg(pivot_aggs(&index, &columns, &values, &agg, &pivot_values))
+                    .collect()
+            },
+            Some(opt),
+            Some(schema),
+            Some("PIVOT"),
+        )
+    }
+
+    /// Similar to [`pivot`](LazyFrame::pivot), but only the given `pivot_values` become new
+    /// columns. As the output schema is known upfront, the new columns can be used in the rest
+    /// of the query.
+    ///
+    /// The new columns are named after the pivot values. If there are multiple value columns,
+    /// they are named `{value}_{columns}_{pivot_value}`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///     df.lazy().pivot_values(
+    ///         ["date"],
+    ///         "city",
+    ///         ["temp"],
+    ///         col("*").mean(),
+    ///         ["Amsterdam", "Utrecht"],
+    ///     )
+    /// }
+    /// ```
+    pub fn pivot_values(
+        self,
+        index: impl IntoVec<String>,
+        columns: &str,
+        values: impl IntoVec<String>,
+        agg: Expr,
+        pivot_values: impl IntoVec<String>,
+    ) -> LazyFrame {
+        let index = index.into_vec();
+        let values = values.into_vec();
+        let pivot_values = pivot_values
+            .into_vec()
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+
+        let aggs = pivot_aggs(&index, columns, &values, &agg, &pivot_values);
+        self.groupby_stable(index_keys(&index)).agg(aggs)
+    }
+}
@@ -127,12 +127,13 @@ pub fn argsort_by<E: AsRef<[Expr]>>(by: E, reverse: &[bool]) -> Expr {
     Expr::Function {
         input: by.as_ref().to_vec(),
         function,
-        output_type: GetOutput::from_type(DataType::UInt32),
+        output_type: GetOutput::from_type(IDX_DTYPE),
         options: FunctionOptions {
             collect_groups: ApplyOptions::ApplyFlat,
             input_wildcard_expansion: false,
             auto_explode: true,
             fmt_str: "argsort_by",
+            cast_to_float: false,
         },
     }
 }
@@ -154,6 +155,7 @@ pub fn concat_str(s: Vec<Expr>, sep: &str) -> Expr {
             input_wildcard_expansion: true,
             auto_explode: true,
             fmt_str: "concat_by",
+            cast_to_float: false,
         },
     }
 }
@@ -184,6 +186,7 @@ pub fn concat_lst(s: Vec<Expr>) -> Expr {
             input_wildcard_expansion: true,
             auto_explode: false,
             fmt_str: "concat_list",
+            cast_to_float: false,
         },
     }
 }
@@ -359,6 +362,7 @@ pub fn datetime(
             input_wildcard_expansion: true,
             auto_explode: false,
             fmt_str: "datetime",
+            cast_to_float: false,
         },
     }
     .alias("datetime")
@@ -564,6 +568,7 @@ where
                 input_wildcard_expansion: true,
                 auto_explode: true,
                 fmt_str: "",
+                cast_to_float: false,
             },
         }
     } else {
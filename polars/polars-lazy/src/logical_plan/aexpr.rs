@@ -107,7 +107,7 @@ impl Default for AExpr {
 }
 impl AExpr {
     /// This should be a 1 on 1 copy of the get_type method of Expr until Expr is completely phased out.
-    pub(crate) fn get_type(
+    pub fn get_type(
         &self,
         schema: &Schema,
         ctxt: Context,
@@ -118,15 +118,10 @@ impl AExpr {
     }
 
     /// Get Field result of the expression. The schema is the input data.
-    pub(crate) fn to_field(
-        &self,
-        schema: &Schema,
-        ctxt: Context,
-        arena: &Arena<AExpr>,
-    ) -> Result<Field> {
+    pub fn to_field(&self, schema: &Schema, ctxt: Context, arena: &Arena<AExpr>) -> Result<Field> {
         use AExpr::*;
         match self {
-            Count => Ok(Field::new("count", DataType::UInt32)),
+            Count => Ok(Field::new("count", IDX_DTYPE)),
             Window { function, .. } => {
                 let e = arena.get(*function);
                 e.to_field(schema, ctxt, arena)
@@ -234,17 +229,17 @@ impl AExpr {
                     }
                     NUnique(expr) => {
                         let mut field = arena.get(*expr).to_field(schema, ctxt, arena)?;
-                        field.coerce(DataType::UInt32);
+                        field.coerce(IDX_DTYPE);
                         Ok(field)
                     }
                     Count(expr) => {
                         let mut field = arena.get(*expr).to_field(schema, ctxt, arena)?;
-                        field.coerce(DataType::UInt32);
+                        field.coerce(IDX_DTYPE);
                         Ok(field)
                     }
                     AggGroups(expr) => {
                         let mut field = arena.get(*expr).to_field(schema, ctxt, arena)?;
-                        field.coerce(DataType::List(DataType::UInt32.into()));
+                        field.coerce(DataType::List(Box::new(IDX_DTYPE)));
                         Ok(field)
                     }
                     Quantile { expr, .. } => {
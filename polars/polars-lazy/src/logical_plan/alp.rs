@@ -18,14 +18,13 @@ use std::sync::Arc;
 pub enum ALogicalPlan {
     Melt {
         input: Node,
-        id_vars: Arc<Vec<String>>,
-        value_vars: Arc<Vec<String>>,
+        args: Arc<MeltArgs>,
         schema: SchemaRef,
     },
     Slice {
         input: Node,
         offset: i64,
-        len: u32,
+        len: IdxSize,
     },
     Selection {
         input: Node,
@@ -141,7 +140,8 @@ impl Default for ALogicalPlan {
 }
 
 impl ALogicalPlan {
-    pub(crate) fn schema<'a>(&'a self, arena: &'a Arena<ALogicalPlan>) -> &'a SchemaRef {
+    /// Get the output schema of this node.
+    pub fn schema<'a>(&'a self, arena: &'a Arena<ALogicalPlan>) -> &'a SchemaRef {
         use ALogicalPlan::*;
         match self {
             Union { inputs, .. } => arena.get(inputs[0]).schema(arena),
@@ -194,15 +194,9 @@ impl ALogicalPlan {
                 inputs,
                 options: *options,
             },
-            Melt {
-                id_vars,
-                value_vars,
-                schema,
-                ..
-            } => Melt {
+            Melt { args, schema, .. } => Melt {
                 input: inputs[0],
-                id_vars: id_vars.clone(),
-                value_vars: value_vars.clone(),
+                args: args.clone(),
                 schema: schema.clone(),
             },
             Slice { offset, len, .. } => Slice {
@@ -546,17 +540,20 @@ impl<'a> ALogicalPlanBuilder<'a> {
         }
     }
 
-    pub fn melt(self, id_vars: Arc<Vec<String>>, value_vars: Arc<Vec<String>>) -> Self {
-        let schema = det_melt_schema(&value_vars, self.schema());
+    pub fn melt(self, args: Arc<MeltArgs>) -> Result<Self> {
+        let schema = det_melt_schema(&args, self.schema())?;
 
         let lp = ALogicalPlan::Melt {
             input: self.root,
-            id_vars,
-            value_vars,
+            args,
             schema,
         };
         let node = self.lp_arena.add(lp);
-        ALogicalPlanBuilder::new(node, self.expr_arena, self.lp_arena)
+        Ok(ALogicalPlanBuilder::new(
+            node,
+            self.expr_arena,
+            self.lp_arena,
+        ))
     }
 
     pub fn project_local(self, exprs: Vec<Node>) -> Self {
@@ -702,7 +699,8 @@ impl<'a> ALogicalPlanBuilder<'a> {
 
         for f in schema_right.fields() {
             let name = f.name();
-            if !right_names.contains(name.as_str()) {
+            // a join_where keeps the join columns of both tables
+            if !options.operators.is_empty() || !right_names.contains(name.as_str()) {
                 if names.contains(name.as_str()) {
                     let new_name = format!("{}{}", name, options.suffix.as_ref());
                     let field = Field::new(&new_name, f.data_type().clone());
@@ -49,7 +49,8 @@ impl LogicalPlanBuilder {
 
         let path = path.into();
         let file = std::fs::File::open(&path)?;
-        let schema = Arc::new(ParquetReader::new(file).schema()?);
+        let (schema, row_groups) = ParquetReader::new(file).schema_and_statistics()?;
+        let schema = Arc::new(schema);
 
         Ok(LogicalPlan::ParquetScan {
             path,
@@ -62,6 +63,7 @@ impl LogicalPlanBuilder {
                 cache,
                 parallel,
                 row_count,
+                row_groups: Arc::new(row_groups),
             },
         }
         .into())
@@ -350,13 +352,18 @@ impl LogicalPlanBuilder {
         .into()
     }
 
-    pub fn melt(self, id_vars: Arc<Vec<String>>, value_vars: Arc<Vec<String>>) -> Self {
-        let schema = det_melt_schema(&value_vars, self.0.schema());
-        LogicalPlan::Melt {
-            input: Box::new(self.0),
-            id_vars,
-            value_vars,
-            schema,
+    pub fn melt(self, args: Arc<MeltArgs>) -> Self {
+        match det_melt_schema(&args, self.0.schema()) {
+            Ok(schema) => LogicalPlan::Melt {
+                input: Box::new(self.0),
+                args,
+                schema,
+            },
+            // the error is returned when the plan runs
+            Err(err) => LogicalPlan::Error {
+                input: Box::new(self.0),
+                err: err.into(),
+            },
         }
         .into()
     }
@@ -369,7 +376,7 @@ impl LogicalPlanBuilder {
         .into()
     }
 
-    pub fn slice(self, offset: i64, len: u32) -> Self {
+    pub fn slice(self, offset: i64, len: IdxSize) -> Self {
         LogicalPlan::Slice {
             input: Box::new(self.0),
             offset,
@@ -406,7 +413,8 @@ impl LogicalPlanBuilder {
         for f in schema_right.fields() {
             let name = f.name();
 
-            if !right_names.iter().any(|s| s.as_ref() == name) {
+            // a join_where keeps the join columns of both tables
+            if !options.operators.is_empty() || !right_names.iter().any(|s| s.as_ref() == name) {
                 if names.contains(name) {
                     let new_name = format!("{}{}", name, options.suffix.as_ref());
                     let field = Field::new(&new_name, f.data_type().clone());
@@ -455,23 +463,36 @@ impl LogicalPlanBuilder {
     }
 }
 
-pub(crate) fn det_melt_schema(value_vars: &[String], input_schema: &Schema) -> SchemaRef {
-    let mut fields = input_schema
-        .fields()
+pub(crate) fn det_melt_schema(args: &MeltArgs, input_schema: &Schema) -> Result<SchemaRef> {
+    let mut fields = args
+        .id_vars
         .iter()
-        .filter(|field| !value_vars.contains(field.name()))
-        .cloned()
-        .collect::<Vec<_>>();
+        .map(|name| input_schema.field_with_name(name).cloned())
+        .collect::<Result<Vec<_>>>()?;
 
     fields.reserve(2);
 
-    let value_dtype = input_schema
-        .field_with_name(&value_vars[0])
-        .expect("field not found")
-        .data_type();
+    let value_dtype = match args.value_vars.first() {
+        Some(name) => input_schema.field_with_name(name)?.data_type(),
+        None => {
+            return Err(PolarsError::NoData(
+                "melt needs at least one value column".into(),
+            ))
+        }
+    };
+    // the other value columns must exist as well
+    for name in &args.value_vars[1..] {
+        input_schema.field_with_name(name)?;
+    }
 
-    fields.push(Field::new("variable", DataType::Utf8));
-    fields.push(Field::new("value", value_dtype.clone()));
+    fields.push(Field::new(
+        args.variable_name.as_deref().unwrap_or("variable"),
+        DataType::Utf8,
+    ));
+    fields.push(Field::new(
+        args.value_name.as_deref().unwrap_or("value"),
+        value_dtype.clone(),
+    ));
 
-    Arc::new(Schema::new(fields))
+    Ok(Arc::new(Schema::new(fields)))
 }
@@ -6,7 +6,8 @@ fn to_aexprs(input: Vec<Expr>, arena: &mut Arena<AExpr>) -> Vec<Node> {
 }
 
 // converts expression to AExpr, which uses an arena (Vec) for allocation
-pub(crate) fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
+/// Converts an [`Expr`] into its arena representation and returns the node of the root.
+pub fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
     let v = match expr {
         Expr::IsUnique(expr) => AExpr::IsUnique(to_aexpr(*expr, arena)),
         Expr::Duplicated(expr) => AExpr::Duplicated(to_aexpr(*expr, arena)),
@@ -169,15 +170,13 @@ pub(crate) fn to_alp(
         }
         LogicalPlan::Melt {
             input,
-            id_vars,
-            value_vars,
+            args,
             schema,
         } => {
             let input = to_alp(*input, expr_arena, lp_arena);
             ALogicalPlan::Melt {
                 input,
-                id_vars,
-                value_vars,
+                args,
                 schema,
             }
         }
@@ -383,11 +382,14 @@ pub(crate) fn to_alp(
                 schema,
             }
         }
+        // the errors are checked before the plan is converted
+        LogicalPlan::Error { input, .. } => return to_alp(*input, expr_arena, lp_arena),
     };
     lp_arena.add(v)
 }
 
-pub(crate) fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
+/// Converts an arena expression back into an [`Expr`].
+pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
     let expr = expr_arena.get(node).clone();
 
     match expr {
@@ -804,15 +806,13 @@ pub(crate) fn node_to_lp(
         }
         ALogicalPlan::Melt {
             input,
-            id_vars,
-            value_vars,
+            args,
             schema,
         } => {
             let input = node_to_lp(input, expr_arena, lp_arena);
             LogicalPlan::Melt {
                 input: Box::new(input),
-                id_vars,
-                value_vars,
+                args,
                 schema,
             }
         }
@@ -1,6 +1,10 @@
 #[cfg(any(feature = "csv-file", feature = "parquet"))]
 use std::path::PathBuf;
-use std::{cell::Cell, fmt::Debug, sync::Arc};
+use std::{
+    cell::Cell,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
 
 use polars_core::prelude::*;
 
@@ -141,13 +145,12 @@ pub enum LogicalPlan {
     Slice {
         input: Box<LogicalPlan>,
         offset: i64,
-        len: u32,
+        len: IdxSize,
     },
     /// A Melt operation
     Melt {
         input: Box<LogicalPlan>,
-        id_vars: Arc<Vec<String>>,
-        value_vars: Arc<Vec<String>>,
+        args: Arc<MeltArgs>,
         schema: SchemaRef,
     },
     /// A User Defined Function
@@ -161,6 +164,57 @@ pub enum LogicalPlan {
         inputs: Vec<LogicalPlan>,
         options: UnionOptions,
     },
+    /// An error that occurred while building the plan. It is returned when the plan is
+    /// executed, so the builders don't have to return a `Result`.
+    Error {
+        input: Box<LogicalPlan>,
+        err: ErrorState,
+    },
+}
+
+/// A `PolarsError` that can be stored in a (cloneable) [`LogicalPlan`]. The error is returned
+/// the first time the plan runs, later runs get an error with the same message.
+#[derive(Clone)]
+pub struct ErrorState(Arc<Mutex<ErrorStateInner>>);
+
+enum ErrorStateInner {
+    NotYetEncountered { err: PolarsError },
+    AlreadyEncountered { msg: String },
+}
+
+impl From<PolarsError> for ErrorState {
+    fn from(err: PolarsError) -> Self {
+        ErrorState(Arc::new(Mutex::new(ErrorStateInner::NotYetEncountered {
+            err,
+        })))
+    }
+}
+
+impl ErrorState {
+    pub(crate) fn take(&self) -> PolarsError {
+        let mut inner = self.0.lock().unwrap();
+        let msg = match &*inner {
+            ErrorStateInner::NotYetEncountered { err } => err.to_string(),
+            ErrorStateInner::AlreadyEncountered { msg } => {
+                return PolarsError::ComputeError(
+                    format!("the plan failed to build before: {}", msg).into(),
+                )
+            }
+        };
+        match std::mem::replace(&mut *inner, ErrorStateInner::AlreadyEncountered { msg }) {
+            ErrorStateInner::NotYetEncountered { err } => err,
+            ErrorStateInner::AlreadyEncountered { .. } => unreachable!(),
+        }
+    }
+}
+
+impl Debug for ErrorState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self.0.lock().unwrap() {
+            ErrorStateInner::NotYetEncountered { err } => write!(f, "{}", err),
+            ErrorStateInner::AlreadyEncountered { msg } => write!(f, "{}", msg),
+        }
+    }
 }
 
 impl Default for LogicalPlan {
@@ -214,6 +268,43 @@ impl LogicalPlan {
                 Some(schema) => schema,
                 None => input.schema(),
             },
+            Error { input, .. } => input.schema(),
+        }
+    }
+
+    /// Return the first error that was stored in the plan while it was built.
+    pub(crate) fn check_errors(&self) -> Result<()> {
+        use LogicalPlan::*;
+        match self {
+            Error { err, .. } => Err(err.take()),
+            Union { inputs, .. } => inputs.iter().try_for_each(|lp| lp.check_errors()),
+            Join {
+                input_left,
+                input_right,
+                ..
+            } => {
+                input_left.check_errors()?;
+                input_right.check_errors()
+            }
+            #[cfg(feature = "csv-file")]
+            CsvScan { .. } => Ok(()),
+            #[cfg(feature = "parquet")]
+            ParquetScan { .. } => Ok(()),
+            #[cfg(feature = "ipc")]
+            IpcScan { .. } => Ok(()),
+            DataFrameScan { .. } => Ok(()),
+            Selection { input, .. }
+            | Cache { input }
+            | LocalProjection { input, .. }
+            | Projection { input, .. }
+            | Aggregate { input, .. }
+            | HStack { input, .. }
+            | Distinct { input, .. }
+            | Sort { input, .. }
+            | Explode { input, .. }
+            | Slice { input, .. }
+            | Melt { input, .. }
+            | Udf { input, .. } => input.check_errors(),
         }
     }
     pub fn describe(&self) -> String {
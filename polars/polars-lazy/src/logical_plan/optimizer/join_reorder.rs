@@ -0,0 +1,406 @@
+//! Cost based reordering of chains of inner joins.
+//!
+//! A chain of inner joins is a left deep tree of `Join` nodes. The inputs of the chain are
+//! estimated with the available metadata (height of in-memory DataFrames, row group statistics
+//! of parquet files that are read when the scan is created and heuristic selectivities of
+//! predicates) and greedily joined such that the intermediate results stay as small as possible.
+use crate::logical_plan::iterator::ArenaLpIter;
+use crate::prelude::*;
+use polars_core::frame::hash_join::JoinType;
+use polars_core::prelude::*;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::predicates::BatchStats;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+// Heuristic selectivities of predicates for which we don't have statistics.
+const EQ_SELECTIVITY: f64 = 0.1;
+const RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+const DEFAULT_SELECTIVITY: f64 = 0.5;
+
+/// Estimated number of rows produced by a logical plan.
+#[derive(Copy, Clone, Debug)]
+struct Cardinality {
+    /// rows after the predicates are applied
+    rows: f64,
+    /// rows of the source before any predicate is applied.
+    /// This is used as an upper bound of the distinct join keys.
+    base: f64,
+}
+
+impl Cardinality {
+    fn filter(self, selectivity: f64) -> Self {
+        Cardinality {
+            rows: self.rows * selectivity,
+            base: self.base,
+        }
+    }
+}
+
+/// An equi-join between two inputs of a join chain on columns with equal names.
+struct Edge {
+    left: usize,
+    right: usize,
+    keys: Vec<Arc<str>>,
+}
+
+fn selectivity(node: Node, expr_arena: &Arena<AExpr>) -> f64 {
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr { left, op, right } => match op {
+            Operator::And => selectivity(*left, expr_arena) * selectivity(*right, expr_arena),
+            Operator::Or => {
+                let l = selectivity(*left, expr_arena);
+                let r = selectivity(*right, expr_arena);
+                l + r - l * r
+            }
+            Operator::Eq => EQ_SELECTIVITY,
+            Operator::NotEq => 1.0 - EQ_SELECTIVITY,
+            Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => RANGE_SELECTIVITY,
+            _ => DEFAULT_SELECTIVITY,
+        },
+        AExpr::Not(input) => 1.0 - selectivity(*input, expr_arena),
+        AExpr::IsNull(_) => EQ_SELECTIVITY,
+        AExpr::IsNotNull(_) => 1.0 - EQ_SELECTIVITY,
+        _ => DEFAULT_SELECTIVITY,
+    }
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_cardinality(
+    row_groups: &[(usize, Option<BatchStats>)],
+    predicate: Option<Node>,
+    n_rows: Option<usize>,
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<Cardinality> {
+    let base = row_groups.iter().map(|(rows, _)| *rows).sum::<usize>() as f64;
+
+    let rows = match predicate {
+        None => base,
+        Some(predicate) => {
+            let phys_pred = DefaultPlanner::default()
+                .create_physical_expr(predicate, Context::Default, expr_arena)
+                .ok()?;
+            // only the row groups that cannot be skipped based on their statistics are read
+            let rows = row_groups
+                .iter()
+                .filter(|(_, stats)| match (phys_pred.as_stats_evaluator(), stats) {
+                    (Some(evaluator), Some(stats)) => {
+                        !matches!(evaluator.should_read(stats), Ok(false))
+                    }
+                    _ => true,
+                })
+                .map(|(rows, _)| *rows)
+                .sum::<usize>() as f64;
+            rows * selectivity(predicate, expr_arena)
+        }
+    };
+    let rows = n_rows.map_or(rows, |n_rows| rows.min(n_rows as f64));
+    Some(Cardinality { rows, base })
+}
+
+fn estimate_cardinality(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<Cardinality> {
+    use ALogicalPlan::*;
+    match lp_arena.get(node) {
+        DataFrameScan { df, selection, .. } => {
+            let base = df.height() as f64;
+            let card = Cardinality { rows: base, base };
+            Some(match selection {
+                Some(predicate) => card.filter(selectivity(*predicate, expr_arena)),
+                None => card,
+            })
+        }
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            predicate, options, ..
+        } => parquet_cardinality(&options.row_groups, *predicate, options.n_rows, expr_arena),
+        Selection { input, predicate } => {
+            let selectivity = selectivity(*predicate, expr_arena);
+            estimate_cardinality(*input, lp_arena, expr_arena).map(|card| card.filter(selectivity))
+        }
+        Slice { input, len, .. } => {
+            estimate_cardinality(*input, lp_arena, expr_arena).map(|card| Cardinality {
+                rows: card.rows.min(*len as f64),
+                base: card.base,
+            })
+        }
+        Projection { input, .. }
+        | LocalProjection { input, .. }
+        | HStack { input, .. }
+        | Sort { input, .. }
+        | Cache { input }
+        | Distinct { input, .. } => estimate_cardinality(*input, lp_arena, expr_arena),
+        _ => None,
+    }
+}
+
+/// Estimated size of the result of an equi-join. The number of distinct keys is bounded
+/// by the unfiltered size of the inputs.
+fn join_size(left: Cardinality, right: Cardinality) -> f64 {
+    left.rows * right.rows / left.base.max(right.base).max(1.0)
+}
+
+/// Inner equi joins without key validation, the relations they validate change when reordered.
+fn is_reorderable_join(options: &JoinOptions) -> bool {
+    matches!(options.how, JoinType::Inner)
+        && options.operators.is_empty()
+        && options.validate == JoinValidation::ManyToMany
+}
+
+fn is_inner_join(lp: &ALogicalPlan) -> bool {
+    matches!(lp, ALogicalPlan::Join { options, .. } if is_reorderable_join(options))
+}
+
+fn column_names(nodes: &[Node], expr_arena: &Arena<AExpr>) -> Option<Vec<Arc<str>>> {
+    nodes
+        .iter()
+        .map(|node| match expr_arena.get(*node) {
+            AExpr::Column(name) => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Greedily determine the order in which the inputs should be joined. Every step joins the input
+/// that leads to the smallest intermediate result. Ties are broken by the original order.
+fn greedy_order(cards: &[Cardinality], edges: &[Edge]) -> Vec<(usize, Option<usize>)> {
+    let n = cards.len();
+    let mut joined = vec![false; n];
+    // (input, edge used to join it)
+    let mut order = Vec::with_capacity(n);
+
+    // start with the pair of inputs that produces the smallest result
+    let (first_edge, mut acc) = edges
+        .iter()
+        .enumerate()
+        .map(|(i, e)| (i, join_size(cards[e.left], cards[e.right])))
+        .fold((0, f64::INFINITY), |best, (i, size)| {
+            if size < best.1 {
+                (i, size)
+            } else {
+                best
+            }
+        });
+    let first = &edges[first_edge];
+    let (a, b) = if first.left < first.right {
+        (first.left, first.right)
+    } else {
+        (first.right, first.left)
+    };
+    order.push((a, None));
+    order.push((b, Some(first_edge)));
+    joined[a] = true;
+    joined[b] = true;
+
+    while order.len() < n {
+        let mut best: Option<(usize, usize, f64)> = None;
+        for (i, e) in edges.iter().enumerate() {
+            let (inner, outer) = match (joined[e.left], joined[e.right]) {
+                (true, false) => (e.left, e.right),
+                (false, true) => (e.right, e.left),
+                _ => continue,
+            };
+            let acc_card = Cardinality {
+                rows: acc,
+                base: cards[inner].base,
+            };
+            let size = join_size(acc_card, cards[outer]);
+            let better = match best {
+                None => true,
+                Some((best_input, _, best_size)) => {
+                    size < best_size || (size == best_size && outer < best_input)
+                }
+            };
+            if better {
+                best = Some((outer, i, size))
+            }
+        }
+        // the inputs of a join chain are always connected
+        let (input, edge, size) = best.unwrap();
+        joined[input] = true;
+        order.push((input, Some(edge)));
+        acc = size;
+    }
+    order
+}
+
+/// Reorders chains of inner joins based on their estimated cardinalities.
+///
+/// Only chains that join on columns with equal names and that don't produce
+/// suffixed columns are reordered, so that the join result is independent of the join order.
+/// A projection is added to restore the original column order.
+pub(crate) struct JoinReorder {}
+
+impl JoinReorder {
+    pub(crate) fn optimize(
+        &self,
+        lp_top: Node,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) {
+        // find the top nodes of all join chains
+        let mut joins = vec![];
+        let mut left_inputs = HashSet::new();
+        for (node, lp) in (&*lp_arena).iter(lp_top) {
+            if let ALogicalPlan::Join { input_left, .. } = lp {
+                if is_inner_join(lp) {
+                    joins.push(node);
+                    if is_inner_join(lp_arena.get(*input_left)) {
+                        left_inputs.insert(*input_left);
+                    }
+                }
+            }
+        }
+        for top in joins {
+            if !left_inputs.contains(&top) {
+                self.reorder_chain(top, lp_arena, expr_arena);
+            }
+        }
+    }
+
+    fn reorder_chain(
+        &self,
+        top: Node,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Option<()> {
+        // collect the inputs and the join keys from the bottom to the top of the chain
+        let mut inputs = vec![];
+        let mut keys = vec![];
+        let mut node = top;
+        let join_options = match lp_arena.get(top) {
+            ALogicalPlan::Join { options, .. } => options.clone(),
+            _ => unreachable!(),
+        };
+        while let ALogicalPlan::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } = lp_arena.get(node)
+        {
+            // all joins get the options of the top join
+            if !is_reorderable_join(options) || options.join_nulls != join_options.join_nulls {
+                break;
+            }
+            let left_names = column_names(left_on, expr_arena)?;
+            let right_names = column_names(right_on, expr_arena)?;
+            if left_names != right_names {
+                return None;
+            }
+            inputs.push(*input_right);
+            keys.push(left_names);
+            node = *input_left;
+        }
+        inputs.push(node);
+        inputs.reverse();
+        keys.reverse();
+
+        if inputs.len() < 3 {
+            return None;
+        }
+
+        let schemas = inputs
+            .iter()
+            .map(|node| lp_arena.get(*node).schema(lp_arena).clone())
+            .collect::<Vec<_>>();
+
+        // the join `i` joins input `i + 1` with the inputs `0..=i`
+        let mut edges = Vec::with_capacity(keys.len());
+        let mut acc_names: HashSet<&str> = schemas[0]
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect();
+        for (i, keys) in keys.into_iter().enumerate() {
+            let right = i + 1;
+            let left = (0..right)
+                .find(|idx| keys.iter().all(|key| schemas[*idx].index_of(key).is_ok()))?;
+            // a name collision would lead to suffixed columns
+            for f in schemas[right].fields() {
+                let name = f.name().as_str();
+                if !keys.iter().any(|key| key.as_ref() == name) && !acc_names.insert(name) {
+                    return None;
+                }
+            }
+            edges.push(Edge { left, right, keys });
+        }
+
+        let cards = inputs
+            .iter()
+            .map(|node| estimate_cardinality(*node, lp_arena, expr_arena))
+            .collect::<Option<Vec<_>>>()?;
+
+        let order = greedy_order(&cards, &edges);
+        if order.iter().enumerate().all(|(i, (input, _))| i == *input) {
+            return None;
+        }
+
+        // build the new chain
+        let mut acc = inputs[order[0].0];
+        let mut acc_schema = schemas[order[0].0].clone();
+        for (input, edge) in &order[1..] {
+            // every input except the first is joined via an edge
+            let keys = &edges[edge.unwrap()].keys;
+            if keys.iter().any(|key| acc_schema.index_of(key).is_err()) {
+                return None;
+            }
+            let mut fields = acc_schema.fields().clone();
+            for f in schemas[*input].fields() {
+                if !keys.iter().any(|key| key.as_ref() == f.name().as_str()) {
+                    if acc_schema.index_of(f.name()).is_ok() {
+                        return None;
+                    }
+                    fields.push(f.clone());
+                }
+            }
+            acc_schema = Arc::new(Schema::new(fields));
+
+            let left_on = keys
+                .iter()
+                .map(|key| expr_arena.add(AExpr::Column(key.clone())))
+                .collect();
+            let right_on = keys
+                .iter()
+                .map(|key| expr_arena.add(AExpr::Column(key.clone())))
+                .collect();
+            acc = lp_arena.add(ALogicalPlan::Join {
+                input_left: acc,
+                input_right: inputs[*input],
+                schema: acc_schema.clone(),
+                left_on,
+                right_on,
+                options: join_options.clone(),
+            });
+        }
+
+        // restore the original column order
+        let schema = lp_arena.get(top).schema(lp_arena).clone();
+        if schema.len() != acc_schema.len() {
+            return None;
+        }
+        let expr = schema
+            .fields()
+            .iter()
+            .map(|f| {
+                acc_schema.index_of(f.name())?;
+                Ok(expr_arena.add(AExpr::Column(Arc::from(f.name().as_str()))))
+            })
+            .collect::<Result<Vec<_>>>()
+            .ok()?;
+        lp_arena.replace(
+            top,
+            ALogicalPlan::Projection {
+                input: acc,
+                expr,
+                schema,
+            },
+        );
+        Some(())
+    }
+}
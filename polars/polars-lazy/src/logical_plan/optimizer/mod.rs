@@ -6,6 +6,7 @@ pub(crate) mod aggregate_pushdown;
 pub(crate) mod aggregate_scan_projections;
 pub(crate) mod drop_nulls;
 pub(crate) mod fast_projection;
+pub(crate) mod join_reorder;
 pub(crate) mod predicate_pushdown;
 pub(crate) mod projection_pushdown;
 pub(crate) mod simplify_expr;
@@ -13,6 +14,8 @@ pub(crate) mod slice_pushdown;
 pub(crate) mod stack_opt;
 pub(crate) mod type_coercion;
 
+pub use stack_opt::{OptimizationRule, OptimizationRuleFactory, StackOptimizer};
+
 pub trait Optimize {
     fn optimize(&self, logical_plan: LogicalPlan) -> Result<LogicalPlan>;
 }
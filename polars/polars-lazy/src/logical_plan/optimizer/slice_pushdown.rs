@@ -7,7 +7,7 @@ pub(crate) struct SlicePushDown {}
 #[derive(Copy, Clone)]
 struct State {
     offset: i64,
-    len: u32,
+    len: IdxSize,
 }
 
 impl SlicePushDown {
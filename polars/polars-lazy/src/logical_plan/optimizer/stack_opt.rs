@@ -1,6 +1,7 @@
 use crate::logical_plan::aexpr::AExpr;
 use crate::logical_plan::alp::ALogicalPlan;
 use crate::prelude::{Arena, Node};
+use std::sync::Arc;
 
 /// Optimizer that uses a stack and memory arenas in favor of recursion
 pub struct StackOptimizer {}
@@ -66,6 +67,14 @@ impl StackOptimizer {
     }
 }
 
+/// Creates a fresh instance of a user defined [`OptimizationRule`] for every optimization pass.
+pub type OptimizationRuleFactory = Arc<dyn Fn() -> Box<dyn OptimizationRule> + Send + Sync>;
+
+/// A rewrite rule that is applied by the [`StackOptimizer`] until a fixed point is reached.
+///
+/// Rules get mutable access to the arenas and return a replacement for the node they
+/// visit, or `None` if the node should stay as is. Custom rules can be registered on a
+/// `LazyFrame` with `with_optimization_rule`.
 pub trait OptimizationRule {
     ///  Optimize (subplan) in LogicalPlan
     ///
@@ -80,6 +89,13 @@ pub trait OptimizationRule {
     ) -> Option<ALogicalPlan> {
         None
     }
+
+    ///  Optimize an expression that is a member of a LogicalPlan node
+    ///
+    /// * `expr_arena` - Expression memory arena
+    /// * `expr_node` - node of the current expression
+    /// * `lp_arena` - LogicalPlan memory arena
+    /// * `lp_node` - node of the LogicalPlan the expression belongs to
     fn optimize_expr(
         &self,
         _expr_arena: &mut Arena<AExpr>,
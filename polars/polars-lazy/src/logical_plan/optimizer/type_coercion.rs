@@ -151,9 +151,11 @@ impl OptimizationRule for TypeCoercionRule {
                             | Operator::Lt
                             | Operator::GtEq
                             | Operator::LtEq
-                    ) && ((type_left == DataType::Categorical
-                        && type_right == DataType::Utf8)
-                        || (type_left == DataType::Utf8 && type_right == DataType::Categorical));
+                    ) && matches!(
+                        (&type_left, &type_right),
+                        (DataType::Categorical | DataType::Enum(_), DataType::Utf8)
+                            | (DataType::Utf8, DataType::Categorical | DataType::Enum(_))
+                    );
 
                     let datetime_arithmetic = matches!(op, Operator::Minus | Operator::Plus)
                         && matches!(
@@ -172,9 +174,11 @@ impl OptimizationRule for TypeCoercionRule {
 
                         let mut st = use_supertype(st, left, right, &type_left, &type_right);
 
-                        let cat_str_arithmetic = (type_left == DataType::Categorical
-                            && type_right == DataType::Utf8)
-                            || (type_left == DataType::Utf8 && type_right == DataType::Categorical);
+                        let cat_str_arithmetic = matches!(
+                            (&type_left, &type_right),
+                            (DataType::Categorical | DataType::Enum(_), DataType::Utf8)
+                                | (DataType::Utf8, DataType::Categorical | DataType::Enum(_))
+                        );
                         if cat_str_arithmetic {
                             st = DataType::Utf8
                         }
@@ -212,6 +216,63 @@ impl OptimizationRule for TypeCoercionRule {
                     None
                 }
             }
+            AExpr::Function {
+                ref input,
+                ref function,
+                ref output_type,
+                options,
+            } if options.cast_to_float => {
+                let plan = lp_arena.get(lp_node);
+                let mut inputs = [None, None];
+
+                if is_scan(plan) {
+                    inputs[0] = Some(lp_node);
+                } else {
+                    plan.copy_inputs(&mut inputs);
+                };
+
+                let input_schema = lp_arena.get(inputs[0]?).schema(lp_arena);
+                let mut input = input.clone();
+                let function = function.clone();
+                let output_type = output_type.clone();
+
+                let mut modified = false;
+                for node in input.iter_mut() {
+                    let dtype = expr_arena
+                        .get(*node)
+                        .get_type(input_schema, Context::Default, expr_arena)
+                        .expect("could not get dtype");
+                    let is_integer = matches!(
+                        dtype,
+                        DataType::UInt8
+                            | DataType::UInt16
+                            | DataType::UInt32
+                            | DataType::UInt64
+                            | DataType::Int8
+                            | DataType::Int16
+                            | DataType::Int32
+                            | DataType::Int64
+                    );
+                    if is_integer {
+                        *node = expr_arena.add(AExpr::Cast {
+                            expr: *node,
+                            data_type: DataType::Float64,
+                            strict: false,
+                        });
+                        modified = true;
+                    }
+                }
+                if modified {
+                    Some(AExpr::Function {
+                        input,
+                        function,
+                        output_type,
+                        options,
+                    })
+                } else {
+                    None
+                }
+            }
             _ => None,
         }
     }
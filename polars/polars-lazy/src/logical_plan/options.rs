@@ -1,6 +1,8 @@
 use crate::prelude::*;
 use polars_core::prelude::*;
 use polars_io::csv::{CsvEncoding, NullValues};
+#[cfg(feature = "parquet")]
+use polars_io::parquet::predicates::BatchStats;
 use polars_io::RowCount;
 
 #[derive(Clone, Debug)]
@@ -28,6 +30,9 @@ pub struct ParquetOptions {
     pub(crate) cache: bool,
     pub(crate) parallel: bool,
     pub(crate) row_count: Option<RowCount>,
+    /// The number of rows and the column statistics of every row group. They are read
+    /// together with the schema when the scan is created.
+    pub(crate) row_groups: Arc<Vec<(usize, Option<BatchStats>)>>,
 }
 
 #[derive(Clone, Debug)]
@@ -42,7 +47,7 @@ pub struct IpcScanOptions {
 pub struct UnionOptions {
     pub(crate) slice: bool,
     pub(crate) slice_offset: i64,
-    pub(crate) slice_len: u32,
+    pub(crate) slice_len: IdxSize,
 }
 
 #[derive(Clone, Debug)]
@@ -110,6 +115,8 @@ pub struct FunctionOptions {
     pub(crate) auto_explode: bool,
     // used for formatting
     pub(crate) fmt_str: &'static str,
+    /// Cast integer inputs to `Float64` during type coercion.
+    pub(crate) cast_to_float: bool,
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -9,6 +9,7 @@ pub(crate) struct DropDuplicatesExec {
 
 impl Executor for DropDuplicatesExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let df = self.input.execute(state)?;
         let subset = self.options.subset.as_ref().map(|v| &***v);
         let keep = self.options.keep_strategy;
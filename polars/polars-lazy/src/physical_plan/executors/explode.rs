@@ -9,6 +9,7 @@ pub(crate) struct ExplodeExec {
 
 impl Executor for ExplodeExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let df = self.input.execute(state)?;
         dbg!(df.explode(&self.columns))
     }
@@ -15,6 +15,7 @@ impl FilterExec {
 
 impl Executor for FilterExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let df = self.input.execute(state)?;
         let s = self.predicate.evaluate(&df, state)?;
         let mask = s.bool().expect("filter predicate wasn't of type boolean");
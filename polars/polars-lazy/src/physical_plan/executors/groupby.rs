@@ -2,6 +2,7 @@ use super::*;
 use crate::logical_plan::Context;
 use crate::prelude::utils::as_aggregated;
 use crate::utils::rename_aexpr_root_names;
+#[cfg(feature = "spill")]
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
 use polars_core::POOL;
 use rayon::prelude::*;
@@ -55,6 +56,7 @@ fn groupby_helper(
         let get_agg = || aggs
             .par_iter()
             .map(|expr| {
+                state.should_stop()?;
                 let opt_agg = as_aggregated(expr.as_ref(), &df, groups, state)?;
                 if let Some(agg) = &opt_agg {
                     if agg.len() != groups.len() {
@@ -77,12 +79,126 @@ fn groupby_helper(
     DataFrame::new(columns)
 }
 
+/// The input of a groupby that may spill to disk.
+#[cfg(feature = "spill")]
+enum SpillableInput {
+    /// The input fits in the memory limit and is aggregated in memory.
+    InMemory(DataFrame),
+    /// The input exceeded the memory limit and is already aggregated partition by partition.
+    Aggregated(DataFrame),
+}
+
+/// Pull the input in batches and buffer them until they exceed the memory limit. From then on
+/// the batches are hash partitioned on the keys as they come in, partitions that exceed the
+/// memory limit are spilled to disk and the partitions are aggregated one by one.
+///
+/// Only inputs that are produced in batches, such as IPC and parquet scans, stay out of memory;
+/// other inputs are produced at once and only the aggregation is partitioned.
+#[cfg(feature = "spill")]
+fn groupby_spilled_helper(
+    input: &mut dyn Executor,
+    keys: &[Arc<dyn PhysicalExpr>],
+    aggs: &[Arc<dyn PhysicalExpr>],
+    state: &ExecutionState,
+    memory_limit: usize,
+) -> Result<SpillableInput> {
+    use polars_core::frame::groupby::HashPartitioner;
+
+    let push = |partitioner: &mut HashPartitioner, df: &DataFrame| -> Result<()> {
+        let n_chunks = df.estimated_size() / std::cmp::max(memory_limit / 4, 1);
+        let n_chunks = n_chunks.clamp(1, std::cmp::max(df.height(), 1));
+        for chunk in split_df(df, n_chunks)? {
+            state.should_stop()?;
+            let chunk_keys = keys
+                .iter()
+                .map(|e| e.evaluate(&chunk, state))
+                .collect::<Result<_>>()?;
+            partitioner.push(&chunk, chunk_keys)?;
+        }
+        Ok(())
+    };
+
+    let mut buffered = vec![];
+    let mut buffered_bytes = 0;
+    let mut partitioner: Option<HashPartitioner> = None;
+    input.execute_batched(state, &mut |df| {
+        state.should_stop()?;
+        match &mut partitioner {
+            Some(partitioner) => push(partitioner, &df),
+            None => {
+                buffered_bytes += df.estimated_size();
+                buffered.push(df);
+                if buffered_bytes > memory_limit {
+                    if state.verbose {
+                        eprintln!(
+                            "input exceeds the memory limit: running SPILLED HASH AGGREGATION"
+                        )
+                    }
+                    let mut new = HashPartitioner::new(&state.spill_options(memory_limit))?;
+                    for df in std::mem::take(&mut buffered) {
+                        push(&mut new, &df)?;
+                    }
+                    partitioner = Some(new);
+                }
+                Ok(())
+            }
+        }
+    })?;
+
+    let mut partitioner = match partitioner {
+        Some(partitioner) => partitioner,
+        None => {
+            let mut df = accumulate_dataframes_vertical(buffered)?;
+            df.rechunk();
+            return Ok(SpillableInput::InMemory(df));
+        }
+    };
+
+    let mut out = Vec::with_capacity(partitioner.n_partitions());
+    for partition in 0..partitioner.n_partitions() {
+        state.should_stop()?;
+        if let Some(df) = partitioner.load_partition(partition)? {
+            let partition_keys = keys
+                .iter()
+                .map(|e| e.evaluate(&df, state))
+                .collect::<Result<_>>()?;
+            out.push(groupby_helper(
+                df,
+                partition_keys,
+                aggs,
+                None,
+                state,
+                false,
+            )?);
+        }
+    }
+    accumulate_dataframes_vertical(out).map(SpillableInput::Aggregated)
+}
+
 impl Executor for GroupByExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
+        #[cfg(feature = "spill")]
+        let df = match state.memory_limit {
+            Some(memory_limit) if self.apply.is_none() && !self.maintain_order => {
+                match groupby_spilled_helper(
+                    self.input.as_mut(),
+                    &self.keys,
+                    &self.aggs,
+                    state,
+                    memory_limit,
+                )? {
+                    SpillableInput::InMemory(df) => df,
+                    SpillableInput::Aggregated(out) => return Ok(out),
+                }
+            }
+            _ => self.input.execute(state)?,
+        };
+        #[cfg(not(feature = "spill"))]
+        let df = self.input.execute(state)?;
         if state.verbose {
             eprintln!("aggregates are not partitionable: running default HASH AGGREGATION")
         }
-        let df = self.input.execute(state)?;
         let keys = self
             .keys
             .iter()
@@ -141,33 +257,38 @@ fn run_partitions(
     POOL.install(|| {
         dfs.into_par_iter()
             .map(|df| {
-                let key = exec.key.evaluate(&df, state)?;
-                let phys_aggs = &exec.phys_aggs;
-                let gb = df.groupby_with_series(vec![key], false, maintain_order)?;
-                let groups = gb.get_groups();
-
-                let mut columns = gb.keys();
-                let agg_columns = phys_aggs
-                    .par_iter()
-                    .map(|expr| {
-                        let agg_expr = expr.as_agg_expr()?;
-                        let opt_agg = agg_expr.evaluate_partitioned(&df, groups, state)?;
-                        if let Some(agg) = &opt_agg {
-                            if agg[0].len() != groups.len() {
-                                panic!(
-                                    "returned aggregation is a different length: {} than the group lengths: {}",
-                                    agg.len(),
-                                    groups.len()
-                                )
-                            }
-                        };
-                        Ok(opt_agg)
-                    }).collect::<Result<Vec<_>>>()?;
-
-                columns.extend(agg_columns.into_iter().flatten().flat_map(|v| v.into_iter()));
-
-                let df = DataFrame::new_no_checks(columns);
-                Ok(df)
+                state.should_stop()?;
+                // the workers don't see the settings of the calling thread
+                let config = state.config.clone();
+                config.scoped_arc(|_| {
+                    let key = exec.key.evaluate(&df, state)?;
+                    let phys_aggs = &exec.phys_aggs;
+                    let gb = df.groupby_with_series(vec![key], false, maintain_order)?;
+                    let groups = gb.get_groups();
+
+                    let mut columns = gb.keys();
+                    let agg_columns = phys_aggs
+                        .par_iter()
+                        .map(|expr| {
+                            let agg_expr = expr.as_agg_expr()?;
+                            let opt_agg = agg_expr.evaluate_partitioned(&df, groups, state)?;
+                            if let Some(agg) = &opt_agg {
+                                if agg[0].len() != groups.len() {
+                                    panic!(
+                                        "returned aggregation is a different length: {} than the group lengths: {}",
+                                        agg.len(),
+                                        groups.len()
+                                    )
+                                }
+                            };
+                            Ok(opt_agg)
+                        }).collect::<Result<Vec<_>>>()?;
+
+                    columns.extend(agg_columns.into_iter().flatten().flat_map(|v| v.into_iter()));
+
+                    let df = DataFrame::new_no_checks(columns);
+                    Ok(df)
+                })
             })
     }).collect()
 }
@@ -214,6 +335,22 @@ fn sample_cardinality(key: &Series, sample_size: usize) -> f32 {
 
 impl Executor for PartitionGroupByExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
+        #[cfg(feature = "spill")]
+        let original_df = match state.memory_limit {
+            Some(memory_limit) if !self.maintain_order => match groupby_spilled_helper(
+                self.input.as_mut(),
+                &[self.key.clone()],
+                &self.phys_aggs,
+                state,
+                memory_limit,
+            )? {
+                SpillableInput::InMemory(df) => df,
+                SpillableInput::Aggregated(out) => return Ok(out),
+            },
+            _ => self.input.execute(state)?,
+        };
+        #[cfg(not(feature = "spill"))]
         let original_df = self.input.execute(state)?;
 
         // already get the keys. This is the very last minute decision which groupby method we choose.
@@ -222,18 +359,16 @@ impl Executor for PartitionGroupByExec {
         // of groups.
         let key = self.key.evaluate(&original_df, state)?;
 
-        if std::env::var("POLARS_NO_PARTITION").is_ok() {
+        if state.config.no_partition {
             if state.verbose {
-                eprintln!("POLARS_NO_PARTITION set: running default HASH AGGREGATION")
+                eprintln!("no_partition set: running default HASH AGGREGATION")
             }
             return groupby_helper(original_df, vec![key], &self.phys_aggs, None, state, false);
         }
 
         // 0.5% is approximately the tipping point
         // and 1% for estimated (we get that by 1/2 sample frac)
-        let mut cardinality_frac = std::env::var("POLARS_PARTITION_CARDINALITY_FRAC")
-            .map(|s| s.parse::<f32>().unwrap())
-            .unwrap_or(0.005f32);
+        let mut cardinality_frac = state.config.partition_cardinality_frac.unwrap_or(0.005f32);
 
         #[cfg(feature = "dtype-categorical")]
         let (frac, sampled_method) = if let Ok(ca) = key.categorical() {
@@ -243,26 +378,22 @@ impl Executor for PartitionGroupByExec {
 
             (cat_map.len() as f32 / ca.len() as f32, "known")
         } else {
-            let sample_frac = std::env::var("POLARS_PARTITION_SAMPLE_FRAC")
-                .map(|s| s.parse::<f32>().unwrap())
-                .unwrap_or(0.001);
+            let sample_frac = state.config.partition_sample_frac;
             let sample_size = (original_df.height() as f32 * sample_frac) as usize;
 
             // if not set, we set it to 1% for sampling
-            if std::env::var("POLARS_PARTITION_CARDINALITY_FRAC").is_err() {
+            if state.config.partition_cardinality_frac.is_none() {
                 cardinality_frac = 0.01f32;
             }
             (sample_cardinality(&key, sample_size), "estimated")
         };
         #[cfg(not(feature = "dtype-categorical"))]
         let (frac, sampled_method) = {
-            let sample_frac = std::env::var("POLARS_PARTITION_SAMPLE_FRAC")
-                .map(|s| s.parse::<f32>().unwrap())
-                .unwrap_or(0.001);
+            let sample_frac = state.config.partition_sample_frac;
             let sample_size = (original_df.height() as f32 * sample_frac) as usize;
 
             // if not set, we set it to 1% for sampling
-            if std::env::var("POLARS_PARTITION_CARDINALITY_FRAC").is_err() {
+            if state.config.partition_cardinality_frac.is_none() {
                 cardinality_frac = 0.01f32;
             }
             (sample_cardinality(&key, sample_size), "estimated")
@@ -292,6 +423,7 @@ impl Executor for PartitionGroupByExec {
         // MERGE phase
         // merge and hash aggregate again
         let df = accumulate_dataframes_vertical(dfs)?;
+        state.should_stop()?;
         let key = self.key.evaluate(&df, state)?;
 
         // first get mutable access and optionally sort
@@ -14,6 +14,7 @@ pub(crate) struct GroupByDynamicExec {
 
 impl Executor for GroupByDynamicExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         #[cfg(feature = "dynamic_groupby")]
         {
             let df = self.input.execute(state)?;
@@ -11,6 +11,7 @@ pub(crate) struct GroupByRollingExec {
 
 impl Executor for GroupByRollingExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         #[cfg(feature = "dynamic_groupby")]
         {
             let df = self.input.execute(state)?;
@@ -19,6 +19,11 @@ pub struct JoinExec {
     // not used if asof not activated
     #[allow(dead_code)]
     asof_by_right: Vec<String>,
+    // not used if range_join not activated
+    #[allow(dead_code)]
+    operators: Vec<JoinOperator>,
+    validate: JoinValidation,
+    join_nulls: bool,
 }
 
 impl JoinExec {
@@ -33,6 +38,9 @@ impl JoinExec {
         suffix: Cow<'static, str>,
         asof_by_left: Vec<String>,
         asof_by_right: Vec<String>,
+        operators: Vec<JoinOperator>,
+        validate: JoinValidation,
+        join_nulls: bool,
     ) -> Self {
         JoinExec {
             input_left: Some(input_left),
@@ -44,12 +52,16 @@ impl JoinExec {
             suffix,
             asof_by_left,
             asof_by_right,
+            operators,
+            validate,
+            join_nulls,
         }
     }
 }
 
 impl Executor for JoinExec {
     fn execute<'a>(&'a mut self, state: &'a ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let mut input_left = self.input_left.take().unwrap();
         let mut input_right = self.input_right.take().unwrap();
 
@@ -62,11 +74,13 @@ impl Executor for JoinExec {
             POOL.join(
                 move || {
                     FETCH_ROWS.with(|fr| fr.set(fetch_rows));
-                    input_left.execute(&state_left)
+                    let config = state_left.config.clone();
+                    config.scoped_arc(|_| input_left.execute(&state_left))
                 },
                 move || {
                     FETCH_ROWS.with(|fr| fr.set(fetch_rows));
-                    input_right.execute(&state_right)
+                    let config = state_right.config.clone();
+                    config.scoped_arc(|_| input_right.execute(&state_right))
                 },
             )
         } else {
@@ -75,6 +89,38 @@ impl Executor for JoinExec {
 
         let df_left = df_left?;
         let df_right = df_right?;
+        state.should_stop()?;
+
+        if !self.operators.is_empty() {
+            #[cfg(feature = "range_join")]
+            {
+                let left_on = self
+                    .left_on
+                    .iter()
+                    .map(|e| e.evaluate(&df_left, state))
+                    .collect::<Result<Vec<_>>>()?;
+                let right_on = self
+                    .right_on
+                    .iter()
+                    .map(|e| e.evaluate(&df_right, state))
+                    .collect::<Result<Vec<_>>>()?;
+                let df = df_left.join_where_from_series(
+                    &df_right,
+                    &left_on,
+                    &right_on,
+                    &self.operators,
+                    Some(self.suffix.clone().into_owned()),
+                );
+                if state.verbose {
+                    eprintln!("join_where dataframes finished");
+                };
+                return df;
+            }
+            #[cfg(not(feature = "range_join"))]
+            {
+                panic!("activate feature range_join")
+            }
+        }
 
         let left_names = self
             .left_on
@@ -107,23 +153,32 @@ impl Executor for JoinExec {
                 &self.asof_by_right,
             )
         } else {
-            df_left.join(
+            // the hash tables check the token while they are built and probed
+            state.cancel_token.scoped(|| {
+                df_left.join_with_options(
+                    &df_right,
+                    &left_names,
+                    &right_names,
+                    self.how,
+                    Some(self.suffix.clone().into_owned()),
+                    self.validate,
+                    self.join_nulls,
+                )
+            })
+        };
+
+        #[cfg(not(feature = "asof_join"))]
+        let df = state.cancel_token.scoped(|| {
+            df_left.join_with_options(
                 &df_right,
                 &left_names,
                 &right_names,
                 self.how,
                 Some(self.suffix.clone().into_owned()),
+                self.validate,
+                self.join_nulls,
             )
-        };
-
-        #[cfg(not(feature = "asof_join"))]
-        let df = df_left.join(
-            &df_right,
-            &left_names,
-            &right_names,
-            self.how,
-            Some(self.suffix.clone().into_owned()),
-        );
+        });
 
         if state.verbose {
             eprintln!("{:?} join dataframes finished", self.how);
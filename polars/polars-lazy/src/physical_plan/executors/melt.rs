@@ -4,13 +4,13 @@ use polars_core::prelude::*;
 
 pub struct MeltExec {
     pub input: Box<dyn Executor>,
-    pub id_vars: Arc<Vec<String>>,
-    pub value_vars: Arc<Vec<String>>,
+    pub args: Arc<MeltArgs>,
 }
 
 impl Executor for MeltExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let df = self.input.execute(state)?;
-        df.melt(self.id_vars.as_slice(), self.value_vars.as_slice())
+        df.melt2((*self.args).clone())
     }
 }
@@ -21,8 +21,6 @@ use polars_core::POOL;
 use rayon::prelude::*;
 use std::path::PathBuf;
 
-const POLARS_VERBOSE: &str = "POLARS_VERBOSE";
-
 fn set_n_rows(n_rows: Option<usize>) -> Option<usize> {
     let fetch_rows = FETCH_ROWS.with(|fetch_rows| fetch_rows.get());
     match fetch_rows {
@@ -15,6 +15,7 @@ pub struct ProjectionExec {
 
 impl Executor for ProjectionExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let df = self.input.execute(state)?;
 
         let df = evaluate_physical_expressions(&df, &self.expr, state, self.has_windows);
@@ -78,6 +78,7 @@ pub struct IpcExec {
 #[cfg(feature = "ipc")]
 impl Executor for IpcExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let (cache_key, cached) = cache_hit(&self.path, &self.predicate, state);
         if let Some(df) = cached {
             return Ok(df);
@@ -93,6 +94,7 @@ impl Executor for IpcExec {
         let df = IpcReader::new(file)
             .with_n_rows(n_rows)
             .with_row_count(std::mem::take(&mut self.options.row_count))
+            .with_cancel_token(Some(state.cancel_token.clone()))
             .finish_with_scan_ops(
                 predicate,
                 aggregate,
@@ -108,6 +110,45 @@ impl Executor for IpcExec {
 
         Ok(df)
     }
+
+    fn execute_batched(
+        &mut self,
+        state: &ExecutionState,
+        on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+    ) -> Result<()> {
+        // the aggregations need the whole file
+        if !self.aggregate.is_empty() {
+            let df = self.execute(state)?;
+            return on_batch(df);
+        }
+        state.should_stop()?;
+        // the batches are not cached, as that would keep the whole file in memory
+        if let (_, Some(df)) = cache_hit(&self.path, &self.predicate, state) {
+            return on_batch(df);
+        }
+        let (file, projection, n_rows, _, predicate) = prepare_scan_args(
+            &self.path,
+            &self.predicate,
+            &mut self.options.with_columns,
+            &mut self.schema,
+            self.options.n_rows,
+            &self.aggregate,
+        );
+        IpcReader::new(file)
+            .with_n_rows(n_rows)
+            .with_row_count(std::mem::take(&mut self.options.row_count))
+            .with_cancel_token(Some(state.cancel_token.clone()))
+            .read_batches_with_scan_ops(
+                predicate,
+                projection.as_ref().map(|v| v.as_ref()),
+                on_batch,
+            )?;
+
+        if state.verbose {
+            println!("ipc {:?} read in batches", self.path);
+        }
+        Ok(())
+    }
 }
 
 #[cfg(feature = "parquet")]
@@ -141,6 +182,7 @@ impl ParquetExec {
 #[cfg(feature = "parquet")]
 impl Executor for ParquetExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let (cache_key, cached) = cache_hit(&self.path, &self.predicate, state);
         if let Some(df) = cached {
             return Ok(df);
@@ -158,6 +200,7 @@ impl Executor for ParquetExec {
             .with_n_rows(n_rows)
             .read_parallel(self.options.parallel)
             .with_row_count(std::mem::take(&mut self.options.row_count))
+            .with_cancel_token(Some(state.cancel_token.clone()))
             .finish_with_scan_ops(
                 predicate,
                 aggregate,
@@ -173,6 +216,46 @@ impl Executor for ParquetExec {
 
         Ok(df)
     }
+
+    fn execute_batched(
+        &mut self,
+        state: &ExecutionState,
+        on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+    ) -> Result<()> {
+        // the aggregations need the whole file
+        if !self.aggregate.is_empty() {
+            let df = self.execute(state)?;
+            return on_batch(df);
+        }
+        state.should_stop()?;
+        // the batches are not cached, as that would keep the whole file in memory
+        if let (_, Some(df)) = cache_hit(&self.path, &self.predicate, state) {
+            return on_batch(df);
+        }
+        let (file, projection, n_rows, _, predicate) = prepare_scan_args(
+            &self.path,
+            &self.predicate,
+            &mut self.options.with_columns,
+            &mut self.schema,
+            self.options.n_rows,
+            &self.aggregate,
+        );
+        ParquetReader::new(file)
+            .with_n_rows(n_rows)
+            .read_parallel(self.options.parallel)
+            .with_row_count(std::mem::take(&mut self.options.row_count))
+            .with_cancel_token(Some(state.cancel_token.clone()))
+            .read_batches_with_scan_ops(
+                predicate,
+                projection.as_ref().map(|v| v.as_ref()),
+                on_batch,
+            )?;
+
+        if state.verbose {
+            println!("parquet {:?} read in batches", self.path);
+        }
+        Ok(())
+    }
 }
 
 #[cfg(feature = "csv-file")]
@@ -187,6 +270,7 @@ pub struct CsvExec {
 #[cfg(feature = "csv-file")]
 impl Executor for CsvExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let (cache_key, cached) = cache_hit(&self.path, &self.predicate, state);
         if let Some(df) = cached {
             return Ok(df);
@@ -235,6 +319,7 @@ impl Executor for CsvExec {
             .with_encoding(self.options.encoding)
             .with_rechunk(self.options.rechunk)
             .with_row_count(std::mem::take(&mut self.options.row_count))
+            .with_cancel_token(Some(state.cancel_token.clone()))
             .finish()?;
 
         if self.options.cache {
@@ -258,6 +343,7 @@ pub struct DataFrameExec {
 
 impl Executor for DataFrameExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let df = mem::take(&mut self.df);
         let mut df = Arc::try_unwrap(df).unwrap_or_else(|df| (*df).clone());
 
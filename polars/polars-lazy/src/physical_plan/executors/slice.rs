@@ -5,11 +5,12 @@ use polars_core::prelude::*;
 pub struct SliceExec {
     pub input: Box<dyn Executor>,
     pub offset: i64,
-    pub len: u32,
+    pub len: IdxSize,
 }
 
 impl Executor for SliceExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let df = self.input.execute(state)?;
         Ok(df.slice(self.offset, self.len as usize))
     }
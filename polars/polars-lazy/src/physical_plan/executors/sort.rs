@@ -1,6 +1,8 @@
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_core::prelude::*;
+#[cfg(feature = "spill")]
+use polars_core::{frame::external_sort::ExternalSorter, utils::split_df};
 
 pub(crate) struct SortExec {
     pub(crate) input: Box<dyn Executor>,
@@ -10,6 +12,18 @@ pub(crate) struct SortExec {
 
 impl Executor for SortExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
+        #[cfg(feature = "spill")]
+        if let Some(memory_limit) = state.memory_limit {
+            let reverse = std::mem::take(&mut self.reverse);
+            return sort_spilled(
+                self.input.as_mut(),
+                &self.by_column,
+                reverse,
+                memory_limit,
+                state,
+            );
+        }
         let df = self.input.execute(state)?;
 
         let by_columns = self
@@ -32,3 +46,77 @@ impl Executor for SortExec {
         df.sort_impl(by_columns, reverse)
     }
 }
+
+/// Pull the input in batches and sort it with an external sort that spills sorted runs to disk
+/// once the batches exceed the memory limit. The sort keys are added as hidden columns, so that
+/// they are available in every run.
+///
+/// Only inputs that are produced in batches, such as IPC and parquet scans, stay out of memory,
+/// and only if the input is sorted by columns: other sort expressions may depend on the whole
+/// input, so it is materialized before the keys are evaluated.
+#[cfg(feature = "spill")]
+fn sort_spilled(
+    input: &mut dyn Executor,
+    by_column: &[Arc<dyn PhysicalExpr>],
+    reverse: Vec<bool>,
+    memory_limit: usize,
+    state: &ExecutionState,
+) -> Result<DataFrame> {
+    if state.verbose {
+        eprintln!("memory limit is set: running EXTERNAL SORT")
+    }
+    let by = (0..by_column.len())
+        .map(|i| format!("_POLARS_SORT_KEY_{}", i))
+        .collect::<Vec<_>>();
+    // broadcast ordering
+    let sort_options = (0..by.len())
+        .map(|i| {
+            let reverse = if reverse.len() == 1 {
+                reverse[0]
+            } else {
+                reverse[i]
+            };
+            // keep the null placement of the in-memory sort; nulls are the smallest values
+            SortOptions {
+                descending: reverse,
+                nulls_last: reverse,
+            }
+        })
+        .collect::<Vec<_>>();
+    let mut sorter = ExternalSorter::new(
+        by.clone(),
+        &sort_options,
+        &state.spill_options(memory_limit),
+    )?;
+
+    let mut n_columns = 0;
+    let mut push = |mut df: DataFrame| -> Result<()> {
+        state.should_stop()?;
+        n_columns = df.width();
+        for (e, name) in by_column.iter().zip(&by) {
+            let mut s = e.evaluate(&df, state)?;
+            s.rename(name);
+            df.with_column(s)?;
+        }
+        // push the batch in chunks that fit in the memory limit, so that runs can be spilled
+        // in between
+        let n_chunks = df.estimated_size() / std::cmp::max(memory_limit / 4, 1);
+        let n_chunks = n_chunks.clamp(1, std::cmp::max(df.height(), 1));
+        for chunk in split_df(&df, n_chunks)? {
+            sorter.push(chunk)?;
+        }
+        Ok(())
+    };
+    if by_column
+        .iter()
+        .all(|e| matches!(e.as_expression(), Expr::Column(_)))
+    {
+        input.execute_batched(state, &mut push)?;
+    } else {
+        let df = input.execute(state)?;
+        push(df)?;
+    }
+
+    let out = sorter.finish()?;
+    out.select_by_range(..n_columns)
+}
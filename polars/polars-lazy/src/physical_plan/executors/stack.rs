@@ -12,6 +12,7 @@ pub struct StackExec {
 
 impl Executor for StackExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let mut df = self.input.execute(state)?;
 
         let res = if self.has_windows {
@@ -9,6 +9,7 @@ pub(crate) struct UdfExec {
 
 impl Executor for UdfExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let df = self.input.execute(state)?;
         self.function.call_udf(df)
     }
@@ -12,6 +12,7 @@ pub(crate) struct UnionExec {
 
 impl Executor for UnionExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.should_stop()?;
         let inputs = std::mem::take(&mut self.inputs);
 
         let dfs = if self.options.slice && self.options.slice_offset >= 0 {
@@ -48,7 +49,11 @@ impl Executor for UnionExec {
             POOL.install(|| {
                 inputs
                     .into_par_iter()
-                    .map(|mut input| input.execute(state))
+                    .map(|mut input| {
+                        // the workers don't see the settings of the calling thread
+                        let config = state.config.clone();
+                        config.scoped_arc(|_| input.execute(state))
+                    })
                     .collect::<Result<Vec<_>>>()
             })?
         };
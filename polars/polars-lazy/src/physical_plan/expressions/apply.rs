@@ -58,7 +58,7 @@ impl PhysicalExpr for ApplyExpr {
             .map(|e| e.evaluate(df, state))
             .collect::<Result<Vec<_>>>()?;
         let in_name = inputs[0].name().to_string();
-        let mut out = self.function.call_udf(&mut inputs)?;
+        let mut out = self.function.call_udf_with_state(&mut inputs, state)?;
         if in_name != out.name() {
             out.rename(&in_name);
         }
@@ -93,17 +93,20 @@ impl PhysicalExpr for ApplyExpr {
                             opt_s.and_then(|s| {
                                 let in_len = s.len();
                                 container[0] = s;
-                                self.function.call_udf(&mut container).ok().map(|s| {
-                                    let len = s.len();
-                                    if len != in_len {
-                                        update_group_tuples = true;
-                                    };
-                                    if len != 1 {
-                                        all_unit_len = false;
-                                    }
-
-                                    s
-                                })
+                                self.function
+                                    .call_udf_with_state(&mut container, state)
+                                    .ok()
+                                    .map(|s| {
+                                        let len = s.len();
+                                        if len != in_len {
+                                            update_group_tuples = true;
+                                        };
+                                        if len != 1 {
+                                            all_unit_len = false;
+                                        }
+
+                                        s
+                                    })
                             })
                         })
                         .collect();
@@ -115,7 +118,7 @@ impl PhysicalExpr for ApplyExpr {
                 ApplyOptions::ApplyFlat => {
                     let s = self
                         .function
-                        .call_udf(&mut [ac.flat_naive().into_owned()])?;
+                        .call_udf_with_state(&mut [ac.flat_naive().into_owned()], state)?;
                     if ac.is_aggregated() {
                         ac.with_update_groups(UpdateGroups::WithGroupsLen);
                     }
@@ -123,7 +126,9 @@ impl PhysicalExpr for ApplyExpr {
                     Ok(ac)
                 }
                 ApplyOptions::ApplyList => {
-                    let s = self.function.call_udf(&mut [ac.aggregated()])?;
+                    let s = self
+                        .function
+                        .call_udf_with_state(&mut [ac.aggregated()], state)?;
                     ac.with_series(s, true);
                     Ok(ac)
                 }
@@ -160,12 +165,15 @@ impl PhysicalExpr for ApplyExpr {
                                     Some(s) => container.push(s),
                                 }
                             }
-                            self.function.call_udf(&mut container).ok().map(|s| {
-                                if s.len() != 1 {
-                                    all_unit_len = false;
-                                }
-                                s
-                            })
+                            self.function
+                                .call_udf_with_state(&mut container, state)
+                                .ok()
+                                .map(|s| {
+                                    if s.len() != 1 {
+                                        all_unit_len = false;
+                                    }
+                                    s
+                                })
                         })
                         .collect_trusted();
                     ca.rename(&name);
@@ -179,7 +187,7 @@ impl PhysicalExpr for ApplyExpr {
                         .map(|ac| ac.flat_naive().into_owned())
                         .collect::<Vec<_>>();
 
-                    let s = self.function.call_udf(&mut s)?;
+                    let s = self.function.call_udf_with_state(&mut s, state)?;
                     let mut ac = acs.pop().unwrap();
                     ac.with_update_groups(UpdateGroups::WithGroupsLen);
                     ac.with_series(s, false);
@@ -187,7 +195,7 @@ impl PhysicalExpr for ApplyExpr {
                 }
                 ApplyOptions::ApplyList => {
                     let mut s = acs.iter_mut().map(|ac| ac.aggregated()).collect::<Vec<_>>();
-                    let s = self.function.call_udf(&mut s)?;
+                    let s = self.function.call_udf_with_state(&mut s, state)?;
                     let mut ac = acs.pop().unwrap();
                     ac.with_update_groups(UpdateGroups::WithGroupsLen);
                     ac.with_series(s, true);
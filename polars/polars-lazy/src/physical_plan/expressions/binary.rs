@@ -290,6 +290,7 @@ impl PhysicalAggregation for BinaryExpr {
 #[cfg(feature = "parquet")]
 mod stats {
     use super::*;
+    use polars_core::config::Config;
     use polars_io::parquet::predicates::BatchStats;
     use polars_io::predicates::StatsEvaluator;
 
@@ -395,7 +396,7 @@ mod stats {
 
     impl StatsEvaluator for BinaryExpr {
         fn should_read(&self, stats: &BatchStats) -> Result<bool> {
-            if std::env::var("POLARS_NO_PARQUET_STATISTICS").is_ok() {
+            if Config::current().no_parquet_statistics {
                 return Ok(true);
             }
 
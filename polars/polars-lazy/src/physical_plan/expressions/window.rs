@@ -365,7 +365,7 @@ impl PhysicalExpr for WindowExpr {
 
                 // groups are not changed, we can map by doing a standard argsort.
                 if std::ptr::eq(ac.groups.as_ref(), gb.get_groups()) {
-                    let mut iter = 0..flattened.len() as u32;
+                    let mut iter = 0..flattened.len() as IdxSize;
                     match ac.groups().as_ref() {
                         GroupsProxy::Idx(groups) => {
                             for g in groups.all() {
@@ -398,8 +398,8 @@ impl PhysicalExpr for WindowExpr {
                 cache_gb(gb);
                 // Safety:
                 // we only have unique indices ranging from 0..len
-                let idx = unsafe { perfect_sort(&POOL, &idx_mapping) };
-                let idx = UInt32Chunked::from_vec("", idx);
+                let idx = POOL.install(|| unsafe { perfect_sort(&idx_mapping) });
+                let idx = IdxCa::from_vec("", idx);
 
                 // Safety:
                 // groups should always be in bounds.
@@ -414,7 +414,10 @@ impl PhysicalExpr for WindowExpr {
                     if groupby_columns.len() == 1 {
                         // group key from right column
                         let right = &keys[0];
-                        groupby_columns[0].hash_join_left(right)
+                        // without validation the join can't fail
+                        groupby_columns[0]
+                            .hash_join_left(right, JoinValidation::ManyToMany)
+                            .unwrap()
                     } else {
                         let df_right = DataFrame::new_no_checks(keys);
                         let df_left = DataFrame::new_no_checks(groupby_columns);
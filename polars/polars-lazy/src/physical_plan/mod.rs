@@ -30,4 +30,17 @@ pub trait PhysicalPlanner {
 /// physical plan until the last executor is evaluated.
 pub trait Executor: Send + Sync {
     fn execute(&mut self, cache: &ExecutionState) -> Result<DataFrame>;
+
+    /// Execute and pass the output to `on_batch` in batches, so that a consumer that works
+    /// through its input incrementally doesn't need all of it in memory. By default the output
+    /// is produced at once and passed as a single batch; the IPC and parquet scans pass their
+    /// record batches and row groups as they are read.
+    fn execute_batched(
+        &mut self,
+        state: &ExecutionState,
+        on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+    ) -> Result<()> {
+        let df = self.execute(state)?;
+        on_batch(df)
+    }
 }
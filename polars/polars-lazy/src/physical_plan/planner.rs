@@ -107,18 +107,9 @@ impl DefaultPlanner {
                     .collect::<Result<Vec<_>>>()?;
                 Ok(Box::new(UnionExec { inputs, options }))
             }
-            Melt {
-                input,
-                id_vars,
-                value_vars,
-                ..
-            } => {
+            Melt { input, args, .. } => {
                 let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
-                Ok(Box::new(MeltExec {
-                    input,
-                    id_vars,
-                    value_vars,
-                }))
+                Ok(Box::new(MeltExec { input, args }))
             }
             Slice { input, offset, len } => {
                 let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
@@ -456,6 +447,9 @@ impl DefaultPlanner {
                     options.suffix,
                     options.asof_by_left,
                     options.asof_by_right,
+                    options.operators,
+                    options.validate,
+                    options.join_nulls,
                 )))
             }
             HStack { input, exprs, .. } => {
@@ -854,7 +848,7 @@ impl DefaultPlanner {
                                 let function = NoEq::new(Arc::new(move |s: &mut [Series]| {
                                     let s = std::mem::take(&mut s[0]);
                                     s.n_unique().map(|count| {
-                                        UInt32Chunked::from_slice(s.name(), &[count as u32])
+                                        IdxCa::from_slice(s.name(), &[count as IdxSize])
                                             .into_series()
                                     })
                                 })
@@ -916,7 +910,7 @@ impl DefaultPlanner {
                                 let function = NoEq::new(Arc::new(move |s: &mut [Series]| {
                                     let s = std::mem::take(&mut s[0]);
                                     let count = s.len();
-                                    Ok(UInt32Chunked::from_slice(s.name(), &[count as u32])
+                                    Ok(IdxCa::from_slice(s.name(), &[count as IdxSize])
                                         .into_series())
                                 })
                                     as Arc<dyn SeriesUdf>);
@@ -1,10 +1,14 @@
 use ahash::RandomState;
+use polars_core::config::Config;
 use polars_core::frame::groupby::GroupsProxy;
+#[cfg(feature = "spill")]
+use polars_core::frame::spill::SpillOptions;
 use polars_core::prelude::*;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
-pub type JoinTuplesCache = Arc<Mutex<HashMap<String, Vec<(u32, Option<u32>)>, RandomState>>>;
+pub type JoinTuplesCache =
+    Arc<Mutex<HashMap<String, Vec<(IdxSize, Option<IdxSize>)>, RandomState>>>;
 pub type GroupsProxyCache = Arc<Mutex<HashMap<String, GroupsProxy, RandomState>>>;
 
 /// State/ cache that is maintained during the Execution of the physical plan.
@@ -17,19 +21,60 @@ pub struct ExecutionState {
     pub(crate) join_tuples: JoinTuplesCache,
     pub(crate) verbose: bool,
     pub(crate) cache_window: bool,
+    /// Number of bytes operations may hold in memory before they spill to disk.
+    pub(crate) memory_limit: Option<usize>,
+    pub(crate) cancel_token: CancellationToken,
+    /// Settings of the engine, taken from the thread that creates the state.
+    pub(crate) config: Arc<Config>,
 }
 
 impl ExecutionState {
     pub fn new() -> Self {
+        let config = Config::current();
         Self {
             df_cache: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
             group_tuples: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
             join_tuples: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
-            verbose: std::env::var("POLARS_VERBOSE").is_ok(),
+            verbose: config.verbose,
             cache_window: true,
+            memory_limit: None,
+            cancel_token: CancellationToken::default(),
+            config,
         }
     }
 
+    /// A state for expressions that are evaluated on other data than the query, e.g. the items
+    /// of sublists. It shares the settings and the cancellation token of the query, but not
+    /// the caches, as those are keyed by the expressions and not by the data.
+    pub(crate) fn split(&self) -> Self {
+        Self {
+            df_cache: Default::default(),
+            group_tuples: Default::default(),
+            join_tuples: Default::default(),
+            verbose: self.verbose,
+            cache_window: self.cache_window,
+            memory_limit: self.memory_limit,
+            cancel_token: self.cancel_token.clone(),
+            config: self.config.clone(),
+        }
+    }
+
+    /// Options of the operations that spill to disk. The files are written to the temporary
+    /// directory of the settings of the query, also when the operation runs on another thread.
+    #[cfg(feature = "spill")]
+    pub(crate) fn spill_options(&self, memory_limit: usize) -> SpillOptions {
+        SpillOptions {
+            spill_dir: Some(self.config.temp_dir.clone()),
+            ..SpillOptions::new(memory_limit)
+        }
+    }
+
+    /// Returns an error if the query is cancelled.
+    /// Executors call this between steps and in long running loops.
+    pub(crate) fn should_stop(&self) -> Result<()> {
+        self.cancel_token.check()
+    }
+
     /// Check if we have DataFrame in cache
     pub fn cache_hit(&self, key: &str) -> Option<DataFrame> {
         let guard = self.df_cache.lock().unwrap();
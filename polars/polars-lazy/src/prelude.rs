@@ -1,4 +1,4 @@
-pub(crate) use polars_utils::arena::{Arena, Node};
+pub use polars_utils::arena::{Arena, Node};
 
 #[cfg(feature = "temporal")]
 pub(crate) use polars_time::in_nanoseconds_window;
@@ -21,6 +21,9 @@ pub use crate::{
     frame::*,
     functions::*,
     logical_plan::{
+        aexpr::{AAggExpr, AExpr},
+        alp::ALogicalPlan,
+        conversion::{node_to_expr, to_aexpr},
         optimizer::{type_coercion::TypeCoercionRule, Optimize, *},
         options::*,
         *,
@@ -28,6 +31,8 @@ pub use crate::{
     physical_plan::{expressions::*, planner::DefaultPlanner, Executor, PhysicalPlanner},
 };
 
+pub use polars_core::cancel::CancellationToken;
+
 #[cfg(feature = "csv-file")]
 pub(crate) use crate::physical_plan::executors::scan::CsvExec;
 #[cfg(feature = "parquet")]
@@ -514,3 +514,38 @@ fn test_take_in_groups() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "spill")]
+fn test_groupby_memory_limit() -> Result<()> {
+    let df = df![
+        "g" => (0..10_000).map(|i| i % 13).collect::<Vec<i32>>(),
+        "v" => (0..10_000).collect::<Vec<i32>>(),
+    ]?;
+    let aggs = [
+        col("v").sum().alias("sum"),
+        col("v").min().alias("min"),
+        col("v").max().alias("max"),
+        col("v").mean().alias("mean"),
+        col("v").count().alias("count"),
+        col("v").n_unique().alias("n_unique"),
+    ];
+
+    let expected = df
+        .clone()
+        .lazy()
+        .groupby([col("g")])
+        .agg(aggs.clone())
+        .sort("g", false)
+        .collect()?;
+    let out = df
+        .lazy()
+        .with_memory_limit(1024)
+        .groupby([col("g")])
+        .agg(aggs)
+        .sort("g", false)
+        .collect()?;
+
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
@@ -404,3 +404,96 @@ fn scan_predicate_on_set_null_values() -> Result<()> {
     assert_eq!(df.shape(), (12, 2));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "spill")]
+fn test_groupby_memory_limit_scan() -> Result<()> {
+    let mut df = df![
+        "g" => (0..1000).map(|i| i % 13).collect::<Vec<i32>>(),
+        "v" => (0..1000).collect::<Vec<i32>>(),
+    ]?;
+    let chunk = df.clone();
+    for _ in 0..9 {
+        df.vstack_mut(&chunk)?;
+    }
+    // every chunk is written as a row group, which the scan passes as a batch
+    let path = std::env::temp_dir().join("polars_test_groupby_memory_limit_scan.parquet");
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&df)?;
+
+    let scan =
+        || LazyFrame::scan_parquet(path.to_str().unwrap().to_string(), Default::default()).unwrap();
+    let aggs = [col("v").sum().alias("sum"), col("v").count().alias("count")];
+    let expected = scan()
+        .groupby([col("g")])
+        .agg(aggs.clone())
+        .sort("g", false)
+        .collect()?;
+    let out = scan()
+        .with_memory_limit(1024)
+        .groupby([col("g")])
+        .agg(aggs)
+        .sort("g", false)
+        .collect()?;
+    std::fs::remove_file(&path)?;
+
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "spill")]
+fn test_sort_memory_limit_scan() -> Result<()> {
+    let mut df = df![
+        "a" => (0..1000).map(|i| (i * 7) % 31).collect::<Vec<i32>>(),
+        "b" => (0..1000).collect::<Vec<i32>>(),
+    ]?;
+    let chunk = df.clone();
+    for _ in 0..9 {
+        df.vstack_mut(&chunk)?;
+    }
+    let path = std::env::temp_dir().join("polars_test_sort_memory_limit_scan.parquet");
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&df)?;
+
+    let scan =
+        || LazyFrame::scan_parquet(path.to_str().unwrap().to_string(), Default::default()).unwrap();
+    let expected = scan()
+        .sort_by_exprs(vec![col("a"), col("b")], vec![true, false])
+        .collect()?;
+    let out = scan()
+        .with_memory_limit(1024)
+        .sort_by_exprs(vec![col("a"), col("b")], vec![true, false])
+        .collect()?;
+    std::fs::remove_file(&path)?;
+
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_iter_batches_scan() -> Result<()> {
+    let mut df = df![
+        "a" => (0..1000).collect::<Vec<i32>>(),
+    ]?;
+    let chunk = df.clone();
+    for _ in 0..9 {
+        df.vstack_mut(&chunk)?;
+    }
+    let path = std::env::temp_dir().join("polars_test_iter_batches_scan.parquet");
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&df)?;
+
+    let scan =
+        || LazyFrame::scan_parquet(path.to_str().unwrap().to_string(), Default::default()).unwrap();
+    // the row groups are sliced as they are read
+    let heights = scan()
+        .iter_batches(600)
+        .map(|df| df.map(|df| df.height()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(heights, [600, 400].repeat(10));
+
+    // dropping the iterator cancels the rest of the scan
+    let mut batches = scan().iter_batches(600);
+    assert_eq!(batches.next().unwrap()?.height(), 600);
+    drop(batches);
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
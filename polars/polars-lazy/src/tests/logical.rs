@@ -49,3 +49,118 @@ fn test_duration() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "dtype-time"))]
+fn test_dt_namespace() -> Result<()> {
+    let datetimes = [
+        NaiveDate::from_ymd(2021, 1, 31).and_hms(10, 40, 0),
+        NaiveDate::from_ymd(2021, 2, 15).and_hms(23, 59, 0),
+    ];
+    let dates = [
+        NaiveDate::from_ymd(2021, 1, 1),
+        NaiveDate::from_ymd(2021, 12, 31),
+    ];
+    let df = DataFrame::new(vec![
+        DatetimeChunked::from_naive_datetime("datetime", datetimes, TimeUnit::Milliseconds)
+            .into_series(),
+        DateChunked::from_naive_date("date", dates).into_series(),
+        Int64Chunked::new("time", &[3_600_000_000_000i64, 0])
+            .into_time()
+            .into_series(),
+    ])?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("datetime")
+                .dt()
+                .truncate("1mo", "0ns")
+                .alias("truncate"),
+            col("datetime").dt().round("1d").alias("round"),
+            col("datetime").dt().offset_by("1mo").alias("offset_by"),
+            col("datetime")
+                .dt()
+                .cast_time_unit(TimeUnit::Nanoseconds)
+                .alias("ns"),
+            col("date").dt().quarter().alias("quarter"),
+            col("date").dt().iso_year().alias("iso_year"),
+            col("date")
+                .dt()
+                .epoch(TimeUnit::Milliseconds)
+                .alias("epoch"),
+            col("date")
+                .dt()
+                .combine(col("time"), TimeUnit::Microseconds)
+                .alias("combine"),
+        ])
+        .collect()?;
+
+    let get = |name: &str| -> Result<Vec<Option<NaiveDateTime>>> {
+        Ok(out.column(name)?.datetime()?.as_datetime_iter().collect())
+    };
+    let dt = |y, m, d, h, min| Some(NaiveDate::from_ymd(y, m, d).and_hms(h, min, 0));
+
+    assert_eq!(
+        get("truncate")?,
+        &[dt(2021, 1, 1, 0, 0), dt(2021, 2, 1, 0, 0)]
+    );
+    assert_eq!(
+        get("round")?,
+        &[dt(2021, 1, 31, 0, 0), dt(2021, 2, 16, 0, 0)]
+    );
+    assert_eq!(
+        get("offset_by")?,
+        &[dt(2021, 2, 28, 10, 40), dt(2021, 3, 15, 23, 59)]
+    );
+    assert_eq!(
+        out.column("ns")?.dtype(),
+        &DataType::Datetime(TimeUnit::Nanoseconds, None)
+    );
+    assert_eq!(
+        get("ns")?,
+        datetimes.iter().copied().map(Some).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Vec::from(out.column("quarter")?.u32()?),
+        &[Some(1), Some(4)]
+    );
+    assert_eq!(
+        Vec::from(out.column("iso_year")?.i32()?),
+        &[Some(2020), Some(2021)]
+    );
+    assert_eq!(
+        Vec::from(out.column("epoch")?.i64()?),
+        &[Some(1_609_459_200_000), Some(1_640_908_800_000)]
+    );
+    assert_eq!(
+        get("combine")?,
+        &[dt(2021, 1, 1, 1, 0), dt(2021, 12, 31, 0, 0)]
+    );
+
+    // an invalid duration is an error when the query runs
+    let out = df
+        .lazy()
+        .select([col("datetime").dt().truncate("1x", "0ns")])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::ValueError(_))));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "temporal")]
+fn test_year_dtype() -> Result<()> {
+    let df = DataFrame::new(vec![DateChunked::from_naive_date(
+        "date",
+        [NaiveDate::from_ymd(2021, 1, 1)],
+    )
+    .into_series()])?;
+
+    let lf = df.lazy().select([col("date").year()]);
+    // the schema must match the `Int32` that is returned by `Series::year`
+    assert_eq!(lf.schema().field(0).unwrap().data_type(), &DataType::Int32);
+    let out = lf.collect()?;
+    assert_eq!(out.column("date")?.dtype(), &DataType::Int32);
+    Ok(())
+}
@@ -24,7 +24,7 @@ use polars_io::prelude::*;
 use std::io::Cursor;
 
 use crate::functions::{argsort_by, pearson_corr};
-use crate::logical_plan::iterator::ArenaLpIter;
+use crate::logical_plan::iterator::{ArenaExprIter, ArenaLpIter};
 use crate::logical_plan::optimizer::simplify_expr::SimplifyExprRule;
 use crate::logical_plan::optimizer::stack_opt::{OptimizationRule, StackOptimizer};
 use crate::prelude::*;
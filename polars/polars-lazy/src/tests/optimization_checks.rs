@@ -293,3 +293,123 @@ fn test_with_row_count_opts() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_user_optimization_rule() -> Result<()> {
+    // rewrites `col(x) * 2` into `col(x) + col(x)`
+    #[derive(Clone)]
+    struct MulTwoToAdd;
+
+    impl OptimizationRule for MulTwoToAdd {
+        fn optimize_expr(
+            &self,
+            expr_arena: &mut Arena<AExpr>,
+            expr_node: Node,
+            _lp_arena: &Arena<ALogicalPlan>,
+            _lp_node: Node,
+        ) -> Option<AExpr> {
+            match expr_arena.get(expr_node) {
+                AExpr::BinaryExpr {
+                    left,
+                    op: Operator::Multiply,
+                    right,
+                } => match expr_arena.get(*right) {
+                    AExpr::Literal(LiteralValue::Int32(2)) => Some(AExpr::BinaryExpr {
+                        left: *left,
+                        op: Operator::Plus,
+                        right: *left,
+                    }),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+    }
+
+    let q = fruits_cars()
+        .lazy()
+        .with_optimization_rule(MulTwoToAdd)
+        .select([col("A") * lit(2i32)]);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    assert!((&lp_arena).iter(lp).all(|(_, lp)| {
+        lp.get_exprs().into_iter().all(|node| {
+            (&expr_arena).iter(node).all(|(_, e)| {
+                !matches!(
+                    e,
+                    AExpr::BinaryExpr {
+                        op: Operator::Multiply,
+                        ..
+                    }
+                )
+            })
+        })
+    }));
+
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("A")?.i32()?),
+        &[Some(2), Some(4), Some(6), Some(8), Some(10)]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_join_reorder() -> Result<()> {
+    let fact = df![
+        "id_a" => (0..100).map(|i| i % 10).collect::<Vec<i32>>(),
+        "id_b" => (0..100).map(|i| i % 4).collect::<Vec<i32>>(),
+        "value" => (0..100).collect::<Vec<i32>>(),
+    ]?;
+    let dim_a = df![
+        "id_a" => (0..10).collect::<Vec<i32>>(),
+        "name_a" => (0..10).map(|i| format!("a{}", i)).collect::<Vec<_>>(),
+    ]?;
+    let dim_b = df![
+        "id_b" => [0, 1, 2, 3],
+        "name_b" => ["b0", "b1", "b2", "b3"],
+    ]?;
+
+    let q = fact
+        .lazy()
+        .inner_join(dim_a.lazy(), col("id_a"), col("id_a"))
+        .inner_join(
+            dim_b.lazy().filter(col("name_b").eq(lit("b1"))),
+            col("id_b"),
+            col("id_b"),
+        );
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q
+        .clone()
+        .with_join_reorder(true)
+        .optimize(&mut lp_arena, &mut expr_arena)?;
+
+    // the filtered dimension is joined first
+    let bottom_join = (&lp_arena)
+        .iter(lp)
+        .filter_map(|(_, lp)| match lp {
+            ALogicalPlan::Join {
+                input_left,
+                input_right,
+                ..
+            } if !matches!(lp_arena.get(*input_left), ALogicalPlan::Join { .. }) => {
+                Some((*input_left, *input_right))
+            }
+            _ => None,
+        })
+        .next()
+        .unwrap();
+    let schema_left = lp_arena.get(bottom_join.0).schema(&lp_arena);
+    let schema_right = lp_arena.get(bottom_join.1).schema(&lp_arena);
+    assert!(schema_left.index_of("name_b").is_ok() || schema_right.index_of("name_b").is_ok());
+
+    let expected = q.clone().collect()?.sort("value", false)?;
+    let out = q.with_join_reorder(true).collect()?.sort("value", false)?;
+    assert_eq!(out.get_column_names(), expected.get_column_names());
+    assert!(out.frame_equal(&expected));
+
+    Ok(())
+}
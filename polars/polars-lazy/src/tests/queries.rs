@@ -70,6 +70,96 @@ fn test_lazy_melt() {
     assert_eq!(out.shape(), (7, 3));
 }
 
+#[test]
+fn test_lazy_melt2() -> Result<()> {
+    let df = df![
+        "id" => [1, 2],
+        "a" => [10, 20],
+        "b" => [30, 40]
+    ]?;
+    let out = df
+        .lazy()
+        .melt2(MeltArgs {
+            id_vars: vec!["id".into()],
+            value_vars: vec!["a".into(), "b".into()],
+            variable_name: Some("col".into()),
+            value_name: Some("val".into()),
+        })
+        .filter(col("col").eq(lit("b")))
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["id", "col", "val"]);
+    assert_eq!(Vec::from(out.column("val")?.i32()?), &[Some(30), Some(40)]);
+
+    // missing columns are an error when the query runs
+    let out = df
+        .clone()
+        .lazy()
+        .melt(vec!["id".into()], vec!["a".into(), "c".into()])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::NotFound(_))));
+    let out = df
+        .lazy()
+        .melt(vec!["foo".into()], vec!["a".into()])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::NotFound(_))));
+    Ok(())
+}
+
+#[test]
+fn test_lazy_pivot() -> Result<()> {
+    let df = df![
+        "date" => [1, 1, 1, 2, 2],
+        "city" => ["ams", "ams", "utr", "ams", "utr"],
+        "temp" => [10, 12, 8, 15, 11],
+        "rain" => [1, 0, 2, 3, 4]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .pivot(["date"], "city", ["temp"], col("*").max())
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["date", "ams", "utr"]);
+    assert_eq!(Vec::from(out.column("ams")?.i32()?), &[Some(12), Some(15)]);
+    assert_eq!(Vec::from(out.column("utr")?.i32()?), &[Some(8), Some(11)]);
+
+    let out = df
+        .lazy()
+        .pivot_values(["date"], "city", ["temp", "rain"], col("*").sum(), ["utr"])
+        .select([col("temp_city_utr"), col("rain_city_utr")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("rain_city_utr")?.i32()?),
+        &[Some(2), Some(4)]
+    );
+
+    // a null pivot value gets its own column and names that clash with the index are prefixed
+    let df = df![
+        "date" => [1, 1, 2],
+        "city" => [Some("date"), None, Some("date")],
+        "temp" => [10, 12, 8]
+    ]?;
+    let out = df
+        .lazy()
+        .pivot(["date"], "city", ["temp"], col("*").first())
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["date", "city_date", "null"]);
+    assert_eq!(
+        Vec::from(out.column("city_date")?.i32()?),
+        &[Some(10), Some(8)]
+    );
+    assert_eq!(Vec::from(out.column("null")?.i32()?), &[Some(12), None]);
+
+    let out = df![
+        "date" => [1]
+    ]?
+    .lazy()
+    .pivot(["date"], "city", ["temp"], col("*").first())
+    .collect();
+    assert!(matches!(out, Err(PolarsError::NotFound(_))));
+    Ok(())
+}
+
 #[test]
 fn test_lazy_drop_nulls() {
     let df = df! {
@@ -1959,3 +2049,646 @@ fn test_is_in() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "spill")]
+fn test_sort_memory_limit() -> Result<()> {
+    let df = df![
+        "a" => (0..5000).map(|i| if i % 7 == 0 { None } else { Some(i % 23) }).collect::<Vec<_>>(),
+        "b" => (0..5000).map(|i| (i * 13) % 31).collect::<Vec<i32>>(),
+        "c" => (0..5000).collect::<Vec<i32>>(),
+    ]?;
+
+    let expected = df
+        .clone()
+        .lazy()
+        .sort_by_exprs(
+            vec![col("a"), col("b") * lit(2), col("c")],
+            vec![true, false, false],
+        )
+        .collect()?;
+    let out = df
+        .lazy()
+        .with_memory_limit(1024)
+        .sort_by_exprs(
+            vec![col("a"), col("b") * lit(2), col("c")],
+            vec![true, false, false],
+        )
+        .collect()?;
+
+    assert!(out.frame_equal_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_collect_cancelled() -> Result<()> {
+    let df = fruits_cars();
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let out = df
+        .clone()
+        .lazy()
+        .groupby([col("fruits")])
+        .agg([col("A").sum()])
+        .collect_cancellable(token);
+    assert!(matches!(out, Err(PolarsError::Cancelled)));
+
+    let out = df
+        .lazy()
+        .groupby([col("fruits")])
+        .agg([col("A").sum()])
+        .collect_with_timeout(std::time::Duration::from_secs(60))?;
+    assert_eq!(out.height(), 2);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "range_join")]
+fn test_join_where() -> Result<()> {
+    let events = df![
+        "user" => ["a", "a", "b", "b", "c"],
+        "ts" => [1, 12, 5, 25, 3]
+    ]?;
+    let sessions = df![
+        "user_id" => ["a", "a", "b", "c"],
+        "start" => [0, 10, 0, 10],
+        "end" => [10, 20, 10, 20],
+        "session" => [1, 2, 3, 4]
+    ]?;
+
+    let out = events
+        .clone()
+        .lazy()
+        .join_where(
+            sessions.clone().lazy(),
+            [
+                col("user").eq(col("user_id")),
+                col("ts").gt_eq(col("start")).and(col("ts").lt(col("end"))),
+            ],
+        )
+        .select([col("ts"), col("start"), col("session")])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("session")?.i32()?),
+        &[Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("start")?.i32()?),
+        &[Some(0), Some(10), Some(0)]
+    );
+
+    // an unsupported predicate is returned as an error by `collect`
+    let out = events
+        .lazy()
+        .join_where(sessions.lazy(), [col("ts").neq(col("start"))])
+        .select([col("ts"), col("session")])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+    Ok(())
+}
+
+#[test]
+fn test_join_validate_join_nulls() -> Result<()> {
+    let left = df![
+        "a" => [Some(1), Some(2), None],
+        "b" => [1, 2, 3]
+    ]?;
+    let right = df![
+        "a" => [Some(1), Some(1), None],
+        "c" => [1, 2, 3]
+    ]?;
+    let join = |validate, join_nulls| {
+        left.clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            .left_on([col("a")])
+            .right_on([col("a")])
+            .how(JoinType::Inner)
+            .validate(validate)
+            .join_nulls(join_nulls)
+            .finish()
+            .collect()
+    };
+
+    assert_eq!(join(JoinValidation::ManyToMany, true)?.height(), 3);
+    assert_eq!(join(JoinValidation::ManyToMany, false)?.height(), 2);
+    assert!(join(JoinValidation::OneToMany, false).is_ok());
+    assert!(join(JoinValidation::ManyToOne, false).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_scoped_config() -> Result<()> {
+    use crate::physical_plan::state::ExecutionState;
+    use polars_core::config::Config;
+
+    let cfg = Config {
+        no_partition: true,
+        verbose: true,
+        ..Config::from_env()
+    };
+    cfg.scoped(|_| {
+        let state = ExecutionState::new();
+        assert!(state.config.no_partition);
+        assert!(state.verbose);
+    });
+    assert_eq!(
+        ExecutionState::new().config.no_partition,
+        Config::from_env().no_partition
+    );
+
+    let df = df![
+        "a" => [1, 1, 2],
+        "b" => [1, 2, 3]
+    ]?;
+    let out = cfg.scoped(|_| {
+        df.lazy()
+            .groupby([col("a")])
+            .agg([col("b").sum()])
+            .sort("a", false)
+            .collect()
+    })?;
+    assert_eq!(Vec::from(out.column("b")?.i32()?), &[Some(3), Some(3)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "strings", feature = "list"))]
+fn test_str_namespace() -> Result<()> {
+    let df = df![
+        "code" => [" 7-a ", "12-b-c", "x"]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("code").str().strip(None).alias("stripped"),
+            col("code")
+                .str()
+                .strip(None)
+                .str()
+                .split_exact("-", 1)
+                .arr()
+                .get(0)
+                .str()
+                .zfill(3)
+                .alias("number"),
+            col("code").str().count_matches("[a-z]").alias("n_letters"),
+            col("code").str().ends_with("c").alias("ends_with"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("stripped")?.utf8()?),
+        &[Some("7-a"), Some("12-b-c"), Some("x")]
+    );
+    assert_eq!(
+        Vec::from(out.column("number")?.utf8()?),
+        &[Some("007"), Some("012"), Some("00x")]
+    );
+    assert_eq!(
+        Vec::from(out.column("n_letters")?.u32()?),
+        &[Some(1), Some(2), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("ends_with")?.bool()?),
+        &[Some(false), Some(true), Some(false)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "list")]
+fn test_arr_eval() -> Result<()> {
+    let df = df![
+        "g" => [1, 1, 2, 2, 2],
+        "v" => [1, -2, 3, 4, -5]
+    ]?;
+
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([col("v").list()])
+        .select([
+            col("v")
+                .arr()
+                .eval(col("").filter(col("").gt(lit(0))))
+                .alias("positive"),
+            col("v").arr().eval(col("").sum()).alias("sum"),
+            col("v")
+                .arr()
+                .eval(col("") * lit(2))
+                .arr()
+                .sum()
+                .alias("doubled_sum"),
+        ])
+        .collect()?;
+
+    let positive = out.column("positive")?.explode()?;
+    assert_eq!(Vec::from(positive.i32()?), &[Some(1), Some(3), Some(4)]);
+    assert_eq!(
+        out.column("positive")?
+            .list()?
+            .lst_lengths()
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[1, 2]
+    );
+    let sum = out.column("sum")?.explode()?;
+    assert_eq!(Vec::from(sum.i32()?), &[Some(-1), Some(2)]);
+    assert_eq!(
+        Vec::from(out.column("doubled_sum")?.i32()?),
+        &[Some(-2), Some(4)]
+    );
+
+    // null sublists stay null and an empty column gives an empty list
+    let mut ca: ListChunked = [Some(Series::new("", &[1i32, 2])), None]
+        .into_iter()
+        .collect();
+    ca.rename("v");
+    let df = DataFrame::new(vec![ca.into_series()])?;
+    let eval = || col("v").arr().eval(col("") * lit(2));
+    let out = df.clone().lazy().select([eval()]).collect()?;
+    let out = out.column("v")?.list()?;
+    assert_eq!(out.null_count(), 1);
+    assert_eq!(Vec::from(out.get(0).unwrap().i32()?), &[Some(2), Some(4)]);
+
+    let out = df.lazy().slice(0, 0).select([eval()]).collect()?;
+    assert_eq!(out.height(), 0);
+    assert_eq!(
+        out.column("v")?.dtype(),
+        &DataType::List(Box::new(DataType::Int32))
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "list")]
+fn test_arr_set_operations() -> Result<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2, 2],
+        "a" => ["x", "y", "y", "z", "x"],
+        "b" => ["y", "w", "w", "x", "x"]
+    ]?;
+
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([col("a").list(), col("b").list()])
+        .select([
+            col("a").arr().union(col("b")).alias("union"),
+            col("a").arr().intersection(col("b")).alias("intersection"),
+            col("a").arr().difference(col("b")).alias("difference"),
+            col("a").arr().contains(lit("x")).alias("contains"),
+            col("a").arr().count_match(lit("y")).alias("count"),
+        ])
+        .collect()?;
+
+    let to_vecs = |name: &str| -> Result<Vec<Vec<Option<String>>>> {
+        Ok(out
+            .column(name)?
+            .list()?
+            .into_iter()
+            .map(|opt_s| {
+                opt_s
+                    .unwrap()
+                    .utf8()
+                    .unwrap()
+                    .into_iter()
+                    .map(|opt_v| opt_v.map(|v| v.to_string()))
+                    .collect()
+            })
+            .collect())
+    };
+    let s = |v: &str| Some(v.to_string());
+    assert_eq!(
+        to_vecs("union")?,
+        &[vec![s("x"), s("y"), s("w")], vec![s("z"), s("x")]]
+    );
+    assert_eq!(to_vecs("intersection")?, &[vec![s("y")], vec![s("x")]]);
+    assert_eq!(to_vecs("difference")?, &[vec![s("x")], vec![s("z")]]);
+    assert_eq!(
+        Vec::from(out.column("contains")?.bool()?),
+        &[Some(true), Some(true)]
+    );
+    assert_eq!(Vec::from(out.column("count")?.u32()?), &[Some(2), Some(0)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "math")]
+fn test_math_functions() -> Result<()> {
+    let df = df![
+        "int" => [1, 4, 16],
+        "float" => [0.0f32, 1.0, -1.0]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("int").sqrt().alias("sqrt"),
+            col("int").log(2.0).alias("log"),
+            col("float").sign().alias("sign"),
+            (lit(2) - col("int")).sign().alias("int_sign"),
+            col("float").exp().alias("exp"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("sqrt")?.f64()?),
+        &[Some(1.0), Some(2.0), Some(4.0)]
+    );
+    let log = out.column("log")?.f64()?;
+    for (v, expected) in log.into_no_null_iter().zip([0.0, 2.0, 4.0]) {
+        assert!((v - expected).abs() < 1e-12);
+    }
+    assert_eq!(
+        Vec::from(out.column("sign")?.f32()?),
+        &[Some(0.0), Some(1.0), Some(-1.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("int_sign")?.i32()?),
+        &[Some(1), Some(-1), Some(-1)]
+    );
+    assert_eq!(out.column("exp")?.dtype(), &DataType::Float32);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "top_k")]
+fn test_value_counts_top_k() -> Result<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "b"],
+        "v" => [1, 2, 2, 5, 3, 4]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select(col("v").value_counts(true))
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["v", "counts"]);
+    assert_eq!(out.column("counts")?.dtype(), &IDX_DTYPE);
+    assert_eq!(out.column("v")?.i32()?.get(0), Some(2));
+    assert_eq!(out.column("counts")?.idx()?.get(0), Some(2));
+    assert_eq!(out.height(), 5);
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("v").unique_counts()])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.idx()?),
+        &[Some(1), Some(2), Some(1), Some(1), Some(1)]
+    );
+
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([col("v").top_k(2, false)])
+        .collect()?;
+    let top = out.column("v")?.explode()?;
+    assert_eq!(Vec::from(top.i32()?), &[Some(2), Some(2), Some(5), Some(4)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cut")]
+fn test_cut_qcut() -> Result<()> {
+    let df = df![
+        "v" => [1, 4, 6, 9]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("v")
+                .cut(
+                    vec![5.0],
+                    Some(vec!["low".to_string(), "high".to_string()]),
+                    false,
+                )
+                .alias("cut"),
+            col("v").qcut(vec![0.5]).alias("qcut"),
+        ])
+        .collect()?;
+
+    assert_eq!(out.column("cut")?.dtype(), &DataType::Categorical);
+    let cut = out.column("cut")?.cast(&DataType::Utf8)?;
+    assert_eq!(
+        Vec::from(cut.utf8()?),
+        &[Some("low"), Some("low"), Some("high"), Some("high")]
+    );
+    let qcut = out.column("qcut")?.cast(&DataType::Utf8)?;
+    assert_eq!(
+        Vec::from(qcut.utf8()?),
+        &[
+            Some("(-inf, 5]"),
+            Some("(-inf, 5]"),
+            Some("(5, inf]"),
+            Some("(5, inf]")
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "remap")]
+fn test_map_dict_replace() -> Result<()> {
+    let df = df![
+        "a" => [Some(1), Some(2), None, Some(3)]
+    ]?;
+
+    let mut mapping = std::collections::HashMap::new();
+    mapping.insert(AnyValue::Int32(1), AnyValue::Utf8("one"));
+    mapping.insert(AnyValue::Int32(2), AnyValue::Utf8("two"));
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").map_dict(mapping.clone(), None).alias("no_default"),
+            col("a")
+                .map_dict(mapping, Some(lit("other")))
+                .alias("default"),
+            col("a")
+                .replace(Series::new("", &[3]), Series::new("", &[30]))
+                .alias("replace"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("no_default")?.utf8()?),
+        &[Some("one"), Some("two"), None, None]
+    );
+    assert_eq!(
+        Vec::from(out.column("default")?.utf8()?),
+        &[Some("one"), Some("two"), Some("other"), Some("other")]
+    );
+    assert_eq!(
+        Vec::from(out.column("replace")?.i32()?),
+        &[Some(1), Some(2), None, Some(30)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_collect_with_pool() -> Result<()> {
+    use polars_core::POOL;
+
+    let df = df![
+        "a" => [1, 1, 2],
+        "b" => [1, 2, 3]
+    ]?;
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(2)
+        .build()
+        .unwrap();
+    let out = df
+        .lazy()
+        .groupby([col("a")])
+        .agg([col("b").sum()])
+        .select([
+            col("a"),
+            col("b").map(
+                |_| Ok(Series::new("b", &[POOL.current_num_threads() as u32; 2])),
+                GetOutput::from_type(DataType::UInt32),
+            ),
+        ])
+        .collect_with_pool(&pool)?;
+    assert_eq!(Vec::from(out.column("b")?.u32()?), &[Some(2), Some(2)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_string_cache_holder_outlives_query() -> Result<()> {
+    use polars_core::StringCacheHolder;
+
+    let _holder = StringCacheHolder::hold();
+    let s = Series::new("a", &["x", "y"]);
+    let before = s.cast(&DataType::Categorical)?;
+    // the query holds the cache as well and must not clear it when it finishes
+    let df = df!["a" => ["x", "y", "z"]]?;
+    df.lazy()
+        .select([col("a").cast(DataType::Categorical)])
+        .collect()?;
+    let after = s.cast(&DataType::Categorical)?;
+
+    let map = |s: &Series| {
+        s.categorical()
+            .unwrap()
+            .get_categorical_map()
+            .unwrap()
+            .clone()
+    };
+    assert!(map(&before).same_src(&map(&after)));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_enum_and_categorical_ordering() -> Result<()> {
+    let levels = DataType::Enum(Arc::from(vec![
+        "low".into(),
+        "medium".into(),
+        "high".into(),
+    ]));
+    let df = df!["level" => ["medium", "low", "high", "low"]]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("level").cast(levels.clone())])
+        .filter(col("level").gt(lit("low")))
+        .sort("level", false)
+        .collect()?;
+    let level = out.column("level")?;
+    assert_eq!(level.dtype(), &levels);
+    assert_eq!(level.str_value(0), "medium");
+    assert_eq!(level.str_value(1), "high");
+
+    // unknown values are an error
+    let s = Series::new("level", &["low", "extreme"]);
+    assert!(s.cast(&levels).is_err());
+
+    let out = df
+        .lazy()
+        .select([
+            col("level")
+                .cast(DataType::Categorical)
+                .cat()
+                .set_ordering(CategoricalOrdering::Lexical)
+                .max()
+                .alias("lexical"),
+            col("level")
+                .cast(DataType::Categorical)
+                .max()
+                .alias("physical"),
+        ])
+        .collect()?;
+    assert_eq!(out.column("lexical")?.str_value(0), "medium");
+    assert_eq!(out.column("physical")?.str_value(0), "high");
+    Ok(())
+}
+
+#[test]
+fn test_lazy_estimated_size() -> Result<()> {
+    let df = fruits_cars();
+    let size = df.estimated_size();
+    assert_eq!(df.clone().lazy().estimated_size(), Some(size));
+
+    let joined = df
+        .clone()
+        .lazy()
+        .join(df.lazy(), [col("A")], [col("A")], JoinType::Inner);
+    assert_eq!(joined.estimated_size(), Some(2 * size));
+
+    let size = std::fs::metadata(FOODS_CSV)?.len() as usize;
+    assert_eq!(scan_foods_csv().estimated_size(), Some(size));
+    Ok(())
+}
+
+#[test]
+fn test_iter_batches() -> Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let df = df![
+        "a" => [1, 2, 3, 4, 5, 6],
+        "b" => ["a", "b", "c", "d", "e", "f"]
+    ]?;
+    let q = df.lazy().filter(col("a").gt(lit(1)));
+
+    // the query runs once for all batches
+    let runs = Arc::new(AtomicUsize::new(0));
+    let runs2 = runs.clone();
+    let counted = q.clone().map(
+        move |df| {
+            runs2.fetch_add(1, Ordering::Relaxed);
+            Ok(df)
+        },
+        None,
+        None,
+        None,
+    );
+    assert_eq!(counted.iter_batches(2).count(), 3);
+    assert_eq!(runs.load(Ordering::Relaxed), 1);
+
+    let batches = q.clone().iter_batches(2).collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        batches.iter().map(|df| df.height()).collect::<Vec<_>>(),
+        &[2, 2, 1]
+    );
+    let mut out = batches[0].clone();
+    for batch in &batches[1..] {
+        out.vstack_mut(batch)?;
+    }
+    assert!(out.frame_equal(&q.clone().collect()?));
+
+    #[cfg(feature = "ffi")]
+    q.to_ffi_stream(2)?;
+    Ok(())
+}
@@ -258,6 +258,37 @@ pub(crate) fn agg_source_paths(
     })
 }
 
+/// Sum the estimated in-memory sizes of the data sources of this LogicalPlan. Returns `None` if
+/// the size of one of the sources is unknown.
+pub(crate) fn estimated_source_size(
+    root_lp: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+) -> Option<usize> {
+    let file_size = |path: &Path| std::fs::metadata(path).ok().map(|md| md.len() as usize);
+
+    lp_arena.iter(root_lp).try_fold(0, |acc, (_, lp)| {
+        use ALogicalPlan::*;
+        let size = match lp {
+            // the in-memory size of a csv file is in the order of the size of the file
+            #[cfg(feature = "csv-file")]
+            CsvScan { path, .. } => file_size(path)?,
+            #[cfg(feature = "ipc")]
+            IpcScan { path, .. } => file_size(path)?,
+            #[cfg(feature = "parquet")]
+            ParquetScan { path, .. } => {
+                use polars_io::prelude::ParquetReader;
+                use polars_io::SerReader;
+
+                let file = std::fs::File::open(path).ok()?;
+                ParquetReader::new(file).uncompressed_size().ok()?
+            }
+            DataFrameScan { df, .. } => df.estimated_size(),
+            _ => 0,
+        };
+        Some(acc + size)
+    })
+}
+
 pub(crate) fn try_path_to_str(path: &Path) -> Result<&str> {
     path.to_str().ok_or_else(|| {
         PolarsError::ComputeError(format!("Non-UTF8 file path: {}", path.to_string_lossy()).into())
@@ -357,7 +357,7 @@ impl Wrap<&DataFrame> {
     }
 }
 
-fn update_subgroups(sub_groups: &[[u32; 2]], base_g: (u32, &Vec<u32>)) -> GroupsIdx {
+fn update_subgroups(sub_groups: &[[IdxSize; 2]], base_g: (IdxSize, &Vec<IdxSize>)) -> GroupsIdx {
     sub_groups
         .iter()
         .map(|&[first, len]| {
@@ -408,8 +408,8 @@ mod test {
             let (_, groups) = df
                 .groupby_rolling(&RollingGroupOptions {
                     index_column: "dt".into(),
-                    period: Duration::parse("2d"),
-                    offset: Duration::parse("-2d"),
+                    period: Duration::parse("2d").unwrap(),
+                    offset: Duration::parse("-2d").unwrap(),
                     closed_window: ClosedWindow::Right,
                 })
                 .unwrap();
@@ -433,7 +433,7 @@ mod test {
             "date",
             start,
             stop,
-            Duration::parse("30m"),
+            Duration::parse("30m").unwrap(),
             ClosedWindow::Both,
             TimeUnit::Milliseconds,
         )
@@ -447,9 +447,9 @@ mod test {
                 vec![groups],
                 &DynamicGroupOptions {
                     index_column: "date".into(),
-                    every: Duration::parse("1h"),
-                    period: Duration::parse("1h"),
-                    offset: Duration::parse("0h"),
+                    every: Duration::parse("1h").unwrap(),
+                    period: Duration::parse("1h").unwrap(),
+                    offset: Duration::parse("0h").unwrap(),
                     truncate: true,
                     include_boundaries: true,
                     closed_window: ClosedWindow::Both,
@@ -479,7 +479,7 @@ mod test {
             "_upper_boundary",
             start,
             stop,
-            Duration::parse("1h"),
+            Duration::parse("1h").unwrap(),
             ClosedWindow::Both,
             TimeUnit::Milliseconds,
         )
@@ -497,24 +497,22 @@ mod test {
             "_lower_boundary",
             start,
             stop,
-            Duration::parse("1h"),
+            Duration::parse("1h").unwrap(),
             ClosedWindow::Both,
             TimeUnit::Milliseconds,
         )
         .into_series();
         assert_eq!(&upper, &range);
 
-        let expected = GroupsProxy::Idx(
-            vec![
-                (0u32, vec![0u32, 1, 2]),
-                (2u32, vec![2]),
-                (5u32, vec![5, 6]),
-                (6u32, vec![6]),
-                (3u32, vec![3, 4]),
-                (4u32, vec![4]),
-            ]
-            .into(),
-        );
+        let expected: Vec<(IdxSize, Vec<IdxSize>)> = vec![
+            (0, vec![0, 1, 2]),
+            (2, vec![2]),
+            (5, vec![5, 6]),
+            (6, vec![6]),
+            (3, vec![3, 4]),
+            (4, vec![4]),
+        ];
+        let expected = GroupsProxy::Idx(expected.into());
         assert_eq!(expected, groups);
     }
 
@@ -526,9 +524,9 @@ mod test {
             vec![],
             &DynamicGroupOptions {
                 index_column: "date".into(),
-                every: Duration::parse("1h"),
-                period: Duration::parse("1i"),
-                offset: Duration::parse("0h"),
+                every: Duration::parse("1h").unwrap(),
+                period: Duration::parse("1i").unwrap(),
+                offset: Duration::parse("0h").unwrap(),
                 truncate: true,
                 include_boundaries: true,
                 closed_window: ClosedWindow::Both,
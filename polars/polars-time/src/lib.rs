@@ -1,12 +1,13 @@
 mod date_range;
 mod groupby;
 mod prelude;
+mod round;
 mod truncate;
 mod upsample;
 mod windows;
 
 pub use {
-    date_range::*, groupby::dynamic::*, truncate::*, upsample::*,
+    date_range::*, groupby::dynamic::*, round::*, truncate::*, upsample::*,
     windows::calendar::date_range as date_range_vec, windows::duration::Duration,
     windows::groupby::ClosedWindow, windows::window::Window,
 };
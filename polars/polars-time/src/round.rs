@@ -0,0 +1,61 @@
+use crate::prelude::*;
+use polars_arrow::export::arrow::temporal_conversions::{MILLISECONDS, SECONDS_IN_DAY};
+use polars_core::prelude::*;
+
+pub trait PolarsRound {
+    /// Round the values to the closest multiple of `every` shifted by `offset`.
+    /// Values that are exactly halfway are rounded up.
+    #[must_use]
+    fn round(&self, every: Duration, offset: Duration) -> Self;
+}
+
+#[inline]
+fn round_value<F, G>(t: i64, truncate: F, add: G) -> i64
+where
+    F: Fn(i64) -> i64,
+    G: Fn(i64) -> i64,
+{
+    let lower = truncate(t);
+    let upper = add(lower);
+    if upper - t <= t - lower {
+        upper
+    } else {
+        lower
+    }
+}
+
+#[cfg(feature = "dtype-datetime")]
+impl PolarsRound for DatetimeChunked {
+    #[must_use]
+    fn round(&self, every: Duration, offset: Duration) -> Self {
+        let w = Window::new(every, every, offset);
+
+        let (truncate, add): (fn(&Window, i64) -> i64, fn(&Duration, i64) -> i64) =
+            match self.time_unit() {
+                TimeUnit::Nanoseconds => (Window::truncate_ns, Duration::add_ns),
+                TimeUnit::Microseconds => (Window::truncate_us, Duration::add_us),
+                TimeUnit::Milliseconds => (Window::truncate_ms, Duration::add_ms),
+            };
+
+        self.apply(|t| round_value(t, |t| truncate(&w, t), |t| add(&every, t)))
+            .into_datetime(self.time_unit(), self.time_zone().clone())
+    }
+}
+
+#[cfg(feature = "dtype-date")]
+impl PolarsRound for DateChunked {
+    #[must_use]
+    fn round(&self, every: Duration, offset: Duration) -> Self {
+        let w = Window::new(every, every, offset);
+        self.apply(|t| {
+            const MSECS_IN_DAY: i64 = MILLISECONDS * SECONDS_IN_DAY;
+            let t = round_value(
+                MSECS_IN_DAY * t as i64,
+                |t| w.truncate_ms(t),
+                |t| every.add_ms(t),
+            );
+            (t / MSECS_IN_DAY) as i32
+        })
+        .into_date()
+    }
+}
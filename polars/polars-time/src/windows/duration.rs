@@ -8,7 +8,8 @@ use polars_arrow::export::arrow::temporal_conversions::{
 };
 use polars_core::export::arrow::temporal_conversions::MICROSECONDS;
 use polars_core::prelude::{
-    datetime_to_timestamp_ms, datetime_to_timestamp_ns, datetime_to_timestamp_us,
+    datetime_to_timestamp_ms, datetime_to_timestamp_ns, datetime_to_timestamp_us, PolarsError,
+    Result,
 };
 use polars_core::utils::arrow::temporal_conversions::NANOSECONDS;
 use std::ops::Mul;
@@ -40,8 +41,8 @@ impl Duration {
     ///
     /// 3d12h4m25s // 3 days, 12 hours, 4 minutes, and 25 seconds
     ///
-    /// # Panics if given str is incorrect
-    pub fn parse(duration: &str) -> Self {
+    /// Returns an error if the string is not a valid duration.
+    pub fn parse(duration: &str) -> Result<Self> {
         let mut nsecs = 0;
         let mut months = 0;
         let mut iter = duration.char_indices();
@@ -57,7 +58,11 @@ impl Duration {
         let mut unit = String::with_capacity(2);
         while let Some((i, mut ch)) = iter.next() {
             if !ch.is_ascii_digit() {
-                let n = duration[start..i].parse::<i64>().unwrap();
+                let n = duration[start..i].parse::<i64>().map_err(|_| {
+                    PolarsError::ValueError(
+                        format!("expected an integer in the duration string '{}'", duration).into(),
+                    )
+                })?;
 
                 loop {
                     if ch.is_ascii_alphabetic() {
@@ -76,7 +81,9 @@ impl Duration {
                     }
                 }
                 if unit.is_empty() {
-                    panic!("expected a unit in the duration string")
+                    return Err(PolarsError::ValueError(
+                        format!("expected a unit in the duration string '{}'", duration).into(),
+                    ));
                 }
 
                 match &*unit {
@@ -95,17 +102,28 @@ impl Duration {
                         nsecs += n;
                         parsed_int = true;
                     }
-                    unit => panic!("unit: '{}' not supported", unit),
+                    unit => {
+                        return Err(PolarsError::ValueError(
+                            format!("unit: '{}' not supported", unit).into(),
+                        ))
+                    }
                 }
                 unit.clear();
             }
         }
-        Duration {
+        // trailing digits without a unit
+        let rest = duration[start..].trim_start_matches('-');
+        if start < duration.len() && rest.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(PolarsError::ValueError(
+                format!("expected a unit in the duration string '{}'", duration).into(),
+            ));
+        }
+        Ok(Duration {
             nsecs: nsecs.abs(),
             months: months.abs(),
             negative,
             parsed_int,
-        }
+        })
     }
 
     fn to_positive(v: i64) -> (bool, i64) {
@@ -392,15 +410,19 @@ mod test {
 
     #[test]
     fn test_parse() {
-        let out = Duration::parse("1ns");
+        let out = Duration::parse("1ns").unwrap();
         assert_eq!(out.nsecs, 1);
-        let out = Duration::parse("1ns1ms");
+        let out = Duration::parse("1ns1ms").unwrap();
         assert_eq!(out.nsecs, NS_MILLISECOND + 1);
-        let out = Duration::parse("123ns40ms");
+        let out = Duration::parse("123ns40ms").unwrap();
         assert_eq!(out.nsecs, 40 * NS_MILLISECOND + 123);
-        let out = Duration::parse("123ns40ms1w");
+        let out = Duration::parse("123ns40ms1w").unwrap();
         assert_eq!(out.nsecs, 40 * NS_MILLISECOND + 123 + NS_WEEK);
-        let out = Duration::parse("-123ns40ms1w");
+        let out = Duration::parse("-123ns40ms1w").unwrap();
         assert!(out.negative);
+
+        assert!(Duration::parse("1x").is_err());
+        assert!(Duration::parse("1h10").is_err());
+        assert!(Duration::parse("h").is_err());
     }
 }
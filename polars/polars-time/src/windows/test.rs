@@ -11,7 +11,7 @@ fn test_date_range() {
     let dates = date_range_vec(
         start.timestamp_nanos(),
         end.timestamp_nanos(),
-        Duration::parse("1mo"),
+        Duration::parse("1mo").unwrap(),
         ClosedWindow::Both,
         TimeUnit::Nanoseconds,
     );
@@ -34,7 +34,7 @@ fn test_feb_date_range() {
     let dates = date_range_vec(
         start.timestamp_nanos(),
         end.timestamp_nanos(),
-        Duration::parse("1mo"),
+        Duration::parse("1mo").unwrap(),
         ClosedWindow::Both,
         TimeUnit::Nanoseconds,
     );
@@ -74,8 +74,12 @@ fn test_groups_large_interval() {
         .map(|d| d.and_hms(0, 0, 0).timestamp_nanos())
         .collect::<Vec<_>>();
 
-    let dur = Duration::parse("2d");
-    let w = Window::new(Duration::parse("2d"), dur.clone(), Duration::from_nsecs(0));
+    let dur = Duration::parse("2d").unwrap();
+    let w = Window::new(
+        Duration::parse("2d").unwrap(),
+        dur.clone(),
+        Duration::from_nsecs(0),
+    );
     let (groups, _, _) = groupby_windows(w, &ts, false, ClosedWindow::Both, TimeUnit::Nanoseconds);
     assert_eq!(groups.len(), 4);
     assert_eq!(groups[0], [0, 1]);
@@ -96,9 +100,9 @@ fn test_offset() {
         .and_hms(0, 0, 0)
         .timestamp_nanos();
     let w = Window::new(
-        Duration::parse("5m"),
-        Duration::parse("5m"),
-        Duration::parse("-2m"),
+        Duration::parse("5m").unwrap(),
+        Duration::parse("5m").unwrap(),
+        Duration::parse("-2m").unwrap(),
     );
 
     let b = w.get_earliest_bounds_ns(t);
@@ -116,7 +120,7 @@ fn test_boundaries() {
     let ts = date_range_vec(
         start.timestamp_nanos(),
         stop.timestamp_nanos(),
-        Duration::parse("30m"),
+        Duration::parse("30m").unwrap(),
         ClosedWindow::Both,
         TimeUnit::Nanoseconds,
     );
@@ -125,9 +129,9 @@ fn test_boundaries() {
     // every 2h
     // period 1h
     let w = Window::new(
-        Duration::parse("1h"),
-        Duration::parse("1h"),
-        Duration::parse("0ns"),
+        Duration::parse("1h").unwrap(),
+        Duration::parse("1h").unwrap(),
+        Duration::parse("0ns").unwrap(),
     );
 
     // earliest bound is first datapoint: 2021-12-16 00:00:00
@@ -219,7 +223,7 @@ fn test_boundaries_2() {
     let ts = date_range_vec(
         start.timestamp_nanos(),
         stop.timestamp_nanos(),
-        Duration::parse("30m"),
+        Duration::parse("30m").unwrap(),
         ClosedWindow::Both,
         TimeUnit::Nanoseconds,
     );
@@ -230,8 +234,12 @@ fn test_boundaries_2() {
     // every 2h
     // period 1h
     // offset 30m
-    let offset = Duration::parse("30m");
-    let w = Window::new(Duration::parse("2h"), Duration::parse("1h"), offset);
+    let offset = Duration::parse("30m").unwrap();
+    let w = Window::new(
+        Duration::parse("2h").unwrap(),
+        Duration::parse("1h").unwrap(),
+        offset,
+    );
 
     // earliest bound is first datapoint: 2021-12-16 00:00:00 + 30m offset: 2021-12-16 00:30:00
     let b = w.get_earliest_bounds_ns(ts[0]);
@@ -287,7 +295,7 @@ fn test_boundaries_ms() {
     let ts = date_range_vec(
         start.timestamp_millis(),
         stop.timestamp_millis(),
-        Duration::parse("30m"),
+        Duration::parse("30m").unwrap(),
         ClosedWindow::Both,
         TimeUnit::Milliseconds,
     );
@@ -296,9 +304,9 @@ fn test_boundaries_ms() {
     // every 2h
     // period 1h
     let w = Window::new(
-        Duration::parse("1h"),
-        Duration::parse("1h"),
-        Duration::parse("0ns"),
+        Duration::parse("1h").unwrap(),
+        Duration::parse("1h").unwrap(),
+        Duration::parse("0ns").unwrap(),
     );
 
     // earliest bound is first datapoint: 2021-12-16 00:00:00
@@ -391,14 +399,14 @@ fn test_rolling_lookback() {
     let dates = date_range_vec(
         start.timestamp_nanos(),
         end.timestamp_nanos(),
-        Duration::parse("30m"),
+        Duration::parse("30m").unwrap(),
         ClosedWindow::Both,
         TimeUnit::Nanoseconds,
     );
 
     let groups = groupby_values(
-        Duration::parse("2h"),
-        Duration::parse("-2h"),
+        Duration::parse("2h").unwrap(),
+        Duration::parse("-2h").unwrap(),
         &dates,
         ClosedWindow::Right,
         TimeUnit::Nanoseconds,
@@ -426,9 +434,9 @@ fn test_end_membership() {
             .timestamp_millis(),
     ];
     let window = Window::new(
-        Duration::parse("1mo"),
-        Duration::parse("2mo"),
-        Duration::parse("-2mo"),
+        Duration::parse("1mo").unwrap(),
+        Duration::parse("2mo").unwrap(),
+        Duration::parse("-2mo").unwrap(),
     );
     // windows
     // 2020-12-01 -> 2021-02-01     members: None
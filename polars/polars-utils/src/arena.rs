@@ -81,6 +81,18 @@ impl<T> Arena<T> {
         self.items.get_mut(idx.0).unwrap()
     }
 
+    /// Get an item without panicking if the node is not allocated in this arena.
+    #[inline]
+    pub fn try_get(&self, idx: Node) -> Option<&T> {
+        self.items.get(idx.0)
+    }
+
+    /// Get a mutable item without panicking if the node is not allocated in this arena.
+    #[inline]
+    pub fn try_get_mut(&mut self, idx: Node) -> Option<&mut T> {
+        self.items.get_mut(idx.0)
+    }
+
     #[inline]
     pub fn replace(&mut self, idx: Node, val: T) {
         let x = self.get_mut(idx);
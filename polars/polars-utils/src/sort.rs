@@ -1,4 +1,4 @@
-use rayon::{prelude::*, ThreadPool};
+use rayon::prelude::*;
 
 /// This is a perfect sort particularly useful for an argsort of an argsort
 /// The second argsort sorts indices from `0` to `len` so can be just assigned to the
@@ -6,32 +6,32 @@ use rayon::{prelude::*, ThreadPool};
 ///
 /// Besides that we know that all indices are unique ang thus not alias so we can parallelize.
 ///
-/// This sort does not sort in place and will allocate.
+/// This sort does not sort in place and will allocate. It runs in the thread pool of the caller.
 ///
 /// - The right indices are used for sorting
 /// - The left indices are placed at the location right points to.
 ///
 /// # Safety
-/// The caller must ensure that the right indexes fo `&[(_, u32)]` are integers ranging from `0..idx.len`
-pub unsafe fn perfect_sort(pool: &ThreadPool, idx: &[(u32, u32)]) -> Vec<u32> {
-    let chunk_size = std::cmp::max(
-        idx.len() / pool.current_num_threads(),
-        pool.current_num_threads(),
-    );
+/// The caller must ensure that the right indexes fo `&[(_, I)]` are integers ranging from `0..idx.len`
+pub unsafe fn perfect_sort<I>(idx: &[(I, I)]) -> Vec<I>
+where
+    I: Copy + Send + Sync + Into<u64>,
+{
+    let n_threads = rayon::current_num_threads();
+    let chunk_size = std::cmp::max(idx.len() / n_threads, n_threads);
 
-    let mut out: Vec<u32> = Vec::with_capacity(idx.len());
-    let ptr = out.as_mut_ptr() as *const u32 as usize;
+    let mut out: Vec<I> = Vec::with_capacity(idx.len());
+    let ptr = out.as_mut_ptr() as *const I as usize;
 
-    pool.install(|| {
-        idx.par_chunks(chunk_size).for_each(|indices| {
-            let ptr = ptr as *mut u32;
-            for (idx_val, idx_location) in indices {
-                // Safety:
-                // idx_location is in bounds by invariant of this function
-                // and we ensured we have at least `idx.len()` capacity
-                *ptr.add(*idx_location as usize) = *idx_val;
-            }
-        });
+    idx.par_chunks(chunk_size).for_each(|indices| {
+        let ptr = ptr as *mut I;
+        for (idx_val, idx_location) in indices {
+            // Safety:
+            // idx_location is in bounds by invariant of this function
+            // and we ensured we have at least `idx.len()` capacity
+            let idx_location: u64 = (*idx_location).into();
+            *ptr.add(idx_location as usize) = *idx_val;
+        }
     });
     // Safety:
     // all elements are written
@@ -56,11 +56,11 @@
 //!
 //! ```rust
 //! use polars::prelude::*;
-//! use polars::toggle_string_cache;
+//! use polars::StringCacheHolder;
 //!
 //! fn example(mut df_a: DataFrame, mut df_b: DataFrame) -> Result<DataFrame> {
-//!     // Set a global string cache
-//!     toggle_string_cache(true);
+//!     // Use the global string cache until the holder is dropped
+//!     let _holder = StringCacheHolder::hold();
 //!
 //!     df_a.try_apply("a", |s| s.cast::<CategoricalType>())?;
 //!     df_b.try_apply("b", |s| s.cast::<CategoricalType>())?;
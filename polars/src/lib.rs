@@ -130,12 +130,16 @@
 //! * Performance related:
 //!     - `simd` - SIMD operations _(nightly only)_
 //!     - `performant` - ~40% faster chunkedarray creation but may lead to unexpected panic if iterator incorrectly sets a size_hint
+//!     - `bigidx` - Use `u64` row indexes so that a `DataFrame` can hold more than `u32::MAX` rows
+//!     - `spill` - Spill the intermediate state of groupbys and sorts that exceed a memory budget to disk.
+//!                 Their input must still fit in memory.
 //! * IO related:
 //!     - `serde` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!                 Can be used for JSON and more serde supported serialization formats.
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `ffi` - Export and import through the Arrow C data and C stream interface
 //!     - `decompress` - Automatically infer compression of csv-files and decompress them.
 //!                      Supported compressions:
 //!                         * zip
@@ -147,8 +151,10 @@
 //!     - `sort_multiple` - Allow sorting a `DataFrame` on multiple columns
 //!     - `rows` - Create `DataFrame` from rows and extract rows from `DataFrames`.
 //!                And activates `pivot` and `transpose` operations
+//!     - `derive` - Derive `IntoDataFrame` and `FromDataFrame` to convert between structs and `DataFrames`.
 //!     - `asof_join` - Join as of, to join on nearest keys instead of exact equality match.
 //!     - `cross_join` - Create the cartesian product of two DataFrames.
+//!     - `range_join` - Join on inequality predicates with `join_where`.
 //!     - `groupby_list` - Allow groupby operation on keys of type List.
 //!     - `row_hash` - Utility to hash DataFrame rows to UInt64Chunked
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
@@ -176,6 +182,10 @@
 //!     - `moment` - kurtosis and skew statistics
 //!     - `ewma` - Exponential moving average windows
 //!     - `abs` - Get absolute values of Series
+//!     - `math` - Trigonometric, exponential and logarithmic functions
+//!     - `top_k` - Get the k largest or smallest values without a full sort
+//!     - `cut` - Bin values with `cut`, `qcut` and `hist`
+//!     - `remap` - Remap values with `map_dict` and `replace`
 //!     - `arange` - Range operation on Series
 //!     - `product` - Compute the product of a Series.
 //! * `DataFrame` pretty printing (Choose one or none, but not both):
@@ -204,6 +214,7 @@
 //! | UInt8                   | dtype-u8          |
 //! | UInt16                  | dtype-u16         |
 //! | Categorical             | dtype-categorical |
+//! | Enum                    | dtype-categorical |
 //!
 //!
 //! Or you can choose on of the preconfigured pre-sets.
@@ -250,6 +261,11 @@
 //! * `POLARS_ALLOW_EXTENSION` -> allows for `[ObjectChunked<T>]` to be used in arrow, opening up possibilities like using
 //!                               `T` in complex lazy expressions. However this does require `unsafe` code allow this.
 //! * `POLARS_NO_PARQUET_STATISTICS` -> if set, statistics in parquet files are ignored.
+//! * `POLARS_VERT_PAR` -> parallelize over the columns of a `DataFrame` instead of within columns.
+//! * `POLARS_TEMP_DIR` -> directory in which spilled data is written.
+//!
+//! These env vars are the defaults of [`Config`](crate::config::Config). A different
+//! `Config` can be activated for a single query or thread with `Config::scoped`.
 //!
 //!
 //! ## Compile for WASM
@@ -268,15 +284,19 @@ pub mod docs;
 pub mod export;
 pub mod prelude;
 
+pub use polars_core::{
+    chunked_array, config, datatypes, doc, error, frame, functions, series, testing,
+};
 #[cfg(feature = "dtype-categorical")]
-pub use polars_core::toggle_string_cache;
-pub use polars_core::{chunked_array, datatypes, doc, error, frame, functions, series, testing};
+pub use polars_core::{toggle_string_cache, StringCacheHolder};
 #[cfg(feature = "temporal")]
 pub use polars_time as time;
 
 pub use polars_core::apply_method_all_arrow_series;
 pub use polars_core::df;
 
+#[cfg(feature = "derive")]
+pub use polars_derive::{FromDataFrame, IntoDataFrame};
 #[cfg(feature = "polars-io")]
 pub use polars_io as io;
 #[cfg(feature = "lazy")]
@@ -1,5 +1,9 @@
 pub use polars_core::frame::groupby::*;
+#[cfg(feature = "derive")]
+pub use polars_core::frame::row::{ColumnValue, FromDataFrame, IntoDataFrame};
 pub use polars_core::{prelude::*, utils::NoNull};
+#[cfg(feature = "derive")]
+pub use polars_derive::{FromDataFrame, IntoDataFrame};
 #[cfg(feature = "polars-io")]
 pub use polars_io::prelude::*;
 
@@ -0,0 +1,89 @@
+use polars::export::chrono::NaiveDate;
+use polars::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, IntoDataFrame, FromDataFrame)]
+struct Location {
+    city: String,
+    zip: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, IntoDataFrame, FromDataFrame)]
+struct Customer {
+    id: i64,
+    #[polars(rename = "customer_name")]
+    name: Option<String>,
+    joined: NaiveDate,
+    scores: Vec<f64>,
+    #[polars(flatten)]
+    location: Location,
+}
+
+fn customers() -> Vec<Customer> {
+    vec![
+        Customer {
+            id: 1,
+            name: Some("alice".into()),
+            joined: NaiveDate::from_ymd(2021, 3, 1),
+            scores: vec![1.0, 2.5],
+            location: Location {
+                city: "Utrecht".into(),
+                zip: Some(3511),
+            },
+        },
+        Customer {
+            id: 2,
+            name: None,
+            joined: NaiveDate::from_ymd(2022, 1, 12),
+            scores: vec![],
+            location: Location {
+                city: "Leiden".into(),
+                zip: None,
+            },
+        },
+    ]
+}
+
+#[test]
+fn test_derive_roundtrip() -> Result<()> {
+    let rows = customers();
+    let df = Customer::into_df(rows.clone())?;
+    assert_eq!(
+        df.get_column_names(),
+        &[
+            "id",
+            "customer_name",
+            "joined",
+            "scores",
+            "location.city",
+            "location.zip"
+        ]
+    );
+    assert_eq!(df.column("joined")?.dtype(), &DataType::Date);
+    assert_eq!(
+        df.column("scores")?.dtype(),
+        &DataType::List(Box::new(DataType::Float64))
+    );
+    assert_eq!(df.column("location.zip")?.null_count(), 1);
+
+    let out = df.iter_as::<Customer>()?.collect::<Vec<_>>();
+    assert_eq!(out, rows);
+    Ok(())
+}
+
+#[test]
+fn test_derive_from_dataframe_errors() -> Result<()> {
+    #[derive(Debug, FromDataFrame)]
+    struct Required {
+        #[allow(dead_code)]
+        city: String,
+        #[allow(dead_code)]
+        zip: u32,
+    }
+
+    let df = Location::into_df(customers().into_iter().map(|c| c.location).collect())?;
+    // a column with nulls cannot be read into a field that isn't an Option
+    assert!(df.iter_as::<Required>().is_err());
+    // missing columns
+    assert!(df.drop("zip")?.iter_as::<Location>().is_err());
+    Ok(())
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "derive")]
+mod derive;
 mod io;
 mod joins;
 mod lazy;
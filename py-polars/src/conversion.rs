@@ -257,7 +257,7 @@ impl ToPyObject for Wrap<DataType> {
             DataType::Datetime(_, _) => pl.getattr("Datetime").unwrap().into(),
             DataType::Duration(_) => pl.getattr("Duration").unwrap().into(),
             DataType::Object(_) => pl.getattr("Object").unwrap().into(),
-            DataType::Categorical => pl.getattr("Categorical").unwrap().into(),
+            DataType::Categorical | DataType::Enum(_) => pl.getattr("Categorical").unwrap().into(),
             DataType::Time => pl.getattr("Time").unwrap().into(),
             dt => panic!("{} not supported", dt),
         }
@@ -491,7 +491,7 @@ impl PyDataFrame {
             .get_columns()
             .iter()
             .enumerate()
-            .filter(|(_i, s)| s.dtype() == &DataType::Categorical)
+            .filter(|(_i, s)| matches!(s.dtype(), DataType::Categorical | DataType::Enum(_)))
             .map(|(i, _)| i)
             .collect::<Vec<_>>();
 
@@ -1187,20 +1187,12 @@ impl PyDataFrame {
         offset: &str,
         stable: bool,
     ) -> PyResult<Self> {
+        let every = Duration::parse(every).map_err(PyPolarsEr::from)?;
+        let offset = Duration::parse(offset).map_err(PyPolarsEr::from)?;
         let out = if stable {
-            self.df.upsample_stable(
-                by,
-                index_column,
-                Duration::parse(every),
-                Duration::parse(offset),
-            )
+            self.df.upsample_stable(by, index_column, every, offset)
         } else {
-            self.df.upsample(
-                by,
-                index_column,
-                Duration::parse(every),
-                Duration::parse(offset),
-            )
+            self.df.upsample(by, index_column, every, offset)
         };
         let out = out.map_err(PyPolarsEr::from)?;
         Ok(out.into())
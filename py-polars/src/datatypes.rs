@@ -48,7 +48,8 @@ impl From<&DataType> for PyDataType {
             DataType::Duration(tu) => Duration(*tu),
             DataType::Time => Time,
             DataType::Object(_) => Object,
-            DataType::Categorical => Categorical,
+            // python has no enum dtype yet, the values behave like a categorical
+            DataType::Categorical | DataType::Enum(_) => Categorical,
             DataType::Null | DataType::Unknown => {
                 panic!("null or unknown not expected here")
             }
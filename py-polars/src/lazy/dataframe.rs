@@ -324,17 +324,17 @@ impl PyLazyFrame {
         period: &str,
         offset: &str,
         closed: Wrap<ClosedWindow>,
-    ) -> PyLazyGroupBy {
+    ) -> PyResult<PyLazyGroupBy> {
         let closed_window = closed.0;
         let ldf = self.ldf.clone();
         let lazy_gb = ldf.groupby_rolling(RollingGroupOptions {
             index_column,
-            period: Duration::parse(period),
-            offset: Duration::parse(offset),
+            period: Duration::parse(period).map_err(PyPolarsEr::from)?,
+            offset: Duration::parse(offset).map_err(PyPolarsEr::from)?,
             closed_window,
         });
 
-        PyLazyGroupBy { lgb: Some(lazy_gb) }
+        Ok(PyLazyGroupBy { lgb: Some(lazy_gb) })
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -348,7 +348,7 @@ impl PyLazyFrame {
         include_boundaries: bool,
         closed: Wrap<ClosedWindow>,
         by: Vec<PyExpr>,
-    ) -> PyLazyGroupBy {
+    ) -> PyResult<PyLazyGroupBy> {
         let closed_window = closed.0;
         let by = by
             .into_iter()
@@ -359,16 +359,16 @@ impl PyLazyFrame {
             by,
             DynamicGroupOptions {
                 index_column,
-                every: Duration::parse(every),
-                period: Duration::parse(period),
-                offset: Duration::parse(offset),
+                every: Duration::parse(every).map_err(PyPolarsEr::from)?,
+                period: Duration::parse(period).map_err(PyPolarsEr::from)?,
+                offset: Duration::parse(offset).map_err(PyPolarsEr::from)?,
                 truncate,
                 include_boundaries,
                 closed_window,
             },
         );
 
-        PyLazyGroupBy { lgb: Some(lazy_gb) }
+        Ok(PyLazyGroupBy { lgb: Some(lazy_gb) })
     }
 
     #[allow(clippy::too_many_arguments)]
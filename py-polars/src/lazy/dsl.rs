@@ -1,5 +1,6 @@
 use super::apply::*;
 use crate::conversion::{str_to_null_behavior, Wrap};
+use crate::error::PyPolarsEr;
 use crate::lazy::map_single;
 use crate::lazy::utils::py_exprs_to_exprs;
 use crate::prelude::{parse_strategy, str_to_rankmethod};
@@ -1116,10 +1117,11 @@ impl PyExpr {
         self.inner.clone().str().concat(delimiter).into()
     }
 
-    fn date_truncate(&self, every: &str, offset: &str) -> Self {
-        let every = Duration::parse(every);
-        let offset = Duration::parse(offset);
-        self.inner
+    fn date_truncate(&self, every: &str, offset: &str) -> PyResult<Self> {
+        let every = Duration::parse(every).map_err(PyPolarsEr::from)?;
+        let offset = Duration::parse(offset).map_err(PyPolarsEr::from)?;
+        Ok(self
+            .inner
             .clone()
             .apply(
                 move |s| match s.dtype() {
@@ -1134,7 +1136,7 @@ impl PyExpr {
                 GetOutput::same_type(),
             )
             .with_fmt("dt.truncate")
-            .into()
+            .into())
     }
 
     pub fn reshape(&self, dims: Vec<i64>) -> Self {
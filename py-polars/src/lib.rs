@@ -328,15 +328,15 @@ fn py_date_range(
     closed: Wrap<ClosedWindow>,
     name: &str,
     tu: &str,
-) -> PySeries {
+) -> PyResult<PySeries> {
     let tu = match tu {
         "ns" => TimeUnit::Nanoseconds,
         "ms" => TimeUnit::Milliseconds,
         _ => panic!("{}", "expected one of {'ns', 'ms'}"),
     };
-    polars::time::date_range(name, start, stop, Duration::parse(every), closed.0, tu)
-        .into_series()
-        .into()
+    let every = Duration::parse(every).map_err(PyPolarsEr::from)?;
+    let ca = polars::time::date_range(name, start, stop, every, closed.0, tu);
+    Ok(ca.into_series().into())
 }
 
 #[pyfunction]
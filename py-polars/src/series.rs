@@ -674,7 +674,9 @@ impl PySeries {
         };
 
         let pylist = match series.dtype() {
-            DataType::Categorical => PyList::new(python, series.categorical().unwrap().iter_str()),
+            DataType::Categorical | DataType::Enum(_) => {
+                PyList::new(python, series.categorical().unwrap().iter_str())
+            }
             DataType::Object(_) => {
                 let v = PyList::empty(python);
                 for i in 0..series.len() {